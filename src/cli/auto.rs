@@ -0,0 +1,163 @@
+//! Directory-based auto-switching: `bridle hook`, `bridle which`, and `bridle shell-init`.
+
+use std::path::{Path, PathBuf};
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::cli::output::{ResolvedFormat, output_list};
+use crate::cli::profile::Target;
+use crate::config::{AutoRule, BridleConfig, ProfileManager, ProfileName};
+use crate::error::{Error, Result};
+
+/// Shells `bridle shell-init` can generate a hook for.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+#[derive(Serialize)]
+struct RuleMatch {
+    /// 1-based position of the rule among the `[[auto]]` entries.
+    rule: usize,
+    path: String,
+    harness_id: String,
+    profile: String,
+    is_active: bool,
+}
+
+fn current_dir(dir: Option<&Path>) -> Result<PathBuf> {
+    match dir {
+        Some(dir) => Ok(dir.to_path_buf()),
+        None => Ok(std::env::current_dir()?),
+    }
+}
+
+/// The first matching rule for each harness, in config order.
+fn matching_rules(config: &BridleConfig, dir: &Path) -> Result<Vec<(Target, RuleMatch)>> {
+    let mut matches: Vec<(Target, RuleMatch)> = Vec::new();
+    for (index, rule) in config.auto_rules_for(dir) {
+        let target = Target::resolve(&rule.harness, None)?;
+        let harness_id = target.config().id().to_string();
+        if matches.iter().any(|(_, m)| m.harness_id == harness_id) {
+            continue;
+        }
+        let AutoRule { path, profile, .. } = rule.clone();
+        let is_active = config.active_profile_for(&harness_id) == Some(profile.as_str());
+        matches.push((
+            target,
+            RuleMatch {
+                rule: index + 1,
+                path,
+                harness_id,
+                profile,
+                is_active,
+            },
+        ));
+    }
+    Ok(matches)
+}
+
+/// Switches harnesses to the profiles their rules pick for `dir`.
+///
+/// Meant to run on every directory change, so it does nothing unless a rule
+/// matches a profile that isn't already active, and only reports on stderr.
+pub fn hook(dir: Option<&Path>) -> Result<()> {
+    let config = BridleConfig::load()?;
+    if config.auto.is_empty() {
+        return Ok(());
+    }
+    let dir = current_dir(dir)?;
+    let pending: Vec<_> = matching_rules(&config, &dir)?
+        .into_iter()
+        .filter(|(_, m)| !m.is_active)
+        .collect();
+    if pending.is_empty() {
+        return Ok(());
+    }
+
+    let manager = ProfileManager::new(BridleConfig::profiles_dir()?);
+    for (target, m) in pending {
+        let harness = target.config();
+        let name = ProfileName::new(&m.profile)
+            .map_err(|_| Error::InvalidProfileName(m.profile.clone()))?;
+        if !manager.profile_exists(harness, &name) {
+            eprintln!(
+                "bridle: auto rule {} wants {} profile '{}', which does not exist",
+                m.rule, m.harness_id, m.profile
+            );
+            continue;
+        }
+        if let Err(e) = manager.backup_current(harness) {
+            eprintln!("bridle: could not back up {} config: {e}", m.harness_id);
+        }
+        manager.switch_profile_with_resources(harness, target.harness(), &name)?;
+        eprintln!("bridle: switched {} to {}", m.harness_id, m.profile);
+    }
+    Ok(())
+}
+
+/// Explains which auto-switch rules apply to `dir`.
+pub fn which(dir: Option<&Path>, format: ResolvedFormat) -> Result<()> {
+    let config = BridleConfig::load()?;
+    let dir = current_dir(dir)?;
+    let matches: Vec<RuleMatch> = matching_rules(&config, &dir)?
+        .into_iter()
+        .map(|(_, m)| m)
+        .collect();
+
+    output_list(&matches, format, |matches| {
+        if matches.is_empty() {
+            println!("No auto-switch rule matches {}", dir.display());
+            return;
+        }
+        for m in matches {
+            let state = if m.is_active { "active" } else { "inactive" };
+            println!(
+                "{} -> {} (rule {}: {}, {state})",
+                m.harness_id, m.profile, m.rule, m.path
+            );
+        }
+    });
+    Ok(())
+}
+
+/// Prints the snippet that runs `bridle hook` whenever the shell changes directory.
+pub fn shell_init(shell: Shell) {
+    let snippet = match shell {
+        Shell::Bash => BASH_HOOK,
+        Shell::Zsh => ZSH_HOOK,
+        Shell::Fish => FISH_HOOK,
+    };
+    print!("{snippet}");
+}
+
+const BASH_HOOK: &str = r#"# Add to ~/.bashrc: eval "$(bridle shell-init bash)"
+_bridle_hook() {
+  if [[ "$PWD" != "${_BRIDLE_LAST_PWD:-}" ]]; then
+    _BRIDLE_LAST_PWD="$PWD"
+    command bridle hook
+  fi
+}
+if [[ ";${PROMPT_COMMAND:-};" != *";_bridle_hook;"* ]]; then
+  PROMPT_COMMAND="_bridle_hook${PROMPT_COMMAND:+;$PROMPT_COMMAND}"
+fi
+"#;
+
+const ZSH_HOOK: &str = r#"# Add to ~/.zshrc: eval "$(bridle shell-init zsh)"
+_bridle_hook() {
+  command bridle hook
+}
+autoload -Uz add-zsh-hook
+add-zsh-hook chpwd _bridle_hook
+_bridle_hook
+"#;
+
+const FISH_HOOK: &str = r#"# Add to ~/.config/fish/config.fish: bridle shell-init fish | source
+function __bridle_hook --on-variable PWD
+    command bridle hook
+end
+__bridle_hook
+"#;
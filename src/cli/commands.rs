@@ -1,7 +1,11 @@
 //! CLI subcommand definitions.
 
+use std::path::PathBuf;
+
 use clap::Subcommand;
 
+use super::auto::Shell;
+
 #[derive(Subcommand, Debug)]
 pub enum Commands {
     /// Show status of all harnesses.
@@ -10,6 +14,9 @@ pub enum Commands {
     /// Initialize bridle configuration.
     Init,
 
+    /// List supported harnesses and what bridle can manage for each.
+    Harnesses,
+
     /// Manage profiles.
     #[command(subcommand)]
     Profile(ProfileCommands),
@@ -21,13 +28,75 @@ pub enum Commands {
     #[command(subcommand)]
     Config(ConfigCommands),
 
-    /// Install skills from a GitHub repository.
+    /// Manage deleted profiles.
+    #[command(subcommand)]
+    Trash(TrashCommands),
+
+    /// Show, edit, and compose profile rules files (CLAUDE.md, AGENTS.md, ...).
+    #[command(subcommand)]
+    Rules(RulesCommands),
+
+    /// Show or change the model profiles use.
+    #[command(subcommand)]
+    Model(ModelCommands),
+
+    /// Manage Claude Code plugins and marketplaces, and port plugins to other harnesses.
+    #[command(subcommand)]
+    Plugin(PluginCommands),
+
+    /// Show and edit the permission rules of Claude Code profiles.
+    #[command(subcommand)]
+    Permissions(PermissionsCommands),
+
+    /// Show and edit the hooks of Claude Code profiles.
+    #[command(subcommand)]
+    Hooks(HooksCommands),
+
+    /// Apply `[[auto]]` rules for a directory (run by the shell on `cd`).
+    Hook {
+        /// Directory to match (defaults to the current directory).
+        #[arg(long)]
+        dir: Option<PathBuf>,
+    },
+
+    /// Print a shell snippet that runs `bridle hook` on every directory change.
+    ShellInit {
+        /// Shell to generate the snippet for.
+        shell: Shell,
+    },
+
+    /// Explain which `[[auto]]` rules match a directory.
+    Which {
+        /// Directory to match (defaults to the current directory).
+        dir: Option<PathBuf>,
+    },
+
+    /// Install skills from one or more GitHub repositories.
     Install {
-        /// GitHub repository URL or owner/repo shorthand.
-        source: String,
+        /// GitHub repository URL, owner/repo shorthand, or registry name.
+        /// Append `//<dir>` to limit discovery to a subdirectory and
+        /// `@<ref>` to pick a branch or tag (e.g. owner/repo//skills/foo@v1.2).
+        #[arg(required = true)]
+        sources: Vec<String>,
         /// Force overwrite existing skills.
         #[arg(long, short)]
         force: bool,
+        /// Install everything discovered into active profiles without prompting.
+        #[arg(long, short)]
+        yes: bool,
+        /// Install components flagged by the security scan.
+        #[arg(long)]
+        allow_risky: bool,
+    },
+
+    /// Search the skill registry for installable sources.
+    Search {
+        /// Text to match against names, descriptions, and components.
+        #[arg(default_value = "")]
+        query: String,
+        /// Registry URL or path (overrides the configured registry).
+        #[arg(long)]
+        registry: Option<String>,
     },
 
     /// Uninstall components from a profile.
@@ -36,6 +105,15 @@ pub enum Commands {
         harness: String,
         /// Profile name.
         profile: String,
+        /// Component to remove as kind:name (skill, agent, command, mcp). Repeatable.
+        #[arg(long = "component", value_name = "KIND:NAME")]
+        components: Vec<String>,
+        /// Remove every component installed from this source (owner/repo).
+        #[arg(long, value_name = "SOURCE")]
+        all_from: Option<String>,
+        /// Don't ask for confirmation.
+        #[arg(long, short)]
+        yes: bool,
     },
 }
 
@@ -56,6 +134,252 @@ pub enum ConfigCommands {
     },
 }
 
+#[derive(Subcommand, Debug)]
+pub enum TrashCommands {
+    /// List deleted profiles, newest first.
+    List {
+        /// Only show profiles of this harness.
+        harness: Option<String>,
+    },
+
+    /// Restore the most recently deleted profile with this name.
+    Restore {
+        /// Harness name.
+        harness: String,
+        /// Profile name.
+        name: String,
+    },
+
+    /// Permanently delete trashed profiles.
+    Empty {
+        /// Only empty the trash of this harness.
+        harness: Option<String>,
+        /// Don't ask for confirmation.
+        #[arg(long, short)]
+        yes: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum RulesCommands {
+    /// Print a profile's rules file.
+    Show {
+        /// Harness name.
+        harness: String,
+        /// Profile name (defaults to the active profile).
+        profile: Option<String>,
+    },
+
+    /// Edit a profile's rules file with $EDITOR.
+    Edit {
+        /// Harness name.
+        harness: String,
+        /// Profile name (defaults to the active profile).
+        profile: Option<String>,
+    },
+
+    /// List the snippets in ~/.config/bridle/snippets/ and the profiles using them.
+    Snippets,
+
+    /// Append a snippet to a profile's rules file on every switch.
+    AddSnippet {
+        /// Harness name.
+        harness: String,
+        /// Profile name.
+        profile: String,
+        /// Snippet name (file name without `.md`).
+        snippet: String,
+    },
+
+    /// Stop appending a snippet to a profile's rules file.
+    RemoveSnippet {
+        /// Harness name.
+        harness: String,
+        /// Profile name.
+        profile: String,
+        /// Snippet name.
+        snippet: String,
+    },
+
+    /// Write one rules document into every harness's profile, under its own file name.
+    Sync {
+        /// Markdown file to copy.
+        file: PathBuf,
+        /// Profile to write to in each harness (defaults to each harness's active profile).
+        #[arg(long)]
+        profile: Option<String>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ModelCommands {
+    /// Print a profile's model.
+    Get {
+        /// Harness name, or `all` for every harness.
+        harness: String,
+        /// Profile name (defaults to the active profile).
+        profile: Option<String>,
+    },
+
+    /// Set a profile's model, live too if the profile is active.
+    Set {
+        /// Harness name, or `all` to set an alias in every harness.
+        harness: String,
+        /// `[PROFILE] MODEL`: the profile defaults to the active one, and the model
+        /// may be an alias (e.g. sonnet).
+        #[arg(required = true, num_args = 1..=2, value_name = "MODEL")]
+        args: Vec<String>,
+    },
+
+    /// List model aliases and the model each stands for per harness.
+    Aliases,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum PluginCommands {
+    /// List a Claude Code profile's plugins and marketplaces.
+    List {
+        /// Profile name (defaults to the active profile).
+        profile: Option<String>,
+    },
+
+    /// Enable a plugin, live too if the profile is active.
+    Enable {
+        /// Plugin as `name@marketplace`.
+        plugin: String,
+        /// Profile name (defaults to the active profile).
+        profile: Option<String>,
+    },
+
+    /// Disable a plugin, live too if the profile is active.
+    Disable {
+        /// Plugin as `name@marketplace`.
+        plugin: String,
+        /// Profile name (defaults to the active profile).
+        profile: Option<String>,
+    },
+
+    /// Add a marketplace from owner/repo, a git URL, or a local directory.
+    AddMarketplace {
+        /// Marketplace source.
+        source: String,
+        /// Profile name (defaults to the active profile).
+        profile: Option<String>,
+        /// Marketplace name (defaults to the repository or manifest name).
+        #[arg(long)]
+        name: Option<String>,
+    },
+
+    /// Remove a plugin (`name@marketplace`), or a marketplace and its plugins.
+    Remove {
+        /// Plugin or marketplace name.
+        name: String,
+        /// Profile name (defaults to the active profile).
+        profile: Option<String>,
+    },
+
+    /// Install a plugin's agents and commands into another harness's profile.
+    Port {
+        /// Plugin as `name@marketplace`.
+        plugin: String,
+        /// Claude Code profile holding the plugin (defaults to the active profile).
+        profile: Option<String>,
+        /// Harness to install into.
+        #[arg(long, default_value = "opencode")]
+        to: String,
+        /// Profile to install into (defaults to the harness's active profile).
+        #[arg(long)]
+        to_profile: Option<String>,
+        /// Overwrite existing agents and commands.
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum PermissionsCommands {
+    /// List a Claude Code profile's permission rules.
+    List {
+        /// Profile name (defaults to the active profile).
+        profile: Option<String>,
+    },
+
+    /// Allow tool uses matching a rule without asking.
+    Allow {
+        /// Permission rule (e.g. `Bash(git diff:*)`).
+        rule: String,
+        /// Profile name (defaults to the active profile).
+        profile: Option<String>,
+    },
+
+    /// Ask before tool uses matching a rule.
+    Ask {
+        /// Permission rule.
+        rule: String,
+        /// Profile name (defaults to the active profile).
+        profile: Option<String>,
+    },
+
+    /// Deny tool uses matching a rule.
+    Deny {
+        /// Permission rule (e.g. `Read(./.env)`).
+        rule: String,
+        /// Profile name (defaults to the active profile).
+        profile: Option<String>,
+    },
+
+    /// Remove a permission rule from whichever list holds it.
+    Remove {
+        /// Permission rule.
+        rule: String,
+        /// Profile name (defaults to the active profile).
+        profile: Option<String>,
+    },
+
+    /// Show permission and hook changes between two profiles.
+    Diff {
+        /// First profile name.
+        profile: String,
+        /// Second profile name (defaults to the current config).
+        other: Option<String>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum HooksCommands {
+    /// List a Claude Code profile's hooks.
+    List {
+        /// Profile name (defaults to the active profile).
+        profile: Option<String>,
+    },
+
+    /// Run a command on a hook event.
+    Add {
+        /// Hook event (e.g. PreToolUse, PostToolUse, Stop).
+        event: String,
+        /// Shell command to run.
+        command: String,
+        /// Profile name (defaults to the active profile).
+        profile: Option<String>,
+        /// Limit the hook to tools matching this pattern (e.g. `Bash`, `Edit|Write`).
+        #[arg(long)]
+        matcher: Option<String>,
+    },
+
+    /// Stop running a command on a hook event.
+    Remove {
+        /// Hook event.
+        event: String,
+        /// Shell command.
+        command: String,
+        /// Profile name (defaults to the active profile).
+        profile: Option<String>,
+        /// Only remove the hook under this matcher.
+        #[arg(long)]
+        matcher: Option<String>,
+    },
+}
+
 #[derive(Subcommand, Debug)]
 pub enum ProfileCommands {
     /// List profiles for a harness.
@@ -83,7 +407,7 @@ pub enum ProfileCommands {
         from_current: bool,
     },
 
-    /// Delete a profile.
+    /// Delete a profile (moves it to the trash).
     Delete {
         /// Harness name.
         harness: String,
@@ -116,4 +440,22 @@ pub enum ProfileCommands {
         /// Second profile name (optional, defaults to current config).
         other: Option<String>,
     },
+
+    /// Show unsaved changes between the live config and the active profile.
+    Status {
+        /// Harness name.
+        harness: String,
+    },
+
+    /// Save the live config into the active profile.
+    Save {
+        /// Harness name.
+        harness: String,
+    },
+
+    /// Discard live changes and restore the active profile.
+    Reset {
+        /// Harness name.
+        harness: String,
+    },
 }
@@ -1,12 +1,12 @@
-use harness_locate::{Harness, HarnessKind};
-
-use crate::config::BridleConfig;
+use crate::config::{BridleConfig, ViewPreference};
 use crate::error::{Error, Result};
-use crate::harness::HarnessConfig;
+use crate::harness::HarnessRegistry;
 
 pub fn set_config(key: &str, value: &str) -> Result<()> {
     match key {
         "profile_marker" => set_profile_marker(value),
+        "registry" => set_registry(value),
+        "tui.view" => set_tui_view(value),
         _ => Err(Error::UnknownSetting(key.to_string())),
     }
 }
@@ -16,6 +16,8 @@ pub fn get_config(key: &str) -> Result<()> {
 
     match key {
         "profile_marker" => println!("{}", config.profile_marker),
+        "registry" => println!("{}", config.registry().unwrap_or("")),
+        "tui.view" => println!("{}", config.tui.view.as_str()),
         _ => return Err(Error::UnknownSetting(key.to_string())),
     }
     Ok(())
@@ -40,10 +42,34 @@ fn set_profile_marker(value: &str) -> Result<()> {
     Ok(())
 }
 
+fn set_registry(value: &str) -> Result<()> {
+    let location = match value.trim() {
+        "" | "none" => None,
+        v => Some(v),
+    };
+
+    let mut config = BridleConfig::load().unwrap_or_default();
+    config.set_registry(location);
+    config.save()?;
+
+    println!("registry = {}", location.unwrap_or(""));
+    Ok(())
+}
+
+fn set_tui_view(value: &str) -> Result<()> {
+    let view: ViewPreference = value.parse().map_err(Error::InvalidValue)?;
+
+    let mut config = BridleConfig::load().unwrap_or_default();
+    config.tui.view = view;
+    config.save()?;
+
+    println!("tui.view = {}", view.as_str());
+    Ok(())
+}
+
 fn cleanup_all_marker_files() {
-    for kind in HarnessKind::ALL {
-        let harness = Harness::new(*kind);
-        let Ok(config_dir) = harness.config_dir() else {
+    for entry in HarnessRegistry::builtin().entries() {
+        let Ok(config_dir) = entry.config().config_dir() else {
             continue;
         };
         let Ok(entries) = std::fs::read_dir(&config_dir) else {
@@ -0,0 +1,41 @@
+//! CLI harnesses command implementation.
+
+use crate::cli::output::{ResolvedFormat, output_list};
+use crate::error::Result;
+use crate::harness::{Capabilities, HarnessEntry, HarnessRegistry};
+
+/// Prints every known harness with what bridle can manage for it.
+pub fn list(format: ResolvedFormat) -> Result<()> {
+    let registry = HarnessRegistry::load()?;
+    output_list(registry.entries(), format, print_matrix);
+    Ok(())
+}
+
+fn print_matrix(entries: &[HarnessEntry]) {
+    let id_width = entries.iter().map(|e| e.id.len()).max().unwrap_or(0).max(7);
+    let name_width = entries
+        .iter()
+        .map(|e| e.name.len())
+        .max()
+        .unwrap_or(0)
+        .max(4);
+
+    let mut header = format!("{:id_width$}  {:name_width$}", "HARNESS", "NAME");
+    for (column, _) in Capabilities::default().flags() {
+        header.push_str(&format!("  {:<8}", column.to_uppercase()));
+    }
+    println!("{}", header.trim_end());
+
+    for entry in entries {
+        let mut row = format!("{:id_width$}  {:name_width$}", entry.id, entry.name);
+        for (_, supported) in entry.capabilities.flags() {
+            let mark = if supported { "yes" } else { "-" };
+            row.push_str(&format!("  {mark:<8}"));
+        }
+        let mut row = row.trim_end().to_string();
+        if !entry.aliases.is_empty() {
+            row.push_str(&format!("  (aliases: {})", entry.aliases.join(", ")));
+        }
+        println!("{row}");
+    }
+}
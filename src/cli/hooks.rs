@@ -0,0 +1,95 @@
+//! CLI hooks command implementation.
+
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::cli::output::{ResolvedFormat, output};
+use crate::cli::plugin::{claude_profile, edit_profile, live_suffix};
+use crate::config::{HookInfo, permissions};
+use crate::error::{Error, Result};
+
+#[derive(Serialize)]
+struct HooksInfo {
+    profile: String,
+    hooks: Vec<HookInfo>,
+}
+
+pub fn list(
+    profile_name: Option<&str>,
+    project: Option<&Path>,
+    format: ResolvedFormat,
+) -> Result<()> {
+    let (target, name) = claude_profile(profile_name, project)?;
+    let info = target.manager()?.show_profile(target.config(), &name)?;
+    let info = HooksInfo {
+        profile: name.as_str().to_string(),
+        hooks: info.hooks.unwrap_or_default(),
+    };
+
+    output(&info, format, |info| {
+        println!("Hooks in {}:", info.profile);
+        if info.hooks.is_empty() {
+            println!("  (none)");
+        }
+        for hook in &info.hooks {
+            println!("  {hook}");
+        }
+    });
+    Ok(())
+}
+
+pub fn add(
+    event: &str,
+    command: &str,
+    matcher: Option<&str>,
+    profile_name: Option<&str>,
+    project: Option<&Path>,
+) -> Result<()> {
+    let (target, name) = claude_profile(profile_name, project)?;
+    let (added, live) = edit_profile(&target, &name, |dir| {
+        permissions::add_hook(dir, event, matcher, command)
+    })?;
+
+    let hook = HookInfo {
+        event: event.to_string(),
+        matcher: matcher.map(String::from),
+        command: command.to_string(),
+    };
+    if !added {
+        println!("Profile {} already has hook: {hook}", name.as_str());
+        return Ok(());
+    }
+    println!(
+        "Added hook {hook} to profile: {}{}",
+        name.as_str(),
+        live_suffix(live)
+    );
+    Ok(())
+}
+
+pub fn remove(
+    event: &str,
+    command: &str,
+    matcher: Option<&str>,
+    profile_name: Option<&str>,
+    project: Option<&Path>,
+) -> Result<()> {
+    let (target, name) = claude_profile(profile_name, project)?;
+    let (removed, live) = edit_profile(&target, &name, |dir| {
+        permissions::remove_hook(dir, event, matcher, command)
+    })?;
+
+    if removed == 0 {
+        return Err(Error::Config(format!(
+            "hook not found in profile {}: {event}: {command}",
+            name.as_str()
+        )));
+    }
+    println!(
+        "Removed {event} hook {command} from profile: {}{}",
+        name.as_str(),
+        live_suffix(live)
+    );
+    Ok(())
+}
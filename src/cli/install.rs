@@ -4,16 +4,28 @@ use std::io::IsTerminal;
 
 use color_eyre::eyre::{Result, eyre};
 use dialoguer_multiselect::theme::ColorfulTheme;
-use dialoguer_multiselect::{GroupMultiSelect, ItemState};
+use dialoguer_multiselect::{Confirm, GroupMultiSelect, ItemState};
 
-use harness_locate::{Harness, HarnessKind, Scope, Severity, validate_agent_for_harness};
+use std::path::{Path, PathBuf};
 
-use crate::config::{BridleConfig, ProfileManager};
-use crate::harness::HarnessConfig;
-use crate::install::discovery::{DiscoveryError, discover_skills};
-use crate::install::installer::{install_agent, install_command, install_skills};
+use harness_locate::Scope;
+
+use crate::cli::profile::project_root;
+use crate::config::{BridleConfig, ProfileManager, ProfileName};
+use crate::install::compat::{
+    count_incompatible_agents, harness_supports_agents, harness_supports_commands,
+    harness_supports_mcp, harness_supports_skills, install_harnesses,
+};
+use crate::install::discovery::{DiscoveryError, discover_skills, normalize_source};
+use crate::install::installer::{
+    InstallOutcome, InstallResult, install_agent, install_command, install_mcp_server,
+    install_skills,
+};
+use crate::install::registry::resolve_source;
+use crate::install::scan::{ScanReport, scan};
 use crate::install::{
-    AgentInfo, CommandInfo, DiscoveryResult, InstallOptions, InstallTarget, McpInfo, SkillInfo,
+    Dependency, DependencyKind, DiscoveryResult, InstallFailure, InstallOptions, InstallReport,
+    InstallTarget,
 };
 
 type TargetGroup = (
@@ -23,79 +35,74 @@ type TargetGroup = (
     Vec<bool>,
 );
 
-fn harness_supports_skills(harness_id: &str) -> bool {
-    parse_harness_kind(harness_id)
-        .and_then(|kind| Harness::locate(kind).ok())
-        .and_then(|h| h.skills(&Scope::Global).ok().flatten())
-        .is_some()
-}
-
-fn harness_supports_agents(harness_id: &str) -> bool {
-    parse_harness_kind(harness_id)
-        .and_then(|kind| Harness::locate(kind).ok())
-        .and_then(|h| h.agents(&Scope::Global).ok().flatten())
-        .is_some()
-}
-
-fn harness_supports_commands(harness_id: &str) -> bool {
-    parse_harness_kind(harness_id)
-        .and_then(|kind| Harness::locate(kind).ok())
-        .and_then(|h| h.commands(&Scope::Global).ok().flatten())
-        .is_some()
-}
+/// Offers to add dependencies that the same source provides and warns about the rest.
+///
+/// Dependencies pulled in this way are followed transitively.
+fn resolve_dependencies(discovery: &DiscoveryResult, selected: &mut DiscoveryResult) -> Result<()> {
+    let missing = selected.missing_dependencies();
+    let (available, unresolved): (Vec<_>, Vec<_>) =
+        missing.into_iter().partition(|dep| discovery.provides(dep));
+
+    for dep in &unresolved {
+        eprintln!(
+            "  ! {} is required but not provided by {}; make sure it is installed",
+            dep, selected.source
+        );
+    }
 
-fn count_incompatible_agents(agents: &[AgentInfo], kind: HarnessKind) -> usize {
-    agents
-        .iter()
-        .filter(|a| {
-            let issues = validate_agent_for_harness(&a.content, kind);
-            issues.iter().any(|i| i.severity == Severity::Error)
-        })
-        .count()
-}
+    if available.is_empty() {
+        return Ok(());
+    }
 
-fn parse_harness_kind(id: &str) -> Option<HarnessKind> {
-    match id {
-        "claude-code" | "claude" | "cc" => Some(HarnessKind::ClaudeCode),
-        "opencode" | "oc" => Some(HarnessKind::OpenCode),
-        "goose" => Some(HarnessKind::Goose),
-        "amp-code" | "amp" | "ampcode" => Some(HarnessKind::AmpCode),
-        _ => None,
+    let names: Vec<String> = available.iter().map(|d| d.to_string()).collect();
+    let confirmed = Confirm::with_theme(&ColorfulTheme::default())
+        .with_prompt(format!(
+            "Selected components also require {}. Install them too?",
+            names.join(", ")
+        ))
+        .default(true)
+        .interact()?;
+    if !confirmed {
+        return Ok(());
     }
-}
 
-/// Selected components from the discovery result
-struct SelectedComponents {
-    skills: Vec<SkillInfo>,
-    mcp_servers: Vec<McpInfo>,
-    agents: Vec<AgentInfo>,
-    commands: Vec<CommandInfo>,
-}
+    let mut pending = available;
+    while let Some(dep) = pending.pop() {
+        selected.add(discovery, &dep);
+        for next in selected.missing_dependencies() {
+            if discovery.provides(&next) && !pending.contains(&next) {
+                pending.push(next);
+            }
+        }
+    }
 
-impl SelectedComponents {
-    fn is_empty(&self) -> bool {
-        self.skills.is_empty()
-            && self.mcp_servers.is_empty()
-            && self.agents.is_empty()
-            && self.commands.is_empty()
+    for dep in selected.missing_dependencies() {
+        if !unresolved.contains(&dep) {
+            eprintln!(
+                "  ! {} is required but not provided by {}; make sure it is installed",
+                dep, selected.source
+            );
+        }
     }
+    Ok(())
 }
 
-pub fn run(source: &str, force: bool) -> Result<()> {
-    if !std::io::stdin().is_terminal() {
-        return Err(eyre!(
-            "Interactive mode requires a terminal. Use --help for non-interactive options."
-        ));
+fn discover(source: &str) -> Result<DiscoveryResult> {
+    let resolved = resolve_source(source)?;
+    if resolved != source {
+        eprintln!("Resolved {} to {}", source, resolved);
     }
-
-    let url = normalize_source(source);
+    let source = resolved;
+    let url = normalize_source(&source);
 
     eprintln!("Discovering components from {}...", url);
 
     let discovery = discover_skills(&url).map_err(|e| match e {
         DiscoveryError::InvalidUrl(msg) => eyre!("Invalid URL: {}", msg),
         DiscoveryError::FetchError(e) => eyre!("Failed to fetch repository: {}", e),
-        DiscoveryError::NoSkillsFound => eyre!("No installable components found in repository"),
+        DiscoveryError::NoSkillsFound => {
+            eyre!("No installable components found in {}", url)
+        }
     })?;
 
     // Build summary of what was found
@@ -113,161 +120,375 @@ pub fn run(source: &str, force: bool) -> Result<()> {
         found_parts.push(format!("{} command(s)", discovery.commands.len()));
     }
 
-    if found_parts.is_empty() {
-        eprintln!("No installable components found in {}", url);
-        return Ok(());
+    eprintln!("Found {} from {}", found_parts.join(", "), discovery.source);
+    Ok(discovery)
+}
+
+/// Options for `bridle install`.
+pub struct InstallArgs<'a> {
+    pub sources: &'a [String],
+    pub force: bool,
+    /// Install everything discovered into active profiles without prompting.
+    pub yes: bool,
+    /// Allow components flagged by the security scan.
+    pub allow_risky: bool,
+    /// Install into this checkout's project profiles instead of the global ones.
+    pub project: Option<&'a Path>,
+}
+
+pub fn run(args: InstallArgs<'_>) -> Result<()> {
+    if !args.yes && !std::io::stdin().is_terminal() {
+        return Err(eyre!(
+            "Interactive mode requires a terminal. Use --yes to install everything into active profiles."
+        ));
     }
 
-    eprintln!(
-        "Found {} from {}/{}",
-        found_parts.join(", "),
-        discovery.source.owner,
-        discovery.source.repo
-    );
+    let project = args.project.map(project_root).transpose()?;
 
-    let selected = select_components(&discovery)?;
+    let discoveries = args
+        .sources
+        .iter()
+        .map(|source| discover(source))
+        .collect::<Result<Vec<_>>>()?;
 
-    if selected.is_empty() {
-        eprintln!("No components selected");
-        return Ok(());
-    }
+    let scans: Vec<ScanReport> = discoveries.iter().map(scan).collect();
+    print_findings(&discoveries, &scans);
 
-    let targets = select_targets(&selected)?;
+    let (selected, targets) = if args.yes {
+        if !args.allow_risky && scans.iter().any(|s| !s.is_empty()) {
+            return Err(eyre!(
+                "Security scan flagged components. Review them interactively or pass --allow-risky."
+            ));
+        }
+        let selected: Vec<DiscoveryResult> = discoveries.to_vec();
+        for selection in &selected {
+            for dep in selection.missing_dependencies() {
+                eprintln!(
+                    "  ! {} is required but not provided by {}; make sure it is installed",
+                    dep, selection.source
+                );
+            }
+        }
+        let targets = active_targets(&selected, project.as_deref())?;
+        (selected, targets)
+    } else {
+        let selected = select_components(&discoveries, &scans, args.allow_risky)?;
+        if selected.is_empty() {
+            eprintln!("No components selected");
+            return Ok(());
+        }
+        let targets = select_targets(&selected, project.as_deref())?;
+        (selected, targets)
+    };
 
     if targets.is_empty() {
         eprintln!("No targets selected");
         return Ok(());
     }
 
-    let options = InstallOptions { force };
+    let options = InstallOptions { force: args.force };
+    let mut report = InstallReport::default();
 
     for target in &targets {
         eprintln!("\nInstalling to {}/{}...", target.harness, target.profile);
 
-        // Install skills
-        if !selected.skills.is_empty() {
-            let report = install_skills(&selected.skills, target, &options);
-
-            for success in &report.installed {
-                eprintln!("  + Installed skill: {}", success.skill);
-            }
-            for skip in &report.skipped {
-                eprintln!("  = Skipped skill: {} (already exists)", skip.skill);
-            }
-            for error in &report.errors {
-                eprintln!(
-                    "  ! Error installing skill {}: {}",
-                    error.skill, error.error
-                );
+        for selection in &selected {
+            if selected.len() > 1 {
+                eprintln!("  from {}:", selection.source);
             }
+            report.merge(install_selection(selection, target, &options));
         }
+    }
 
-        // Install agents
-        if !selected.agents.is_empty() && !harness_supports_agents(&target.harness) {
-            eprintln!(
-                "  ~ Skipping {} agent(s) - not supported by {}",
-                selected.agents.len(),
-                target.harness
-            );
-        } else {
-            for agent in &selected.agents {
-                match install_agent(agent, target, &options) {
-                    Ok(crate::install::installer::InstallOutcome::Installed(success)) => {
-                        eprintln!("  + Installed agent: {}", success.skill);
-                    }
-                    Ok(crate::install::installer::InstallOutcome::Skipped(skip)) => {
-                        eprintln!("  = Skipped agent: {} (already exists)", skip.skill);
-                    }
-                    Err(e) => {
-                        eprintln!("  ! Error installing agent {}: {}", agent.name, e);
-                    }
-                }
-            }
+    eprintln!(
+        "\nDone! {} installed, {} skipped, {} failed",
+        report.installed.len(),
+        report.skipped.len(),
+        report.errors.len()
+    );
+    Ok(())
+}
+
+fn print_findings(discoveries: &[DiscoveryResult], scans: &[ScanReport]) {
+    for (discovery, scan) in discoveries.iter().zip(scans) {
+        if scan.is_empty() {
+            continue;
+        }
+        eprintln!("Security scan of {} flagged:", discovery.source);
+        for finding in &scan.findings {
+            eprintln!("  ! {}: {}", finding.component, finding.reason);
         }
+    }
+}
+
+/// Scope for install targets: the project checkout when given, otherwise global.
+fn target_scope(project: Option<&Path>) -> Scope {
+    match project {
+        Some(root) => Scope::Project(root.to_path_buf()),
+        None => Scope::Global,
+    }
+}
+
+/// The active profile of every harness that can take at least one selected component.
+fn active_targets(
+    selected: &[DiscoveryResult],
+    project: Option<&Path>,
+) -> Result<Vec<InstallTarget>> {
+    let config = BridleConfig::load()?;
+    let scope = target_scope(project);
+    let has = |f: fn(&DiscoveryResult) -> bool| selected.iter().any(f);
+
+    let mut targets = Vec::new();
+    for entry in install_harnesses(project) {
+        let harness_id = entry.id.as_str();
+        let Some(active) = config.active_profile_in(&scope, harness_id) else {
+            continue;
+        };
+        let Ok(profile) = ProfileName::new(active) else {
+            continue;
+        };
+
+        let supported = (has(|s| !s.skills.is_empty()) && harness_supports_skills(harness_id))
+            || (has(|s| !s.agents.is_empty()) && harness_supports_agents(harness_id))
+            || (has(|s| !s.commands.is_empty()) && harness_supports_commands(harness_id))
+            || (has(|s| !s.mcp_servers.is_empty()) && harness_supports_mcp(harness_id));
+        if supported {
+            targets.push(InstallTarget {
+                harness: harness_id.to_string(),
+                profile,
+                project: project.map(PathBuf::from),
+            });
+        }
+    }
+
+    if targets.is_empty() {
+        return Err(match project {
+            Some(root) => eyre!(
+                "No active project profiles in {}. Switch to one first with: bridle profile switch --project <path> <harness> <name>",
+                root.display()
+            ),
+            None => eyre!(
+                "No active profiles to install into. Switch to a profile first with: bridle profile switch <harness> <name>"
+            ),
+        });
+    }
+    Ok(targets)
+}
 
-        // Install commands
-        if !selected.commands.is_empty() && !harness_supports_commands(&target.harness) {
+/// Installs one source's selected components into a target, logging each outcome.
+fn install_selection(
+    selected: &DiscoveryResult,
+    target: &InstallTarget,
+    options: &InstallOptions,
+) -> InstallReport {
+    let mut report = InstallReport::default();
+
+    // Install skills
+    if !selected.skills.is_empty() && !harness_supports_skills(&target.harness) {
+        eprintln!(
+            "  ~ Skipping {} skill(s) - not supported by {}",
+            selected.skills.len(),
+            target.harness
+        );
+    } else if !selected.skills.is_empty() {
+        let skills_report =
+            install_skills(&selected.skills, target, options, Some(&selected.source));
+
+        for success in &skills_report.installed {
+            eprintln!("  + Installed skill: {}", success.skill);
+        }
+        for skip in &skills_report.skipped {
+            eprintln!("  = Skipped skill: {} (already exists)", skip.skill);
+        }
+        for error in &skills_report.errors {
             eprintln!(
-                "  ~ Skipping {} command(s) - not supported by {}",
-                selected.commands.len(),
-                target.harness
+                "  ! Error installing skill {}: {}",
+                error.skill, error.error
             );
-        } else {
-            for cmd in &selected.commands {
-                match install_command(cmd, target, &options) {
-                    Ok(crate::install::installer::InstallOutcome::Installed(success)) => {
-                        eprintln!("  + Installed command: {}", success.skill);
-                    }
-                    Ok(crate::install::installer::InstallOutcome::Skipped(skip)) => {
-                        eprintln!("  = Skipped command: {} (already exists)", skip.skill);
-                    }
-                    Err(e) => {
-                        eprintln!("  ! Error installing command {}: {}", cmd.name, e);
-                    }
-                }
-            }
         }
+        report.merge(skills_report);
+    }
+
+    // Install agents
+    if !selected.agents.is_empty() && !harness_supports_agents(&target.harness) {
+        eprintln!(
+            "  ~ Skipping {} agent(s) - not supported by {}",
+            selected.agents.len(),
+            target.harness
+        );
+    } else {
+        for agent in &selected.agents {
+            let outcome = install_agent(agent, target, options, Some(&selected.source));
+            record_outcome(&mut report, "agent", &agent.name, target, outcome);
+        }
+    }
 
-        // TODO: Install MCP servers when installer is implemented
+    // Install commands
+    if !selected.commands.is_empty() && !harness_supports_commands(&target.harness) {
+        eprintln!(
+            "  ~ Skipping {} command(s) - not supported by {}",
+            selected.commands.len(),
+            target.harness
+        );
+    } else {
+        for cmd in &selected.commands {
+            let outcome = install_command(cmd, target, options, Some(&selected.source));
+            record_outcome(&mut report, "command", &cmd.name, target, outcome);
+        }
+    }
+
+    // Install MCP servers
+    if !selected.mcp_servers.is_empty() && !harness_supports_mcp(&target.harness) {
+        eprintln!(
+            "  ~ Skipping {} MCP server(s) - not supported by {}",
+            selected.mcp_servers.len(),
+            target.harness
+        );
+    } else {
         for mcp in &selected.mcp_servers {
-            eprintln!(
-                "  ~ MCP server: {} (installer not yet implemented)",
-                mcp.name
-            );
+            let outcome = install_mcp_server(mcp, target, options, Some(&selected.source));
+            record_outcome(&mut report, "MCP server", &mcp.name, target, outcome);
         }
     }
 
-    eprintln!("\nDone!");
-    Ok(())
+    report
 }
 
-/// Select components to install using grouped multi-select UI
-fn select_components(discovery: &DiscoveryResult) -> Result<SelectedComponents> {
-    // Build groups for each non-empty category
-    let mut groups: Vec<(&str, Vec<String>, Vec<usize>)> = Vec::new();
-
-    if !discovery.skills.is_empty() {
-        let names: Vec<String> = discovery.skills.iter().map(|s| s.name.clone()).collect();
-        let indices: Vec<usize> = (0..discovery.skills.len()).collect();
-        groups.push(("Skills", names, indices));
+fn record_outcome(
+    report: &mut InstallReport,
+    kind: &str,
+    name: &str,
+    target: &InstallTarget,
+    outcome: InstallResult,
+) {
+    match outcome {
+        Ok(InstallOutcome::Installed(success)) => {
+            eprintln!("  + Installed {}: {}", kind, success.skill);
+            report.installed.push(success);
+        }
+        Ok(InstallOutcome::Skipped(skip)) => {
+            eprintln!("  = Skipped {}: {} (already exists)", kind, skip.skill);
+            report.skipped.push(skip);
+        }
+        Err(e) => {
+            eprintln!("  ! Error installing {} {}: {}", kind, name, e);
+            report.errors.push(InstallFailure {
+                skill: name.to_string(),
+                target: target.clone(),
+                error: e.to_string(),
+            });
+        }
     }
+}
 
-    if !discovery.mcp_servers.is_empty() {
-        let names: Vec<String> = discovery
-            .mcp_servers
-            .iter()
-            .map(|m| m.name.clone())
-            .collect();
-        let indices: Vec<usize> = (0..discovery.mcp_servers.len()).collect();
-        groups.push(("MCP Servers", names, indices));
+#[derive(Clone, Copy)]
+enum Category {
+    Skills,
+    McpServers,
+    Agents,
+    Commands,
+}
+
+impl Category {
+    fn label(self) -> &'static str {
+        match self {
+            Category::Skills => "Skills",
+            Category::McpServers => "MCP Servers",
+            Category::Agents => "Agents",
+            Category::Commands => "Commands",
+        }
     }
 
-    if !discovery.agents.is_empty() {
-        let names: Vec<String> = discovery.agents.iter().map(|a| a.name.clone()).collect();
-        let indices: Vec<usize> = (0..discovery.agents.len()).collect();
-        groups.push(("Agents", names, indices));
+    fn kind(self) -> DependencyKind {
+        match self {
+            Category::Skills => DependencyKind::Skill,
+            Category::McpServers => DependencyKind::Mcp,
+            Category::Agents => DependencyKind::Agent,
+            Category::Commands => DependencyKind::Command,
+        }
     }
+}
 
-    if !discovery.commands.is_empty() {
-        let names: Vec<String> = discovery.commands.iter().map(|c| c.name.clone()).collect();
-        let indices: Vec<usize> = (0..discovery.commands.len()).collect();
-        groups.push(("Commands", names, indices));
+/// Select components to install using grouped multi-select UI.
+///
+/// Each source contributes its own groups; with several sources the group
+/// headers name the source so identically named components stay distinct.
+fn select_components(
+    discoveries: &[DiscoveryResult],
+    scans: &[ScanReport],
+    allow_risky: bool,
+) -> Result<Vec<DiscoveryResult>> {
+    // (header, item names, discovery index, category)
+    let mut groups: Vec<(String, Vec<String>, usize, Category)> = Vec::new();
+
+    for (idx, discovery) in discoveries.iter().enumerate() {
+        let categories = [
+            (
+                Category::Skills,
+                discovery
+                    .skills
+                    .iter()
+                    .map(|s| s.name.clone())
+                    .collect::<Vec<_>>(),
+            ),
+            (
+                Category::McpServers,
+                discovery
+                    .mcp_servers
+                    .iter()
+                    .map(|m| m.name.clone())
+                    .collect(),
+            ),
+            (
+                Category::Agents,
+                discovery.agents.iter().map(|a| a.name.clone()).collect(),
+            ),
+            (
+                Category::Commands,
+                discovery.commands.iter().map(|c| c.name.clone()).collect(),
+            ),
+        ];
+
+        for (category, names) in categories {
+            if names.is_empty() {
+                continue;
+            }
+            let header = if discoveries.len() > 1 {
+                format!("{} ({})", category.label(), discovery.source)
+            } else {
+                category.label().to_string()
+            };
+            groups.push((header, names, idx, category));
+        }
     }
 
     if groups.is_empty() {
-        return Ok(SelectedComponents {
-            skills: Vec::new(),
-            mcp_servers: Vec::new(),
-            agents: Vec::new(),
-            commands: Vec::new(),
-        });
+        return Ok(Vec::new());
     }
 
-    // All items selected by default
-    let defaults: Vec<Vec<bool>> = groups
-        .iter()
-        .map(|(_, names, _)| vec![true; names.len()])
-        .collect();
+    // Flagged items carry their scan findings and start unselected unless
+    // --allow-risky was given; everything else is selected by default.
+    let mut defaults: Vec<Vec<bool>> = Vec::new();
+    let mut items: Vec<Vec<(String, ItemState)>> = Vec::new();
+    for (_, names, discovery_idx, category) in &groups {
+        let mut group_defaults = Vec::new();
+        let mut group_items = Vec::new();
+        for name in names {
+            let reasons =
+                scans[*discovery_idx].reasons_for(&Dependency::new(category.kind(), name));
+            let state = if reasons.is_empty() {
+                ItemState::Normal
+            } else {
+                ItemState::Warning {
+                    message: reasons.join("; "),
+                }
+            };
+            group_defaults.push(reasons.is_empty() || allow_risky);
+            group_items.push((name.clone(), state));
+        }
+        defaults.push(group_defaults);
+        items.push(group_items);
+    }
 
     let theme = ColorfulTheme::default();
     let mut group_select = GroupMultiSelect::new()
@@ -275,90 +496,58 @@ fn select_components(discovery: &DiscoveryResult) -> Result<SelectedComponents>
         .with_prompt("Select components to install (Esc to cancel)")
         .defaults(defaults);
 
-    for (category, names, _) in &groups {
-        let name_refs: Vec<&str> = names.iter().map(|s| s.as_str()).collect();
-        group_select = group_select.group(*category, name_refs);
+    for ((header, _, _, _), group_items) in groups.iter().zip(items) {
+        group_select = group_select.group_with_states(header.as_str(), group_items);
     }
 
     let Some(selections) = group_select.interact_opt()? else {
-        return Ok(SelectedComponents {
-            skills: Vec::new(),
-            mcp_servers: Vec::new(),
-            agents: Vec::new(),
-            commands: Vec::new(),
-        });
+        return Ok(Vec::new());
     };
 
     // Map selections back to discovery items
-    let mut selected = SelectedComponents {
-        skills: Vec::new(),
-        mcp_servers: Vec::new(),
-        agents: Vec::new(),
-        commands: Vec::new(),
-    };
+    let mut selected: Vec<DiscoveryResult> = discoveries
+        .iter()
+        .map(|d| DiscoveryResult::empty(&d.source))
+        .collect();
 
     for (group_idx, selected_indices) in selections.iter().enumerate() {
-        let (category, _, _) = &groups[group_idx];
-        match *category {
-            "Skills" => {
-                for &idx in selected_indices {
-                    selected.skills.push(discovery.skills[idx].clone());
-                }
-            }
-            "MCP Servers" => {
-                for &idx in selected_indices {
-                    selected
-                        .mcp_servers
-                        .push(discovery.mcp_servers[idx].clone());
-                }
-            }
-            "Agents" => {
-                for &idx in selected_indices {
-                    selected.agents.push(discovery.agents[idx].clone());
-                }
+        let (_, _, discovery_idx, category) = &groups[group_idx];
+        let discovery = &discoveries[*discovery_idx];
+        let selection = &mut selected[*discovery_idx];
+        for &idx in selected_indices {
+            match category {
+                Category::Skills => selection.skills.push(discovery.skills[idx].clone()),
+                Category::McpServers => selection
+                    .mcp_servers
+                    .push(discovery.mcp_servers[idx].clone()),
+                Category::Agents => selection.agents.push(discovery.agents[idx].clone()),
+                Category::Commands => selection.commands.push(discovery.commands[idx].clone()),
             }
-            "Commands" => {
-                for &idx in selected_indices {
-                    selected.commands.push(discovery.commands[idx].clone());
-                }
-            }
-            _ => {}
         }
     }
 
-    Ok(selected)
-}
-
-fn normalize_source(source: &str) -> String {
-    if source.starts_with("http://") || source.starts_with("https://") {
-        source.to_string()
-    } else if source.contains('/') && !source.contains(':') {
-        format!("https://github.com/{}", source)
-    } else {
-        source.to_string()
+    for (discovery, selection) in discoveries.iter().zip(selected.iter_mut()) {
+        resolve_dependencies(discovery, selection)?;
     }
+
+    selected.retain(|s| !s.is_empty());
+    Ok(selected)
 }
 
-fn select_targets(selected: &SelectedComponents) -> Result<Vec<InstallTarget>> {
+fn select_targets(
+    selected: &[DiscoveryResult],
+    project: Option<&Path>,
+) -> Result<Vec<InstallTarget>> {
     let config = BridleConfig::load()?;
-    let profiles_dir = BridleConfig::profiles_dir()?;
+    let scope = target_scope(project);
+    let profiles_dir = BridleConfig::profiles_dir_for(&scope)?;
     let manager = ProfileManager::new(profiles_dir);
 
-    let harness_kinds = [
-        HarnessKind::OpenCode,
-        HarnessKind::ClaudeCode,
-        HarnessKind::Goose,
-        HarnessKind::AmpCode,
-    ];
-
     let mut groups: Vec<TargetGroup> = Vec::new();
 
-    for kind in &harness_kinds {
-        let Ok(harness) = Harness::locate(*kind) else {
-            continue;
-        };
-        let harness_id = harness.id();
-        let Ok(profiles) = manager.list_profiles(&harness) else {
+    for entry in install_harnesses(project) {
+        let harness_id = entry.id.as_str();
+        let Ok(profiles) = manager.list_profiles(entry.config().as_ref()) else {
             continue;
         };
 
@@ -366,29 +555,34 @@ fn select_targets(selected: &SelectedComponents) -> Result<Vec<InstallTarget>> {
             continue;
         }
 
-        let active_profile = config.active_profile_for(harness_id);
+        let active_profile = config.active_profile_in(&scope, harness_id);
         let supports_skills = harness_supports_skills(harness_id);
         let supports_agents = harness_supports_agents(harness_id);
         let supports_commands = harness_supports_commands(harness_id);
 
-        let can_install_skills = supports_skills && !selected.skills.is_empty();
-        let can_install_agents = supports_agents && !selected.agents.is_empty();
-        let can_install_commands = supports_commands && !selected.commands.is_empty();
-        let can_install_mcp = !selected.mcp_servers.is_empty(); // TODO: add harness MCP support check
+        let has_skills = selected.iter().any(|s| !s.skills.is_empty());
+        let has_agents = selected.iter().any(|s| !s.agents.is_empty());
+        let has_commands = selected.iter().any(|s| !s.commands.is_empty());
+        let has_mcp = selected.iter().any(|s| !s.mcp_servers.is_empty());
+
+        let can_install_skills = supports_skills && has_skills;
+        let can_install_agents = supports_agents && has_agents;
+        let can_install_commands = supports_commands && has_commands;
+        let can_install_mcp = harness_supports_mcp(harness_id) && has_mcp;
 
         let can_install_anything =
             can_install_skills || can_install_agents || can_install_commands || can_install_mcp;
 
         let mut skipped: Vec<&str> = Vec::new();
-        if !selected.agents.is_empty() && !supports_agents {
+        if has_agents && !supports_agents {
             skipped.push("agents");
         }
-        if !selected.commands.is_empty() && !supports_commands {
+        if has_commands && !supports_commands {
             skipped.push("commands");
         }
 
-        let incompatible_agent_count = if supports_agents && !selected.agents.is_empty() {
-            count_incompatible_agents(&selected.agents, *kind)
+        let incompatible_agent_count = if supports_agents && has_agents {
+            count_incompatible_agents(selected.iter().flat_map(|s| &s.agents), entry.kind)
         } else {
             0
         };
@@ -433,6 +627,7 @@ fn select_targets(selected: &SelectedComponents) -> Result<Vec<InstallTarget>> {
             targets.push(InstallTarget {
                 harness: harness_id.to_string(),
                 profile,
+                project: project.map(PathBuf::from),
             });
             defaults.push(default_selected);
         }
@@ -472,28 +667,3 @@ fn select_targets(selected: &SelectedComponents) -> Result<Vec<InstallTarget>> {
 
     Ok(selected_targets)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn normalize_source_handles_shorthand() {
-        assert_eq!(
-            normalize_source("owner/repo"),
-            "https://github.com/owner/repo"
-        );
-    }
-
-    #[test]
-    fn normalize_source_preserves_full_url() {
-        let url = "https://github.com/owner/repo";
-        assert_eq!(normalize_source(url), url);
-    }
-
-    #[test]
-    fn normalize_source_preserves_http() {
-        let url = "http://example.com/repo";
-        assert_eq!(normalize_source(url), url);
-    }
-}
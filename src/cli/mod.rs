@@ -1,13 +1,25 @@
 //! CLI module for bridle.
 
+pub mod auto;
 mod commands;
 pub mod config_cmd;
+pub mod harnesses;
+pub mod hooks;
 pub mod init;
 pub mod install;
+pub mod model;
 pub mod output;
+pub mod permissions;
+pub mod plugin;
 pub mod profile;
+pub mod rules;
+pub mod search;
 pub mod status;
+pub mod trash;
 pub mod tui;
 pub mod uninstall;
 
-pub use commands::{Commands, ConfigCommands, ProfileCommands};
+pub use commands::{
+    Commands, ConfigCommands, HooksCommands, ModelCommands, PermissionsCommands, PluginCommands,
+    ProfileCommands, RulesCommands, TrashCommands,
+};
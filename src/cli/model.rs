@@ -0,0 +1,176 @@
+//! CLI model command implementation.
+
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::cli::output::{ResolvedFormat, output_list};
+use crate::cli::profile::{Target, profile_or_active, profile_targets};
+use crate::config::{BridleConfig, ProfileName, model};
+use crate::error::{Error, Result};
+
+/// The harness name that selects every harness with a model setting.
+const ALL: &str = "all";
+
+#[derive(Serialize)]
+struct ModelInfo {
+    harness_id: String,
+    profile: String,
+    model: Option<String>,
+    provider: Option<String>,
+}
+
+#[derive(Serialize)]
+struct ModelChange {
+    harness_id: String,
+    profile: String,
+    model: String,
+    live: bool,
+}
+
+#[derive(Serialize)]
+struct AliasEntry {
+    alias: String,
+    models: std::collections::BTreeMap<String, String>,
+}
+
+/// The profiles `harness_name` selects: one, or one per harness for `all`.
+fn targets(
+    harness_name: &str,
+    profile_name: Option<&str>,
+    project: Option<&Path>,
+) -> Result<Vec<(Target, ProfileName)>> {
+    if harness_name == ALL {
+        return profile_targets(project, profile_name, |e| {
+            e.config().model_setting().is_some()
+        });
+    }
+    let target = Target::resolve(harness_name, project)?;
+    if target.config().model_setting().is_none() {
+        return Err(Error::Config(format!(
+            "{} has no model setting",
+            target.config().id()
+        )));
+    }
+    let name = profile_or_active(&target, profile_name)?;
+    Ok(vec![(target, name)])
+}
+
+pub fn get(
+    harness_name: &str,
+    profile_name: Option<&str>,
+    project: Option<&Path>,
+    format: ResolvedFormat,
+) -> Result<()> {
+    let mut entries = Vec::new();
+    for (target, name) in targets(harness_name, profile_name, project)? {
+        let harness = target.config();
+        let manager = target.manager()?;
+        let info = manager.show_profile(harness, &name)?;
+        entries.push(ModelInfo {
+            harness_id: harness.id().to_string(),
+            profile: name.as_str().to_string(),
+            model: info.model,
+            provider: model::read_provider(harness, &info.path),
+        });
+    }
+
+    output_list(&entries, format, |entries| {
+        if entries.is_empty() {
+            println!("No harness has an active profile");
+        }
+        for entry in entries {
+            let model = entry.model.as_deref().unwrap_or("(not set)");
+            match &entry.provider {
+                Some(provider) => println!(
+                    "{} {}: {model} ({provider})",
+                    entry.harness_id, entry.profile
+                ),
+                None => println!("{} {}: {model}", entry.harness_id, entry.profile),
+            }
+        }
+    });
+    Ok(())
+}
+
+/// `args` is `[PROFILE] MODEL`; the model may be an alias from [`model::aliases`].
+pub fn set(
+    harness_name: &str,
+    args: &[String],
+    project: Option<&Path>,
+    format: ResolvedFormat,
+) -> Result<()> {
+    let (profile_name, requested) = match args {
+        [model] => (None, model.as_str()),
+        [profile, model] => (Some(profile.as_str()), model.as_str()),
+        _ => return Err(Error::InvalidValue("expected [PROFILE] MODEL".to_string())),
+    };
+    let config = BridleConfig::load()?;
+    if harness_name == ALL && !model::aliases(&config).contains_key(requested) {
+        return Err(Error::InvalidValue(format!(
+            "'{requested}' is not a model alias (see `bridle model aliases`)"
+        )));
+    }
+
+    let mut changes = Vec::new();
+    let mut skipped = Vec::new();
+    for (target, name) in targets(harness_name, profile_name, project)? {
+        let harness = target.config();
+        let value = match model::resolve(&config, requested, harness.id()) {
+            Ok(value) => value,
+            Err(_) if harness_name == ALL => {
+                skipped.push(harness.id().to_string());
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+
+        let profile_path = target.manager()?.profile_path(harness, &name);
+        model::write_model(harness, &profile_path, &value)?;
+        let live = target.active_profile()?.as_deref() == Some(name.as_str());
+        if live {
+            model::write_model(harness, &harness.config_dir()?, &value)?;
+        }
+        changes.push(ModelChange {
+            harness_id: harness.id().to_string(),
+            profile: name.as_str().to_string(),
+            model: value,
+            live,
+        });
+    }
+
+    output_list(&changes, format, |changes| {
+        if changes.is_empty() {
+            println!("No profiles changed");
+        }
+        for change in changes {
+            let live = if change.live { " (live)" } else { "" };
+            println!(
+                "Set model for {} {}: {}{live}",
+                change.harness_id, change.profile, change.model
+            );
+        }
+        if !skipped.is_empty() {
+            println!("No '{requested}' model for: {}", skipped.join(", "));
+        }
+    });
+    Ok(())
+}
+
+pub fn list_aliases(format: ResolvedFormat) -> Result<()> {
+    let config = BridleConfig::load()?;
+    let entries: Vec<AliasEntry> = model::aliases(&config)
+        .into_iter()
+        .map(|(alias, models)| AliasEntry { alias, models })
+        .collect();
+
+    output_list(&entries, format, |entries| {
+        for entry in entries {
+            println!("{}:", entry.alias);
+            for (harness, model) in &entry.models {
+                println!("  {harness}: {model}");
+            }
+        }
+    });
+    Ok(())
+}
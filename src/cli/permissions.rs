@@ -0,0 +1,173 @@
+//! CLI permissions command implementation.
+
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::cli::output::{ResolvedFormat, output};
+use crate::cli::plugin::{claude_profile, edit_profile, live_suffix, profile_path};
+use crate::config::diff::{FieldChange, PermissionChange, ProfileDiff, permission_changes};
+use crate::config::{PermissionEffect, PermissionRules, ProfileName, permissions};
+use crate::error::{Error, Result};
+
+#[derive(Serialize)]
+struct PermissionsInfo {
+    profile: String,
+    #[serde(flatten)]
+    rules: PermissionRules,
+}
+
+#[derive(Serialize)]
+struct PermissionsDiff {
+    left: String,
+    right: String,
+    mode: Option<FieldChange>,
+    permissions: Vec<PermissionChange>,
+    hooks_added: Vec<String>,
+    hooks_removed: Vec<String>,
+}
+
+pub fn list(
+    profile_name: Option<&str>,
+    project: Option<&Path>,
+    format: ResolvedFormat,
+) -> Result<()> {
+    let (target, name) = claude_profile(profile_name, project)?;
+    let info = target.manager()?.show_profile(target.config(), &name)?;
+    let info = PermissionsInfo {
+        profile: name.as_str().to_string(),
+        rules: info.permissions.unwrap_or_default(),
+    };
+
+    output(&info, format, |info| {
+        println!("Permissions in {}:", info.profile);
+        if let Some(mode) = &info.rules.default_mode {
+            println!("  mode: {mode}");
+        }
+        if info.rules.rules().next().is_none() {
+            println!("  (no rules)");
+        }
+        for (effect, rule) in info.rules.rules() {
+            println!("  {} {rule}", effect.as_str());
+        }
+    });
+    Ok(())
+}
+
+pub fn add(
+    effect: PermissionEffect,
+    rule: &str,
+    profile_name: Option<&str>,
+    project: Option<&Path>,
+) -> Result<()> {
+    let (target, name) = claude_profile(profile_name, project)?;
+    let (previous, live) = edit_profile(&target, &name, |dir| {
+        permissions::add_rule(dir, effect, rule)
+    })?;
+
+    let verb = match previous {
+        Some(previous) if previous == effect => "Kept",
+        Some(_) => "Moved",
+        None => "Added",
+    };
+    println!(
+        "{verb} {} rule {rule} in profile: {}{}",
+        effect.as_str(),
+        name.as_str(),
+        live_suffix(live)
+    );
+    Ok(())
+}
+
+pub fn remove(rule: &str, profile_name: Option<&str>, project: Option<&Path>) -> Result<()> {
+    let (target, name) = claude_profile(profile_name, project)?;
+    let (previous, live) = edit_profile(&target, &name, |dir| permissions::remove_rule(dir, rule))?;
+
+    let Some(previous) = previous else {
+        return Err(Error::Config(format!(
+            "permission rule not found in profile {}: {rule}",
+            name.as_str()
+        )));
+    };
+    println!(
+        "Removed {} rule {rule} from profile: {}{}",
+        previous.as_str(),
+        name.as_str(),
+        live_suffix(live)
+    );
+    Ok(())
+}
+
+/// Compares the permission rules and hooks of two Claude Code profiles, or of a
+/// profile and the live config, flagging changes that widen access.
+pub fn diff(
+    profile_name: &str,
+    other_name: Option<&str>,
+    project: Option<&Path>,
+    format: ResolvedFormat,
+) -> Result<()> {
+    let (target, name) = claude_profile(Some(profile_name), project)?;
+    let manager = target.manager()?;
+    let left = manager.show_profile(target.config(), &name)?;
+    let right = match other_name {
+        Some(other) => {
+            let other = ProfileName::new(other)
+                .map_err(|_| Error::InvalidProfileName(other.to_string()))?;
+            if !profile_path(&target, &other)?.exists() {
+                return Err(Error::ProfileNotFound(other.as_str().to_string()));
+            }
+            manager.show_profile(target.config(), &other)?
+        }
+        None => manager.show_live(target.config())?,
+    };
+
+    let profile_diff = ProfileDiff::new(&left, &right);
+    let hooks = profile_diff.sections.iter().find(|s| s.label == "Hooks");
+    let diff = PermissionsDiff {
+        left: left.name.clone(),
+        right: right.name.clone(),
+        mode: profile_diff
+            .fields
+            .iter()
+            .find(|f| f.label == "Permission mode")
+            .cloned(),
+        permissions: permission_changes(&left, &right),
+        hooks_added: hooks.map(|h| h.added.clone()).unwrap_or_default(),
+        hooks_removed: hooks.map(|h| h.removed.clone()).unwrap_or_default(),
+    };
+
+    output(&diff, format, |diff| {
+        let unchanged = diff.mode.is_none()
+            && diff.permissions.is_empty()
+            && diff.hooks_added.is_empty()
+            && diff.hooks_removed.is_empty();
+        if unchanged {
+            println!("No permission or hook differences");
+            return;
+        }
+        println!("Permission changes from {} to {}:", diff.left, diff.right);
+        if let Some(mode) = &diff.mode {
+            let show = |v: &Option<String>| v.clone().unwrap_or_else(|| "(not set)".to_string());
+            println!("  ~ mode: {} -> {}", show(&mode.left), show(&mode.right));
+        }
+        for change in &diff.permissions {
+            let sign = if change.added { "+" } else { "-" };
+            let note = if change.widens {
+                "  [widens access]"
+            } else {
+                ""
+            };
+            println!("  {sign} {} {}{note}", change.effect.as_str(), change.rule);
+        }
+        if !diff.hooks_added.is_empty() || !diff.hooks_removed.is_empty() {
+            println!("Hook changes:");
+            for hook in &diff.hooks_removed {
+                println!("  - {hook}");
+            }
+            for hook in &diff.hooks_added {
+                println!("  + {hook}");
+            }
+        }
+    });
+    Ok(())
+}
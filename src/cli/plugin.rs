@@ -0,0 +1,308 @@
+//! CLI plugin command implementation.
+
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::cli::output::{ResolvedFormat, output};
+use crate::cli::profile::{Target, profile_or_active, project_root};
+use crate::config::ProfileName;
+use crate::config::plugins::{self, MarketplaceEntry, PluginEntry};
+use crate::error::{Error, Result};
+use crate::harness::HarnessRegistry;
+use crate::install::discovery::discover_local_components;
+use crate::install::installer::{InstallOutcome, InstallResult, install_agent, install_command};
+use crate::install::{InstallOptions, InstallTarget};
+
+/// Plugins are Claude Code state; every subcommand works on a Claude Code profile.
+const CLAUDE_CODE: &str = "claude-code";
+
+#[derive(Serialize)]
+struct PluginList {
+    profile: String,
+    plugins: Vec<PluginEntry>,
+    marketplaces: Vec<MarketplaceEntry>,
+}
+
+#[derive(Serialize)]
+struct PortedComponent {
+    kind: &'static str,
+    name: String,
+    /// `installed`, `skipped` (already exists), `unsupported`, or `failed`.
+    outcome: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[derive(Serialize)]
+struct PortReport {
+    plugin: String,
+    harness_id: String,
+    profile: String,
+    components: Vec<PortedComponent>,
+}
+
+/// The Claude Code target and profile `profile_name`, or its active profile.
+pub(crate) fn claude_profile(
+    profile_name: Option<&str>,
+    project: Option<&Path>,
+) -> Result<(Target, ProfileName)> {
+    let target = Target::resolve(CLAUDE_CODE, project)?;
+    let name = profile_or_active(&target, profile_name)?;
+    Ok((target, name))
+}
+
+pub(crate) fn profile_path(target: &Target, name: &ProfileName) -> Result<PathBuf> {
+    Ok(target.manager()?.profile_path(target.config(), name))
+}
+
+/// Applies `edit` to profile `name`, and to the live config if the profile is
+/// active. Returns the profile's result and whether the live config was edited.
+pub(crate) fn edit_profile<T>(
+    target: &Target,
+    name: &ProfileName,
+    edit: impl Fn(&Path) -> Result<T>,
+) -> Result<(T, bool)> {
+    let result = edit(&profile_path(target, name)?)?;
+    let live = target.active_profile()?.as_deref() == Some(name.as_str());
+    if live {
+        edit(&target.config().config_dir()?)?;
+    }
+    Ok((result, live))
+}
+
+pub(crate) fn live_suffix(live: bool) -> &'static str {
+    if live { " (live)" } else { "" }
+}
+
+pub fn list(
+    profile_name: Option<&str>,
+    project: Option<&Path>,
+    format: ResolvedFormat,
+) -> Result<()> {
+    let (target, name) = claude_profile(profile_name, project)?;
+    let path = profile_path(&target, &name)?;
+    let list = PluginList {
+        profile: name.as_str().to_string(),
+        plugins: plugins::plugins(&path)?,
+        marketplaces: plugins::marketplaces(&path)?,
+    };
+
+    output(&list, format, |list| {
+        println!("Plugins in {} ({CLAUDE_CODE}):", list.profile);
+        if list.plugins.is_empty() {
+            println!("  (none)");
+        }
+        for plugin in &list.plugins {
+            let state = if plugin.enabled {
+                "enabled"
+            } else {
+                "disabled"
+            };
+            let installed = if plugin.installed { ", installed" } else { "" };
+            println!("  {} ({state}{installed})", plugin.id);
+        }
+        println!("Marketplaces:");
+        if list.marketplaces.is_empty() {
+            println!("  (none)");
+        }
+        for marketplace in &list.marketplaces {
+            println!("  {}: {}", marketplace.name, marketplace.source);
+        }
+    });
+    Ok(())
+}
+
+pub fn set_enabled(
+    plugin: &str,
+    profile_name: Option<&str>,
+    enabled: bool,
+    project: Option<&Path>,
+) -> Result<()> {
+    let (target, name) = claude_profile(profile_name, project)?;
+    plugins::split_id(plugin)?;
+    if !enabled
+        && !plugins::plugins(&profile_path(&target, &name)?)?
+            .iter()
+            .any(|p| p.id == plugin)
+    {
+        return Err(Error::Config(format!(
+            "plugin not found in profile {}: {plugin}",
+            name.as_str()
+        )));
+    }
+
+    let ((), live) = edit_profile(&target, &name, |dir| {
+        plugins::set_enabled(dir, plugin, enabled)
+    })?;
+    let verb = if enabled { "Enabled" } else { "Disabled" };
+    println!(
+        "{verb} plugin {plugin} in profile: {}{}",
+        name.as_str(),
+        live_suffix(live)
+    );
+    Ok(())
+}
+
+pub fn add_marketplace(
+    source: &str,
+    profile_name: Option<&str>,
+    marketplace_name: Option<&str>,
+    project: Option<&Path>,
+) -> Result<()> {
+    let (target, name) = claude_profile(profile_name, project)?;
+    let entry = plugins::parse_source(source)?;
+    let marketplace = match marketplace_name {
+        Some(marketplace) => marketplace.to_string(),
+        None => plugins::marketplace_name(&entry).ok_or_else(|| {
+            Error::InvalidValue(format!("cannot name marketplace {source}; pass --name"))
+        })?,
+    };
+
+    let live_dir = target.config().config_dir()?;
+    let ((), live) = edit_profile(&target, &name, |dir| {
+        plugins::add_marketplace(dir, &marketplace, entry.clone(), &live_dir)
+    })?;
+    println!(
+        "Added marketplace {marketplace} to profile: {}{}",
+        name.as_str(),
+        live_suffix(live)
+    );
+    Ok(())
+}
+
+/// Removes a plugin (`name@marketplace`) or, for a bare name, a marketplace
+/// along with its plugins.
+pub fn remove(target_name: &str, profile_name: Option<&str>, project: Option<&Path>) -> Result<()> {
+    let (target, name) = claude_profile(profile_name, project)?;
+    let is_plugin = target_name.contains('@');
+    let (removed, live) = edit_profile(&target, &name, |dir| {
+        if is_plugin {
+            plugins::remove_plugin(dir, target_name)
+        } else {
+            plugins::remove_marketplace(dir, target_name)
+        }
+    })?;
+
+    let kind = if is_plugin { "plugin" } else { "marketplace" };
+    if !removed {
+        return Err(Error::Config(format!(
+            "{kind} not found in profile {}: {target_name}",
+            name.as_str()
+        )));
+    }
+    println!(
+        "Removed {kind} {target_name} from profile: {}{}",
+        name.as_str(),
+        live_suffix(live)
+    );
+    Ok(())
+}
+
+fn ported(kind: &'static str, name: &str, outcome: InstallResult) -> PortedComponent {
+    let (outcome, error) = match outcome {
+        Ok(InstallOutcome::Installed(_)) => ("installed", None),
+        Ok(InstallOutcome::Skipped(_)) => ("skipped", None),
+        Err(e) => ("failed", Some(e.to_string())),
+    };
+    PortedComponent {
+        kind,
+        name: name.to_string(),
+        outcome,
+        error,
+    }
+}
+
+fn unsupported(kind: &'static str, name: &str) -> PortedComponent {
+    PortedComponent {
+        kind,
+        name: name.to_string(),
+        outcome: "unsupported",
+        error: None,
+    }
+}
+
+/// Installs the agents and commands of a Claude Code plugin into a profile of
+/// `harness_name`, translated the way `bridle install` translates them.
+pub fn port(
+    plugin: &str,
+    profile_name: Option<&str>,
+    harness_name: &str,
+    to_profile: Option<&str>,
+    force: bool,
+    project: Option<&Path>,
+    format: ResolvedFormat,
+) -> Result<()> {
+    let (source, name) = claude_profile(profile_name, project)?;
+    let source_path = profile_path(&source, &name)?;
+    let live_dir = source.config().config_dir()?;
+    let plugin_dir = plugins::plugin_dir(&source_path, plugin, &live_dir).ok_or_else(|| {
+        Error::Config(format!(
+            "plugin files not found in profile {}: {plugin}",
+            name.as_str()
+        ))
+    })?;
+    let (agents, commands) = discover_local_components(&plugin_dir);
+
+    let target = Target::resolve(harness_name, project)?;
+    let target_profile = profile_or_active(&target, to_profile)?;
+    let harness_id = target.config().id().to_string();
+    let capabilities = HarnessRegistry::load()?.get(&harness_id)?.capabilities;
+    let install_target = InstallTarget {
+        harness: harness_id.clone(),
+        profile: target_profile.clone(),
+        project: project.map(project_root).transpose()?,
+    };
+    let options = InstallOptions { force };
+
+    let mut components = Vec::new();
+    for agent in &agents {
+        components.push(if capabilities.agents {
+            ported(
+                "agent",
+                &agent.name,
+                install_agent(agent, &install_target, &options, None),
+            )
+        } else {
+            unsupported("agent", &agent.name)
+        });
+    }
+    for command in &commands {
+        components.push(if capabilities.commands {
+            ported(
+                "command",
+                &command.name,
+                install_command(command, &install_target, &options, None),
+            )
+        } else {
+            unsupported("command", &command.name)
+        });
+    }
+
+    let report = PortReport {
+        plugin: plugin.to_string(),
+        harness_id,
+        profile: target_profile.as_str().to_string(),
+        components,
+    };
+    output(&report, format, |report| {
+        if report.components.is_empty() {
+            println!("Plugin {} has no agents or commands", report.plugin);
+            return;
+        }
+        println!(
+            "Ported {} to {} {}:",
+            report.plugin, report.harness_id, report.profile
+        );
+        for component in &report.components {
+            let detail = match (component.outcome, &component.error) {
+                (_, Some(error)) => format!("failed: {error}"),
+                ("skipped", None) => "skipped (already exists, use --force)".to_string(),
+                ("unsupported", None) => format!("skipped ({}s unsupported)", component.kind),
+                (outcome, None) => outcome.to_string(),
+            };
+            println!("  {} {}: {detail}", component.kind, component.name);
+        }
+    });
+    Ok(())
+}
@@ -1,11 +1,24 @@
-use harness_locate::{Harness, HarnessKind};
+use std::path::{Path, PathBuf};
+
+use harness_locate::Harness;
 use serde::Serialize;
 
 use crate::cli::output::{ResolvedFormat, output, output_list};
+use crate::config::diff::{FileChange, FileStatus};
 use crate::config::{BridleConfig, ProfileManager, ProfileName};
 use crate::display::{ProfileNode, SectionKind, nodes_to_text, profile_to_nodes};
 use crate::error::{Error, Result};
-use crate::harness::HarnessConfig;
+use crate::harness::{
+    HarnessConfig, HarnessDescriptor, HarnessEntry, HarnessRegistry, ProjectHarness,
+};
+
+#[derive(Serialize)]
+struct ProfileStatus {
+    harness_id: String,
+    profile: String,
+    clean: bool,
+    changes: Vec<FileChange>,
+}
 
 #[derive(Serialize)]
 struct ProfileListEntry {
@@ -14,31 +27,107 @@ struct ProfileListEntry {
     is_active: bool,
 }
 
-fn resolve_harness(name: &str) -> Result<Harness> {
-    let kind = match name {
-        "claude-code" | "claude" | "cc" => HarnessKind::ClaudeCode,
-        "opencode" | "oc" => HarnessKind::OpenCode,
-        "goose" => HarnessKind::Goose,
-        "amp-code" | "amp" | "ampcode" => HarnessKind::AmpCode,
-        _ => return Err(Error::UnknownHarness(name.to_string())),
-    };
-    Ok(Harness::new(kind))
+/// Resolves a `--project` argument to the absolute checkout path its profiles are tracked by.
+pub(crate) fn project_root(project: &Path) -> Result<PathBuf> {
+    project
+        .canonicalize()
+        .ok()
+        .filter(|p| p.is_dir())
+        .ok_or_else(|| Error::ProjectNotFound(project.display().to_string()))
 }
 
-fn get_manager() -> Result<ProfileManager> {
-    let profiles_dir = BridleConfig::profiles_dir()?;
-    Ok(ProfileManager::new(profiles_dir))
+/// A harness in the global scope, in a project checkout given with `--project`,
+/// or described by a descriptor (built in, or a file in `~/.config/bridle/harnesses/`).
+pub(crate) enum Target {
+    Global(Harness),
+    Project(ProjectHarness),
+    Custom(Box<HarnessDescriptor>),
 }
 
-pub fn list_profiles(harness_name: &str, format: ResolvedFormat) -> Result<()> {
-    let harness = resolve_harness(harness_name)?;
-    let manager = get_manager()?;
+impl Target {
+    pub(crate) fn resolve(name: &str, project: Option<&Path>) -> Result<Self> {
+        // Built-ins resolve without reading descriptor files.
+        let harness = match HarnessRegistry::kind(name) {
+            Some(kind) => Harness::new(kind),
+            None => return Self::resolve_descriptor(name, project),
+        };
+        let Some(project) = project else {
+            return Ok(Self::Global(harness));
+        };
+        Ok(Self::Project(ProjectHarness::new(
+            harness,
+            project_root(project)?,
+        )))
+    }
 
-    let active_profile: Option<String> = BridleConfig::load()
-        .ok()
-        .and_then(|c| c.active_profile_for(harness.id()).map(|s| s.to_string()));
+    fn resolve_descriptor(name: &str, project: Option<&Path>) -> Result<Self> {
+        // Built-in descriptors (Codex, Gemini) also resolve without reading descriptor files.
+        let descriptor = match HarnessRegistry::builtin().find(name) {
+            Some(entry) => entry.descriptor.clone(),
+            None => HarnessRegistry::load()?.get(name)?.descriptor.clone(),
+        }
+        .expect("harnesses without a built-in kind come from descriptors");
+        if project.is_some() {
+            return Err(Error::Config(format!(
+                "{} has no project scope",
+                descriptor.id
+            )));
+        }
+        Ok(Self::Custom(Box::new(descriptor)))
+    }
+
+    /// The built-in harness, whose resource directories bridle knows how to translate.
+    pub(crate) fn harness(&self) -> Option<&Harness> {
+        match self {
+            Self::Global(harness) => Some(harness),
+            Self::Project(project) => Some(project.harness()),
+            Self::Custom(_) => None,
+        }
+    }
+
+    pub(crate) fn config(&self) -> &dyn HarnessConfig {
+        match self {
+            Self::Global(harness) => harness,
+            Self::Project(project) => project,
+            Self::Custom(descriptor) => descriptor.as_ref(),
+        }
+    }
+
+    pub(crate) fn manager(&self) -> Result<ProfileManager> {
+        let profiles_dir = BridleConfig::profiles_dir_for(&self.config().scope())?;
+        Ok(ProfileManager::new(profiles_dir))
+    }
+
+    /// The profile marked active in this target's scope.
+    pub(crate) fn active_profile(&self) -> Result<Option<String>> {
+        let config = BridleConfig::load()?;
+        let harness = self.config();
+        Ok(config
+            .active_profile_in(&harness.scope(), harness.id())
+            .map(String::from))
+    }
 
-    let profiles = manager.list_profiles(&harness)?;
+    /// The `--project` argument to repeat in suggested commands.
+    fn project_flag(&self) -> String {
+        match self {
+            Self::Global(_) | Self::Custom(_) => String::new(),
+            Self::Project(project) => format!(" --project {}", project.root().display()),
+        }
+    }
+}
+
+pub fn list_profiles(
+    harness_name: &str,
+    project: Option<&Path>,
+    format: ResolvedFormat,
+) -> Result<()> {
+    let target = Target::resolve(harness_name, project)?;
+    let harness = target.config();
+    let manager = target.manager()?;
+
+    let active_profile = target.active_profile().ok().flatten();
+
+    let profiles = manager.list_profiles(harness)?;
     let entries: Vec<ProfileListEntry> = profiles
         .iter()
         .map(|p| ProfileListEntry {
@@ -65,18 +154,25 @@ pub fn list_profiles(harness_name: &str, format: ResolvedFormat) -> Result<()> {
     Ok(())
 }
 
-pub fn show_profile(harness_name: &str, profile_name: &str, format: ResolvedFormat) -> Result<()> {
-    let harness = resolve_harness(harness_name)?;
+pub fn show_profile(
+    harness_name: &str,
+    profile_name: &str,
+    project: Option<&Path>,
+    format: ResolvedFormat,
+) -> Result<()> {
+    let target = Target::resolve(harness_name, project)?;
     let name = ProfileName::new(profile_name)
         .map_err(|_| Error::InvalidProfileName(profile_name.to_string()))?;
-    let manager = get_manager()?;
+    let manager = target.manager()?;
 
-    let info = manager.show_profile(&harness, &name)?;
-    output(&info, format, |info| print_profile_text(info, &harness));
+    let info = manager.show_profile(target.config(), &name)?;
+    output(&info, format, |info| {
+        print_profile_text(info, target.config())
+    });
     Ok(())
 }
 
-fn print_profile_text(info: &crate::config::ProfileInfo, harness: &harness_locate::Harness) {
+fn print_profile_text(info: &crate::config::ProfileInfo, harness: &dyn HarnessConfig) {
     let mut nodes = profile_to_nodes(info);
 
     if info.is_active {
@@ -96,48 +192,67 @@ fn print_profile_text(info: &crate::config::ProfileInfo, harness: &harness_locat
     print!("{}", nodes_to_text(&nodes));
 }
 
-pub fn create_profile(harness_name: &str, profile_name: &str) -> Result<()> {
-    let harness = resolve_harness(harness_name)?;
+pub fn create_profile(
+    harness_name: &str,
+    profile_name: &str,
+    project: Option<&Path>,
+) -> Result<()> {
+    let target = Target::resolve(harness_name, project)?;
     let name = ProfileName::new(profile_name)
         .map_err(|_| Error::InvalidProfileName(profile_name.to_string()))?;
-    let manager = get_manager()?;
+    let manager = target.manager()?;
 
-    let path = manager.create_profile(&harness, &name)?;
+    let path = manager.create_profile(target.config(), &name)?;
     println!("Created profile: {}", name.as_str());
     println!("Path: {}", path.display());
     Ok(())
 }
 
-pub fn create_profile_from_current(harness_name: &str, profile_name: &str) -> Result<()> {
-    let harness = resolve_harness(harness_name)?;
+pub fn create_profile_from_current(
+    harness_name: &str,
+    profile_name: &str,
+    project: Option<&Path>,
+) -> Result<()> {
+    let target = Target::resolve(harness_name, project)?;
     let name = ProfileName::new(profile_name)
         .map_err(|_| Error::InvalidProfileName(profile_name.to_string()))?;
-    let manager = get_manager()?;
+    let manager = target.manager()?;
 
-    let path = manager.create_from_current_with_resources(&harness, Some(&harness), &name)?;
+    let path =
+        manager.create_from_current_with_resources(target.config(), target.harness(), &name)?;
     println!("Created profile from current config: {}", name.as_str());
     println!("Path: {}", path.display());
     Ok(())
 }
 
-pub fn delete_profile(harness_name: &str, profile_name: &str) -> Result<()> {
-    let harness = resolve_harness(harness_name)?;
+pub fn delete_profile(
+    harness_name: &str,
+    profile_name: &str,
+    project: Option<&Path>,
+) -> Result<()> {
+    let target = Target::resolve(harness_name, project)?;
     let name = ProfileName::new(profile_name)
         .map_err(|_| Error::InvalidProfileName(profile_name.to_string()))?;
-    let manager = get_manager()?;
-
-    manager.delete_profile(&harness, &name)?;
-    println!("Deleted profile: {}", name.as_str());
+    let manager = target.manager()?;
+
+    manager.delete_profile(target.config(), &name)?;
+    println!("Moved profile to trash: {}", name.as_str());
+    println!(
+        "Restore with: bridle trash restore{} {} {}",
+        target.project_flag(),
+        target.config().id(),
+        name.as_str()
+    );
     Ok(())
 }
 
-pub fn edit_profile(harness_name: &str, profile_name: &str) -> Result<()> {
-    let harness = resolve_harness(harness_name)?;
+pub fn edit_profile(harness_name: &str, profile_name: &str, project: Option<&Path>) -> Result<()> {
+    let target = Target::resolve(harness_name, project)?;
     let name = ProfileName::new(profile_name)
         .map_err(|_| Error::InvalidProfileName(profile_name.to_string()))?;
-    let manager = get_manager()?;
+    let manager = target.manager()?;
 
-    let profile_path = manager.profile_path(&harness, &name);
+    let profile_path = manager.profile_path(target.config(), &name);
     if !profile_path.exists() {
         return Err(Error::ProfileNotFound(profile_name.to_string()));
     }
@@ -162,13 +277,15 @@ pub fn diff_profiles(
     harness_name: &str,
     profile_name: &str,
     other_name: Option<&str>,
+    project: Option<&Path>,
 ) -> Result<()> {
-    let harness = resolve_harness(harness_name)?;
+    let target = Target::resolve(harness_name, project)?;
+    let harness = target.config();
     let name = ProfileName::new(profile_name)
         .map_err(|_| Error::InvalidProfileName(profile_name.to_string()))?;
-    let manager = get_manager()?;
+    let manager = target.manager()?;
 
-    let profile_path = manager.profile_path(&harness, &name);
+    let profile_path = manager.profile_path(harness, &name);
     if !profile_path.exists() {
         return Err(Error::ProfileNotFound(profile_name.to_string()));
     }
@@ -176,13 +293,13 @@ pub fn diff_profiles(
     let other_path = if let Some(other) = other_name {
         let other_name =
             ProfileName::new(other).map_err(|_| Error::InvalidProfileName(other.to_string()))?;
-        let path = manager.profile_path(&harness, &other_name);
+        let path = manager.profile_path(harness, &other_name);
         if !path.exists() {
             return Err(Error::ProfileNotFound(other.to_string()));
         }
         path
     } else {
-        harness.config(&harness_locate::Scope::Global)?
+        harness.config_dir()?
     };
 
     let status = std::process::Command::new("diff")
@@ -199,19 +316,24 @@ pub fn diff_profiles(
     Ok(())
 }
 
-pub fn switch_profile(harness_name: &str, profile_name: &str) -> Result<()> {
-    let harness = resolve_harness(harness_name)?;
+pub fn switch_profile(
+    harness_name: &str,
+    profile_name: &str,
+    project: Option<&Path>,
+) -> Result<()> {
+    let target = Target::resolve(harness_name, project)?;
+    let harness = target.config();
     let name = ProfileName::new(profile_name)
         .map_err(|_| Error::InvalidProfileName(profile_name.to_string()))?;
-    let manager = get_manager()?;
+    let manager = target.manager()?;
 
-    if !manager.profile_exists(&harness, &name) {
+    if !manager.profile_exists(harness, &name) {
         return Err(Error::ProfileNotFound(profile_name.to_string()));
     }
 
     let harness_id = harness.id();
 
-    match manager.backup_current(&harness) {
+    match manager.backup_current(harness) {
         Ok(backup_path) => {
             println!("Backed up current config to: {}", backup_path.display());
         }
@@ -220,8 +342,131 @@ pub fn switch_profile(harness_name: &str, profile_name: &str) -> Result<()> {
         }
     }
 
-    manager.switch_profile_with_resources(&harness, Some(&harness), &name)?;
+    manager.switch_profile_with_resources(harness, target.harness(), &name)?;
     println!("Switched to profile: {}", name.as_str());
     println!("Harness: {harness_id}");
+    if let Target::Project(project) = &target {
+        println!("Project: {}", project.root().display());
+    }
+    Ok(())
+}
+
+pub(crate) fn active_profile(target: &Target) -> Result<ProfileName> {
+    let active = target.active_profile()?.ok_or(Error::NoActiveProfile)?;
+    ProfileName::new(&active).map_err(|_| Error::InvalidProfileName(active.clone()))
+}
+
+/// `name` if given, otherwise the active profile. Fails if the profile doesn't exist.
+pub(crate) fn profile_or_active(target: &Target, name: Option<&str>) -> Result<ProfileName> {
+    let name = match name {
+        Some(name) => {
+            ProfileName::new(name).map_err(|_| Error::InvalidProfileName(name.to_string()))?
+        }
+        None => active_profile(target)?,
+    };
+    if !target.manager()?.profile_exists(target.config(), &name) {
+        return Err(Error::ProfileNotFound(name.as_str().to_string()));
+    }
+    Ok(name)
+}
+
+/// Every harness accepted by `filter` that has profile `name` (or an active
+/// profile), paired with that profile. Harnesses without a built-in kind have
+/// no project scope, so they are left out with `--project`.
+pub(crate) fn profile_targets(
+    project: Option<&Path>,
+    name: Option<&str>,
+    filter: impl Fn(&HarnessEntry) -> bool,
+) -> Result<Vec<(Target, ProfileName)>> {
+    let registry = HarnessRegistry::load()?;
+    let mut targets = Vec::new();
+    for entry in registry.entries() {
+        if !filter(entry) || (project.is_some() && entry.kind.is_none()) {
+            continue;
+        }
+        let target = Target::resolve(&entry.id, project)?;
+        if let Ok(profile) = profile_or_active(&target, name) {
+            targets.push((target, profile));
+        }
+    }
+    Ok(targets)
+}
+
+pub fn profile_status(
+    harness_name: &str,
+    project: Option<&Path>,
+    format: ResolvedFormat,
+) -> Result<()> {
+    let target = Target::resolve(harness_name, project)?;
+    let harness = target.config();
+    let name = active_profile(&target)?;
+    let manager = target.manager()?;
+
+    let changes = manager.live_drift(harness, &name)?;
+    let status = ProfileStatus {
+        harness_id: harness.id().to_string(),
+        profile: name.as_str().to_string(),
+        clean: changes.is_empty(),
+        changes,
+    };
+
+    output(&status, format, |status| {
+        println!("Active profile: {} ({})", status.profile, status.harness_id);
+        if status.clean {
+            println!("No unsaved changes");
+            return;
+        }
+        println!("Unsaved changes in live config:");
+        for change in &status.changes {
+            let marker = match change.status {
+                FileStatus::Added => "A",
+                FileStatus::Removed => "D",
+                FileStatus::Modified => "M",
+            };
+            println!("  {} {}", marker, change.path.display());
+        }
+        println!();
+        println!(
+            "Run 'bridle profile save{1} {0}' to keep them or 'bridle profile reset{1} {0}' to discard them",
+            harness_name,
+            target.project_flag()
+        );
+    });
+    Ok(())
+}
+
+pub fn save_profile(harness_name: &str, project: Option<&Path>) -> Result<()> {
+    let target = Target::resolve(harness_name, project)?;
+    let name = active_profile(&target)?;
+    let manager = target.manager()?;
+
+    let path = manager.save_live(target.config(), &name)?;
+    println!("Saved live config to profile: {}", name.as_str());
+    println!("Path: {}", path.display());
+    Ok(())
+}
+
+pub fn reset_profile(harness_name: &str, project: Option<&Path>) -> Result<()> {
+    let target = Target::resolve(harness_name, project)?;
+    let harness = target.config();
+    let name = active_profile(&target)?;
+    let manager = target.manager()?;
+
+    if manager.live_drift(harness, &name)?.is_empty() {
+        println!("No unsaved changes for profile: {}", name.as_str());
+        return Ok(());
+    }
+
+    match manager.backup_current(harness) {
+        Ok(backup_path) => {
+            println!("Backed up current config to: {}", backup_path.display());
+        }
+        Err(e) => {
+            println!("Warning: Could not backup current config: {e}");
+        }
+    }
+
+    manager.reset_live(harness, &name)?;
+    println!("Reset live config to profile: {}", name.as_str());
     Ok(())
 }
@@ -0,0 +1,265 @@
+//! CLI rules command implementation.
+
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::cli::output::{ResolvedFormat, output, output_list};
+use crate::cli::profile::{Target, profile_or_active, profile_targets};
+use crate::config::{BridleConfig, ProfileName, rules};
+use crate::error::{Error, Result};
+
+#[derive(Serialize)]
+struct RulesInfo {
+    harness_id: String,
+    profile: String,
+    path: PathBuf,
+    exists: bool,
+    snippets: Vec<String>,
+    content: String,
+}
+
+#[derive(Serialize)]
+struct SnippetEntry {
+    name: String,
+    /// Profiles using the snippet, as `harness/profile`.
+    used_by: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct SyncEntry {
+    harness_id: String,
+    profile: String,
+    path: PathBuf,
+    live: bool,
+}
+
+/// The rules file inside profile `name`.
+fn profile_rules_path(target: &Target, name: &ProfileName) -> Result<PathBuf> {
+    let harness = target.config();
+    let profile_path = target.manager()?.profile_path(harness, name);
+    rules::profile_rules_path(harness, &profile_path)
+        .ok_or_else(|| Error::Config(format!("{} has no rules file", harness.id())))
+}
+
+/// Optionally replaces the rules of profile `name` with `content`, composes its
+/// snippets into them, and copies the result live if the profile is active.
+///
+/// Returns whether the live rules file was updated.
+fn write_rules(target: &Target, name: &ProfileName, content: Option<&str>) -> Result<bool> {
+    let harness = target.config();
+    let path = profile_rules_path(target, name)?;
+    if let Some(content) = content {
+        std::fs::write(&path, content)?;
+    }
+
+    let config = BridleConfig::load()?;
+    let profile_path = target.manager()?.profile_path(harness, name);
+    let snippets = config.snippets_for(harness.id(), name.as_str());
+    rules::apply_snippets(harness, &profile_path, snippets)?;
+
+    if target.active_profile()?.as_deref() != Some(name.as_str()) || !path.is_file() {
+        return Ok(false);
+    }
+    let Some(live) = rules::live_rules_path(harness)? else {
+        return Ok(false);
+    };
+    if let Some(parent) = live.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::copy(&path, &live)?;
+    Ok(true)
+}
+
+pub fn show(
+    harness_name: &str,
+    profile_name: Option<&str>,
+    project: Option<&Path>,
+    format: ResolvedFormat,
+) -> Result<()> {
+    let target = Target::resolve(harness_name, project)?;
+    let name = profile_or_active(&target, profile_name)?;
+    let path = profile_rules_path(&target, &name)?;
+    let config = BridleConfig::load()?;
+    let harness_id = target.config().id();
+
+    let info = RulesInfo {
+        harness_id: harness_id.to_string(),
+        profile: name.as_str().to_string(),
+        exists: path.is_file(),
+        content: std::fs::read_to_string(&path).unwrap_or_default(),
+        snippets: config.snippets_for(harness_id, name.as_str()).to_vec(),
+        path,
+    };
+
+    output(&info, format, |info| {
+        println!("Rules for {} ({})", info.profile, info.harness_id);
+        println!("Path: {}", info.path.display());
+        if !info.snippets.is_empty() {
+            println!("Snippets: {}", info.snippets.join(", "));
+        }
+        println!();
+        if info.exists {
+            print!("{}", info.content);
+        } else {
+            println!("No rules file");
+        }
+    });
+    Ok(())
+}
+
+pub fn edit(harness_name: &str, profile_name: Option<&str>, project: Option<&Path>) -> Result<()> {
+    let target = Target::resolve(harness_name, project)?;
+    let name = profile_or_active(&target, profile_name)?;
+    let path = profile_rules_path(&target, &name)?;
+
+    let editor = BridleConfig::load().unwrap_or_default().editor();
+    let status = std::process::Command::new(&editor).arg(&path).status()?;
+    if !status.success() {
+        return Err(Error::Command(format!(
+            "Editor exited with status: {status}"
+        )));
+    }
+
+    let live = write_rules(&target, &name, None)?;
+    println!("Edited rules: {}", path.display());
+    if live {
+        println!("Updated live rules for active profile: {}", name.as_str());
+    }
+    Ok(())
+}
+
+pub fn list_snippets(format: ResolvedFormat) -> Result<()> {
+    let config = BridleConfig::load()?;
+    let entries: Vec<SnippetEntry> = rules::list_snippets()?
+        .into_iter()
+        .map(|name| {
+            let used_by = config
+                .snippets
+                .iter()
+                .flat_map(|(harness, profiles)| {
+                    profiles
+                        .iter()
+                        .filter(|(_, names)| names.contains(&name))
+                        .map(move |(profile, _)| format!("{harness}/{profile}"))
+                })
+                .collect();
+            SnippetEntry { name, used_by }
+        })
+        .collect();
+
+    output_list(&entries, format, |entries| {
+        if entries.is_empty() {
+            println!(
+                "No snippets in {}",
+                BridleConfig::snippets_dir()
+                    .map(|d| d.display().to_string())
+                    .unwrap_or_default()
+            );
+            return;
+        }
+        println!("Snippets:");
+        for entry in entries {
+            if entry.used_by.is_empty() {
+                println!("  {}", entry.name);
+            } else {
+                println!("  {} ({})", entry.name, entry.used_by.join(", "));
+            }
+        }
+    });
+    Ok(())
+}
+
+pub fn add_snippet(
+    harness_name: &str,
+    profile_name: &str,
+    snippet: &str,
+    project: Option<&Path>,
+) -> Result<()> {
+    let target = Target::resolve(harness_name, project)?;
+    let name = profile_or_active(&target, Some(profile_name))?;
+    profile_rules_path(&target, &name)?;
+    rules::load_snippet(snippet)?;
+
+    let mut config = BridleConfig::load()?;
+    if !config.add_snippet(target.config().id(), name.as_str(), snippet) {
+        println!("Profile {} already uses snippet: {snippet}", name.as_str());
+        return Ok(());
+    }
+    config.save()?;
+
+    let live = write_rules(&target, &name, None)?;
+    println!("Added snippet {snippet} to profile: {}", name.as_str());
+    if live {
+        println!("Updated live rules");
+    }
+    Ok(())
+}
+
+pub fn remove_snippet(
+    harness_name: &str,
+    profile_name: &str,
+    snippet: &str,
+    project: Option<&Path>,
+) -> Result<()> {
+    let target = Target::resolve(harness_name, project)?;
+    let name = profile_or_active(&target, Some(profile_name))?;
+
+    let mut config = BridleConfig::load()?;
+    if !config.remove_snippet(target.config().id(), name.as_str(), snippet) {
+        println!("Profile {} does not use snippet: {snippet}", name.as_str());
+        return Ok(());
+    }
+    config.save()?;
+
+    let live = write_rules(&target, &name, None)?;
+    println!("Removed snippet {snippet} from profile: {}", name.as_str());
+    if live {
+        println!("Updated live rules");
+    }
+    Ok(())
+}
+
+/// Writes `file` as the rules of profile `profile_name` (or the active profile)
+/// of every harness that has a rules file, under each harness's own file name.
+pub fn sync(
+    file: &Path,
+    profile_name: Option<&str>,
+    project: Option<&Path>,
+    format: ResolvedFormat,
+) -> Result<()> {
+    let content = std::fs::read_to_string(file)?;
+
+    let mut synced = Vec::new();
+    for (target, name) in profile_targets(project, profile_name, |e| e.capabilities.rules)? {
+        let live = write_rules(&target, &name, Some(&content))?;
+        synced.push(SyncEntry {
+            harness_id: target.config().id().to_string(),
+            profile: name.as_str().to_string(),
+            path: profile_rules_path(&target, &name)?,
+            live,
+        });
+    }
+
+    output_list(&synced, format, |synced| {
+        if synced.is_empty() {
+            match profile_name {
+                Some(name) => println!("No harness has a profile named {name}"),
+                None => println!("No harness has an active profile"),
+            }
+            return;
+        }
+        println!("Synced {}:", file.display());
+        for entry in synced {
+            let live = if entry.live { " (live)" } else { "" };
+            println!(
+                "  {} {}: {}{}",
+                entry.harness_id,
+                entry.profile,
+                entry.path.display(),
+                live
+            );
+        }
+    });
+    Ok(())
+}
@@ -0,0 +1,134 @@
+//! CLI search command implementation.
+
+use color_eyre::eyre::{Result, eyre};
+use serde::Serialize;
+
+use crate::cli::output::{ResolvedFormat, output_list};
+use crate::config::BridleConfig;
+use crate::harness::HarnessRegistry;
+use crate::install::ComponentType;
+use crate::install::compat::{
+    harness_supports_agents, harness_supports_commands, harness_supports_mcp,
+    harness_supports_skills,
+};
+use crate::install::registry::{RegistryEntry, RegistryError, RegistryIndex};
+
+#[derive(Serialize)]
+struct SearchResult {
+    name: String,
+    source: String,
+    description: Option<String>,
+    components: Vec<ComponentMatch>,
+}
+
+#[derive(Serialize)]
+struct ComponentMatch {
+    kind: &'static str,
+    name: String,
+    compatible: Vec<String>,
+}
+
+/// Harness ids that can accept each component kind, computed once per search.
+struct Compatibility {
+    skills: Vec<String>,
+    agents: Vec<String>,
+    commands: Vec<String>,
+    mcp: Vec<String>,
+}
+
+impl Compatibility {
+    fn detect() -> Self {
+        let ids: Vec<String> = HarnessRegistry::builtin()
+            .entries()
+            .iter()
+            .map(|entry| entry.id.clone())
+            .collect();
+
+        let filter = |supports: fn(&str) -> bool| -> Vec<String> {
+            ids.iter().filter(|id| supports(id)).cloned().collect()
+        };
+
+        Self {
+            skills: filter(harness_supports_skills),
+            agents: filter(harness_supports_agents),
+            commands: filter(harness_supports_commands),
+            mcp: filter(harness_supports_mcp),
+        }
+    }
+
+    fn for_component(&self, component_type: Option<ComponentType>) -> &[String] {
+        match component_type {
+            Some(ComponentType::Skill) => &self.skills,
+            Some(ComponentType::Agent) => &self.agents,
+            Some(ComponentType::Command) => &self.commands,
+            None => &self.mcp,
+        }
+    }
+}
+
+fn component_label(component_type: Option<ComponentType>) -> &'static str {
+    match component_type {
+        Some(ComponentType::Skill) => "skill",
+        Some(ComponentType::Agent) => "agent",
+        Some(ComponentType::Command) => "command",
+        None => "mcp",
+    }
+}
+
+fn to_result(entry: &RegistryEntry, compat: &Compatibility) -> SearchResult {
+    SearchResult {
+        name: entry.name.clone(),
+        source: entry.source.clone(),
+        description: entry.description.clone(),
+        components: entry
+            .components()
+            .map(|(component_type, name)| ComponentMatch {
+                kind: component_label(component_type),
+                name: name.to_string(),
+                compatible: compat.for_component(component_type).to_vec(),
+            })
+            .collect(),
+    }
+}
+
+pub fn run(query: &str, registry: Option<&str>, format: ResolvedFormat) -> Result<()> {
+    let config = BridleConfig::load()?;
+    let location = registry
+        .or(config.registry())
+        .ok_or_else(|| eyre!(RegistryError::NotConfigured))?;
+
+    let index = RegistryIndex::load(location)?;
+    let compat = Compatibility::detect();
+    let results: Vec<SearchResult> = index
+        .search(query)
+        .into_iter()
+        .map(|entry| to_result(entry, &compat))
+        .collect();
+
+    output_list(&results, format, |results| {
+        if results.is_empty() {
+            println!("No registry entries match '{}'", query);
+            return;
+        }
+        for result in results {
+            println!("{} ({})", result.name, result.source);
+            if let Some(desc) = &result.description {
+                println!("  {}", desc);
+            }
+            for component in &result.components {
+                let compatible = if component.compatible.is_empty() {
+                    "no installed harness".to_string()
+                } else {
+                    component.compatible.join(", ")
+                };
+                println!(
+                    "  {:<8} {} [{}]",
+                    component.kind, component.name, compatible
+                );
+            }
+            println!();
+        }
+        println!("Install with: bridle install <name>");
+    });
+    Ok(())
+}
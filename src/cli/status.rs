@@ -1,8 +1,9 @@
-use harness_locate::{Harness, HarnessKind, InstallationStatus, Scope};
+use harness_locate::InstallationStatus;
 use serde::Serialize;
 
 use crate::cli::output::{ResolvedFormat, output};
 use crate::config::BridleConfig;
+use crate::harness::HarnessRegistry;
 
 #[derive(Debug, Serialize)]
 pub struct StatusOutput {
@@ -24,28 +25,34 @@ pub struct ActiveProfile {
     pub profile: String,
 }
 
+fn status_label(status: crate::error::Result<InstallationStatus>) -> &'static str {
+    match status {
+        Ok(InstallationStatus::FullyInstalled { .. }) => "installed",
+        Ok(InstallationStatus::ConfigOnly { .. }) => "config only",
+        Ok(InstallationStatus::BinaryOnly { .. }) => "binary only",
+        _ => "not installed",
+    }
+}
+
 pub fn display_status(format: ResolvedFormat) {
-    let harnesses: Vec<HarnessStatus> = HarnessKind::ALL
+    let registry = HarnessRegistry::load().unwrap_or_else(|e| {
+        eprintln!("Warning: could not load harness descriptors: {e}");
+        HarnessRegistry::builtin()
+    });
+    let harnesses: Vec<HarnessStatus> = registry
+        .entries()
         .iter()
-        .map(|kind| {
-            let harness = Harness::new(*kind);
-            let status = match harness.installation_status() {
-                Ok(InstallationStatus::FullyInstalled { .. }) => "installed",
-                Ok(InstallationStatus::ConfigOnly { .. }) => "config only",
-                Ok(InstallationStatus::BinaryOnly { .. }) => "binary only",
-                _ => "not installed",
-            };
-            let config_path = if harness.is_installed() {
-                harness
-                    .config(&Scope::Global)
-                    .ok()
-                    .map(|p| p.display().to_string())
+        .map(|entry| {
+            let harness = entry.config();
+            let status = status_label(harness.installation_status());
+            let config_path = if status != "not installed" {
+                harness.config_dir().ok().map(|p| p.display().to_string())
             } else {
                 None
             };
             HarnessStatus {
-                id: kind.to_string(),
-                name: kind.to_string(),
+                id: entry.id.clone(),
+                name: entry.name.clone(),
                 status: status.to_string(),
                 config_path,
             }
@@ -0,0 +1,96 @@
+//! CLI trash command implementation.
+
+use std::io::IsTerminal;
+use std::path::Path;
+
+use color_eyre::eyre::{Result, eyre};
+use dialoguer_multiselect::Confirm;
+use dialoguer_multiselect::theme::ColorfulTheme;
+use harness_locate::Scope;
+
+use crate::cli::output::{ResolvedFormat, output_list};
+use crate::cli::profile::Target;
+use crate::config::{BridleConfig, ProfileManager, ProfileName};
+use crate::error::Error;
+
+/// Project profiles have a trash of their own, shared by all checkouts.
+fn get_manager(project: Option<&Path>) -> Result<ProfileManager> {
+    let scope = match project {
+        Some(root) => Scope::Project(root.to_path_buf()),
+        None => Scope::Global,
+    };
+    Ok(ProfileManager::new(BridleConfig::profiles_dir_for(&scope)?))
+}
+
+fn resolve_optional(harness_name: Option<&str>, project: Option<&Path>) -> Result<Option<Target>> {
+    Ok(harness_name
+        .map(|name| Target::resolve(name, project))
+        .transpose()?)
+}
+
+pub fn list(
+    harness_name: Option<&str>,
+    project: Option<&Path>,
+    format: ResolvedFormat,
+) -> Result<()> {
+    let target = resolve_optional(harness_name, project)?;
+    let manager = get_manager(project)?;
+    let entries = manager.list_trash(target.as_ref().map(Target::config))?;
+
+    output_list(&entries, format, |entries| {
+        if entries.is_empty() {
+            println!("Trash is empty");
+            return;
+        }
+        for entry in entries {
+            println!(
+                "  {}  {}/{}",
+                entry.deleted_at, entry.harness_id, entry.name
+            );
+        }
+    });
+    Ok(())
+}
+
+pub fn restore(harness_name: &str, profile_name: &str, project: Option<&Path>) -> Result<()> {
+    let target = Target::resolve(harness_name, project)?;
+    let name = ProfileName::new(profile_name)
+        .map_err(|_| Error::InvalidProfileName(profile_name.to_string()))?;
+    let manager = target.manager()?;
+
+    let path = manager.restore_from_trash(target.config(), &name)?;
+    println!("Restored profile: {}", name.as_str());
+    println!("Path: {}", path.display());
+    Ok(())
+}
+
+pub fn empty(harness_name: Option<&str>, project: Option<&Path>, yes: bool) -> Result<()> {
+    let target = resolve_optional(harness_name, project)?;
+    let harness = target.as_ref().map(Target::config);
+    let manager = get_manager(project)?;
+
+    let count = manager.list_trash(harness)?.len();
+    if count == 0 {
+        println!("Trash is empty");
+        return Ok(());
+    }
+
+    if !yes {
+        if !std::io::stdin().is_terminal() {
+            return Err(eyre!(
+                "Emptying the trash cannot be undone. Pass --yes to confirm."
+            ));
+        }
+        let confirmed = Confirm::with_theme(&ColorfulTheme::default())
+            .with_prompt(format!("Permanently delete {} trashed profile(s)?", count))
+            .default(false)
+            .interact()?;
+        if !confirmed {
+            return Ok(());
+        }
+    }
+
+    let removed = manager.empty_trash(harness)?;
+    println!("Permanently deleted {} profile(s)", removed);
+    Ok(())
+}
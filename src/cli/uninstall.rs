@@ -4,63 +4,150 @@ use std::io::IsTerminal;
 use std::path::Path;
 
 use color_eyre::eyre::{Result, eyre};
-use dialoguer_multiselect::MultiSelect;
 use dialoguer_multiselect::theme::ColorfulTheme;
+use dialoguer_multiselect::{Confirm, MultiSelect};
 
-use crate::config::BridleConfig;
-use crate::install::uninstaller::uninstall_components;
-use crate::install::{ComponentType, InstallTarget};
+use crate::cli::profile::{Target, project_root};
+use crate::config::ProfileName;
+use crate::install::discovery::parse_source;
+use crate::install::installer::component_dir;
+use crate::install::manifest::{InstallManifest, manifest_path};
+use crate::install::uninstaller::{components_from_source, prune_manifest, uninstall_components};
+use crate::install::{ComponentType, Dependency, DependencyKind, InstallTarget};
 
-pub fn run(harness: &str, profile: &str) -> Result<()> {
-    if !std::io::stdin().is_terminal() {
-        return Err(eyre!("Interactive mode requires a terminal."));
-    }
+/// Options for `bridle uninstall`.
+pub struct UninstallArgs<'a> {
+    pub harness: &'a str,
+    pub profile: &'a str,
+    /// Components given as `kind:name`; empty means select interactively.
+    pub components: &'a [String],
+    /// Remove everything installed from this source.
+    pub all_from: Option<&'a str>,
+    /// Skip confirmation prompts.
+    pub yes: bool,
+    /// Uninstall from this checkout's project profiles instead of the global ones.
+    pub project: Option<&'a Path>,
+}
 
-    let profiles_dir = BridleConfig::profiles_dir()?;
-    let profile_name = crate::config::ProfileName::new(profile)?;
+pub fn run(args: UninstallArgs<'_>) -> Result<()> {
+    let profile_name = ProfileName::new(args.profile)?;
+    let project = args.project.map(project_root).transpose()?;
 
-    let profile_path = profiles_dir.join(harness).join(profile);
+    let scoped = Target::resolve(args.harness, project.as_deref())?;
+    let harness = scoped.config();
+    let harness_id = harness.id();
+    let manager = scoped.manager()?;
+    let profile_path = manager.profile_path(harness, &profile_name);
     if !profile_path.exists() {
-        return Err(eyre!("Profile not found: {}/{}", harness, profile));
+        return Err(eyre!("Profile not found: {}/{}", harness_id, args.profile));
     }
 
-    let components = list_installed_components(&profile_path)?;
+    let mut selected_components = Vec::new();
+    for spec in args.components {
+        let component = Dependency::parse(spec).ok_or_else(|| {
+            eyre!(
+                "Invalid component '{}'. Use kind:name with kind one of skill, agent, command, mcp",
+                spec
+            )
+        })?;
+        selected_components.push(component);
+    }
 
-    if components.is_empty() {
-        eprintln!("No components installed in {}/{}", harness, profile);
-        return Ok(());
+    if let Some(source) = args.all_from {
+        let (github_ref, subpath) = parse_source(source)?;
+        let from_source = components_from_source(
+            &profile_path,
+            &github_ref.owner,
+            &github_ref.repo,
+            subpath.as_deref(),
+        )?;
+        if from_source.is_empty() {
+            eprintln!(
+                "No components from {} recorded in {}/{}",
+                source, harness_id, args.profile
+            );
+        }
+        for component in from_source {
+            if !selected_components.contains(&component) {
+                selected_components.push(component);
+            }
+        }
     }
 
-    let component_labels: Vec<String> = components
-        .iter()
-        .map(|(name, comp_type)| format!("{:?}: {}", comp_type, name))
-        .collect();
+    if args.components.is_empty() && args.all_from.is_none() {
+        if !std::io::stdin().is_terminal() {
+            return Err(eyre!(
+                "Interactive mode requires a terminal. Use --component or --all-from."
+            ));
+        }
 
-    let Some(selected_indices) = MultiSelect::with_theme(&ColorfulTheme::default())
-        .with_prompt("Select components to uninstall (Esc to cancel)")
-        .items(&component_labels)
-        .interact_opt()?
-    else {
-        eprintln!("Cancelled");
-        return Ok(());
-    };
+        let mut components = list_installed_components(&profile_path, harness_id)?;
+        if let Ok(info) = manager.show_profile(harness, &profile_name) {
+            components.extend(
+                info.mcp_servers
+                    .into_iter()
+                    .map(|server| Dependency::new(DependencyKind::Mcp, server.name)),
+            );
+        }
+
+        if components.is_empty() {
+            eprintln!("No components installed in {}/{}", harness_id, args.profile);
+            return Ok(());
+        }
+
+        let component_labels: Vec<String> = components.iter().map(|c| c.to_string()).collect();
+
+        let Some(selected_indices) = MultiSelect::with_theme(&ColorfulTheme::default())
+            .with_prompt("Select components to uninstall (Esc to cancel)")
+            .items(&component_labels)
+            .interact_opt()?
+        else {
+            eprintln!("Cancelled");
+            return Ok(());
+        };
 
-    if selected_indices.is_empty() {
+        selected_components = selected_indices
+            .iter()
+            .map(|&i| components[i].clone())
+            .collect();
+    }
+
+    if selected_components.is_empty() {
         eprintln!("No components selected");
         return Ok(());
     }
 
-    let selected_components: Vec<_> = selected_indices
-        .iter()
-        .map(|&i| components[i].clone())
-        .collect();
+    let prompt = format!(
+        "Uninstall {} component(s) from {}/{}?",
+        selected_components.len(),
+        harness_id,
+        args.profile
+    );
+    if !confirm_uninstall(&profile_path, &selected_components, &prompt, args.yes)? {
+        eprintln!("Cancelled");
+        return Ok(());
+    }
+
+    // Components whose files are already gone only need their manifest entry dropped.
+    for entry in prune_manifest(&profile_path, harness_id)? {
+        eprintln!(
+            "Pruned manifest entry for missing {}: {}",
+            entry.component_type, entry.name
+        );
+        let pruned = Dependency::new(entry.component_type, entry.name.as_str());
+        selected_components.retain(|c| c != &pruned);
+    }
+    if selected_components.is_empty() {
+        return Ok(());
+    }
 
     let target = InstallTarget {
-        harness: harness.to_string(),
+        harness: harness_id.to_string(),
         profile: profile_name,
+        project,
     };
 
-    eprintln!("\nUninstalling from {}/{}...", harness, profile);
+    eprintln!("\nUninstalling from {}/{}...", harness_id, args.profile);
 
     let report = uninstall_components(&selected_components, &target);
 
@@ -79,19 +166,81 @@ pub fn run(harness: &str, profile: &str) -> Result<()> {
     }
 
     eprintln!("\nDone!");
-    Ok(())
+    if report.errors.is_empty() {
+        Ok(())
+    } else {
+        Err(eyre!(
+            "{} component(s) could not be removed",
+            report.errors.len()
+        ))
+    }
 }
 
-fn list_installed_components(profile_path: &Path) -> Result<Vec<(String, ComponentType)>> {
+/// Warns about components that still depend on something being removed, then
+/// asks before removing anything.
+///
+/// Returns `false` if the user declines to continue. With `yes`, warnings are
+/// printed but never block removal.
+fn confirm_uninstall(
+    profile_path: &Path,
+    removing: &[Dependency],
+    prompt: &str,
+    yes: bool,
+) -> Result<bool> {
+    let manifest = InstallManifest::load(&manifest_path(profile_path))?;
+
+    let mut warnings = Vec::new();
+    for dep in removing {
+        for dependent in manifest.dependents(dep) {
+            let dependent_ref = Dependency::new(dependent.component_type, dependent.name.as_str());
+            if !removing.contains(&dependent_ref) {
+                warnings.push(format!(
+                    "{} {} still requires {}",
+                    dependent.component_type, dependent.name, dep
+                ));
+            }
+        }
+    }
+
+    for warning in &warnings {
+        eprintln!("  ! {}", warning);
+    }
+    if yes {
+        return Ok(true);
+    }
+    if !std::io::stdin().is_terminal() {
+        return Err(eyre!(
+            "Confirmation requires a terminal. Pass --yes to uninstall without asking."
+        ));
+    }
+    let prompt = if warnings.is_empty() {
+        prompt
+    } else {
+        "Uninstall anyway?"
+    };
+    Ok(Confirm::with_theme(&ColorfulTheme::default())
+        .with_prompt(prompt)
+        .default(false)
+        .interact()?)
+}
+
+fn list_installed_components(profile_path: &Path, harness_id: &str) -> Result<Vec<Dependency>> {
     let mut components = Vec::new();
 
+    // Codex keeps agents and commands together as prompts; list them once, as commands.
     let component_types = [
-        (ComponentType::Skill, "skills"),
-        (ComponentType::Agent, "agents"),
-        (ComponentType::Command, "commands"),
+        ComponentType::Skill,
+        ComponentType::Agent,
+        ComponentType::Command,
     ];
 
-    for (comp_type, dir_name) in component_types {
+    for comp_type in component_types {
+        let dir_name = component_dir(harness_id, comp_type);
+        if matches!(comp_type, ComponentType::Agent)
+            && dir_name == component_dir(harness_id, ComponentType::Command)
+        {
+            continue;
+        }
         let dir = profile_path.join(dir_name);
         if !dir.exists() {
             continue;
@@ -99,10 +248,18 @@ fn list_installed_components(profile_path: &Path) -> Result<Vec<(String, Compone
 
         for entry in std::fs::read_dir(&dir)? {
             let entry = entry?;
-            if entry.file_type()?.is_dir()
-                && let Some(name) = entry.file_name().to_str()
-            {
-                components.push((name.to_string(), comp_type));
+            let path = entry.path();
+            // Skills are directories; agents and commands are `<name>.md` files,
+            // Gemini commands `<name>.toml`.
+            let extension = path.extension().and_then(|e| e.to_str());
+            let name = match (comp_type, extension) {
+                (ComponentType::Skill, _) if path.is_dir() => path.file_name(),
+                (ComponentType::Agent, Some("md"))
+                | (ComponentType::Command, Some("md" | "toml")) => path.file_stem(),
+                _ => None,
+            };
+            if let Some(name) = name.and_then(|n| n.to_str()) {
+                components.push(Dependency::new(comp_type.into(), name));
             }
         }
     }
@@ -0,0 +1,137 @@
+//! Directory-based auto-switch rules.
+
+use std::path::{Component, Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// An `[[auto]]` rule: switch `harness` to `profile` inside directories matching `path`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AutoRule {
+    /// Directory pattern. A leading `~` expands to the home directory, `*` matches
+    /// within a single path component, and `**` matches any number of components.
+    pub path: String,
+
+    /// Harness to switch (e.g. "claude-code").
+    pub harness: String,
+
+    /// Profile to activate.
+    pub profile: String,
+}
+
+impl AutoRule {
+    /// Whether `dir` matches this rule's path pattern.
+    pub fn matches(&self, dir: &Path) -> bool {
+        let pattern = expand_home(&self.path);
+        let pattern = components(&pattern);
+        let dir = components(dir);
+        let pattern: Vec<&str> = pattern.iter().map(String::as_str).collect();
+        let dir: Vec<&str> = dir.iter().map(String::as_str).collect();
+        match_components(&pattern, &dir)
+    }
+}
+
+/// Expands a leading `~` in `pattern` to the home directory.
+pub(crate) fn expand_home(pattern: &str) -> PathBuf {
+    let home = || harness_locate::platform::home_dir().ok();
+    if pattern == "~" {
+        if let Some(home) = home() {
+            return home;
+        }
+    } else if let Some(rest) = pattern.strip_prefix("~/")
+        && let Some(home) = home()
+    {
+        return home.join(rest);
+    }
+    PathBuf::from(pattern)
+}
+
+fn components(path: &Path) -> Vec<String> {
+    path.components()
+        .filter_map(|c| match c {
+            Component::Normal(name) => Some(name.to_string_lossy().into_owned()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn match_components(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|skip| match_components(rest, &path[skip..])),
+        Some((segment, rest)) => match path.split_first() {
+            Some((name, path_rest)) => {
+                match_segment(segment, name) && match_components(rest, path_rest)
+            }
+            None => false,
+        },
+    }
+}
+
+fn match_segment(pattern: &str, name: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == name,
+        Some((prefix, rest)) => {
+            let Some(name) = name.strip_prefix(prefix) else {
+                return false;
+            };
+            if rest.is_empty() {
+                return true;
+            }
+            name.char_indices()
+                .map(|(i, _)| i)
+                .chain(std::iter::once(name.len()))
+                .any(|i| match_segment(rest, &name[i..]))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(path: &str) -> AutoRule {
+        AutoRule {
+            path: path.to_string(),
+            harness: "claude-code".to_string(),
+            profile: "work".to_string(),
+        }
+    }
+
+    #[test]
+    fn double_star_matches_the_whole_tree() {
+        let rule = rule("/home/me/work/**");
+        assert!(rule.matches(Path::new("/home/me/work")));
+        assert!(rule.matches(Path::new("/home/me/work/app/src")));
+        assert!(!rule.matches(Path::new("/home/me/personal")));
+        assert!(!rule.matches(Path::new("/home/me/workshop")));
+    }
+
+    #[test]
+    fn single_star_matches_one_component() {
+        let rule = rule("/src/*/api");
+        assert!(rule.matches(Path::new("/src/app/api")));
+        assert!(!rule.matches(Path::new("/src/app/nested/api")));
+    }
+
+    #[test]
+    fn star_matches_within_a_component() {
+        let rule = rule("/src/client-*");
+        assert!(rule.matches(Path::new("/src/client-acme")));
+        assert!(!rule.matches(Path::new("/src/internal")));
+    }
+
+    #[test]
+    fn plain_path_matches_only_itself() {
+        let rule = rule("/src/app");
+        assert!(rule.matches(Path::new("/src/app")));
+        assert!(rule.matches(Path::new("/src/app/")));
+        assert!(!rule.matches(Path::new("/src/app/sub")));
+    }
+
+    #[test]
+    fn tilde_expands_to_home() {
+        let home = harness_locate::platform::home_dir().unwrap();
+        assert!(rule("~/work/**").matches(&home.join("work/app")));
+        assert!(!rule("~/work/**").matches(Path::new("/elsewhere/work/app")));
+    }
+}
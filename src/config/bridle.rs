@@ -1,8 +1,11 @@
 //! Bridle's own configuration file handling.
 
+use harness_locate::Scope;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::path::PathBuf;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+use super::auto::AutoRule;
 
 /// User preference for TUI view mode.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -13,17 +16,116 @@ pub enum ViewPreference {
     Dashboard,
     /// Legacy list view.
     Legacy,
-    /// Card-based view (requires tui-cards feature).
-    #[cfg(feature = "tui-cards")]
+    /// Responsive grid of profile cards.
     Cards,
 }
 
+impl ViewPreference {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Dashboard => "dashboard",
+            Self::Legacy => "legacy",
+            Self::Cards => "cards",
+        }
+    }
+}
+
+impl std::str::FromStr for ViewPreference {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "dashboard" => Ok(Self::Dashboard),
+            "legacy" => Ok(Self::Legacy),
+            "cards" => Ok(Self::Cards),
+            _ => Err(s.to_string()),
+        }
+    }
+}
+
+/// Built-in key binding sets the `[tui.keys]` overrides are applied on top of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyPreset {
+    /// Arrow keys plus single-letter mnemonics.
+    #[default]
+    Default,
+    /// `hjkl` movement, `g`/`G` for first/last.
+    Vim,
+    /// `C-n`/`C-p` movement, `C-s` to search.
+    Emacs,
+}
+
+/// One key or a list of alternatives, e.g. `"q"` or `["q", "esc"]`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum KeyList {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl KeyList {
+    pub fn keys(&self) -> &[String] {
+        match self {
+            Self::One(key) => std::slice::from_ref(key),
+            Self::Many(keys) => keys,
+        }
+    }
+}
+
+/// `[tui.keys]`: a preset plus per-action overrides (`action = "key"`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct KeyConfig {
+    #[serde(default)]
+    pub preset: KeyPreset,
+
+    /// Action name to key(s), replacing the preset's binding for that action.
+    #[serde(flatten)]
+    pub bindings: BTreeMap<String, KeyList>,
+}
+
+/// Built-in palettes the `[tui.theme]` color slots are applied on top of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemePreset {
+    /// For dark terminal backgrounds.
+    #[default]
+    Default,
+    /// For light terminal backgrounds.
+    Light,
+    /// Avoids red/green pairs; uses blue and orange instead.
+    Colorblind,
+}
+
+/// `[tui.theme]`: a preset plus named color slots (`warning = "magenta"`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct ThemeConfig {
+    #[serde(default)]
+    pub preset: ThemePreset,
+
+    /// Slot name to color: a name (`"cyan"`), hex (`"#ff8800"`), or index (`"208"`).
+    #[serde(flatten)]
+    pub colors: BTreeMap<String, String>,
+}
+
 /// TUI-specific configuration.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct TuiConfig {
     /// Preferred view mode.
     #[serde(default)]
     pub view: ViewPreference,
+
+    /// Key bindings.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub keys: KeyConfig,
+
+    /// Colors.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub theme: ThemeConfig,
+}
+
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
 }
 
 /// Bridle's configuration.
@@ -33,6 +135,10 @@ pub struct BridleConfig {
     #[serde(default)]
     pub active: HashMap<String, String>,
 
+    /// Active project profiles per checkout (project root -> harness_id -> profile_name).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub projects: BTreeMap<String, HashMap<String, String>>,
+
     /// Whether to create `BRIDLE_PROFILE_<name>` marker files in harness config directories.
     /// Disabled by default (opt-in).
     #[serde(default)]
@@ -54,6 +160,22 @@ pub struct BridleConfig {
     /// Default harness to show when TUI opens.
     #[serde(default)]
     pub default_harness: Option<String>,
+
+    /// Skill registry index (HTTP URL or local path to a JSON file).
+    #[serde(default)]
+    pub registry: Option<String>,
+
+    /// Directory rules applied by `bridle hook`, first match per harness wins.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub auto: Vec<AutoRule>,
+
+    /// Snippets appended to a profile's rules file on switch (harness_id -> profile -> names).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub snippets: BTreeMap<String, BTreeMap<String, Vec<String>>>,
+
+    /// Model aliases added to or overriding the built-in ones (alias -> harness_id -> model).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub models: BTreeMap<String, BTreeMap<String, String>>,
 }
 
 impl BridleConfig {
@@ -80,9 +202,7 @@ impl BridleConfig {
 
     /// Get the default configuration file path.
     pub fn config_path() -> crate::error::Result<PathBuf> {
-        harness_locate::platform::config_dir()
-            .map(|d| d.join("bridle").join("config.toml"))
-            .map_err(|e| crate::error::Error::NoConfigFound(e.to_string()))
+        Self::config_dir().map(|d| d.join("config.toml"))
     }
 
     /// Get the configuration directory path.
@@ -102,11 +222,39 @@ impl BridleConfig {
         Self::config_dir().map(|d| d.join("profiles"))
     }
 
+    /// Get the directory holding project-scope profiles, backups, and trash.
+    pub fn projects_dir() -> crate::error::Result<PathBuf> {
+        Self::config_dir().map(|d| d.join("projects"))
+    }
+
+    /// Get the directory holding harness descriptors (`*.toml`).
+    pub fn harnesses_dir() -> crate::error::Result<PathBuf> {
+        Self::config_dir().map(|d| d.join("harnesses"))
+    }
+
+    /// Get the directory holding rules snippets (`*.md`).
+    pub fn snippets_dir() -> crate::error::Result<PathBuf> {
+        Self::config_dir().map(|d| d.join("snippets"))
+    }
+
+    /// Get the profiles directory for `scope`.
+    ///
+    /// Project profiles are shared between checkouts; only the active one is per checkout.
+    pub fn profiles_dir_for(scope: &Scope) -> crate::error::Result<PathBuf> {
+        match scope {
+            Scope::Project(_) => Self::projects_dir().map(|d| d.join("profiles")),
+            _ => Self::profiles_dir(),
+        }
+    }
+
     /// Save configuration to the default location.
     pub fn save(&self) -> crate::error::Result<()> {
         let path = Self::config_path()?;
         let content =
             toml::to_string_pretty(self).map_err(|e| crate::error::Error::Config(e.to_string()))?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
         std::fs::write(&path, content)?;
         Ok(())
     }
@@ -127,6 +275,47 @@ impl BridleConfig {
         self.active.remove(harness_id);
     }
 
+    /// Get the active profile for a harness in `scope`.
+    pub fn active_profile_in(&self, scope: &Scope, harness_id: &str) -> Option<&str> {
+        match scope {
+            Scope::Project(root) => self
+                .projects
+                .get(&project_key(root))
+                .and_then(|active| active.get(harness_id))
+                .map(|s| s.as_str()),
+            _ => self.active_profile_for(harness_id),
+        }
+    }
+
+    /// Set the active profile for a harness in `scope`.
+    pub fn set_active_profile_in(&mut self, scope: &Scope, harness_id: &str, profile: &str) {
+        match scope {
+            Scope::Project(root) => {
+                self.projects
+                    .entry(project_key(root))
+                    .or_default()
+                    .insert(harness_id.to_string(), profile.to_string());
+            }
+            _ => self.set_active_profile(harness_id, profile),
+        }
+    }
+
+    /// Clear the active profile for a harness in `scope`.
+    pub fn clear_active_profile_in(&mut self, scope: &Scope, harness_id: &str) {
+        match scope {
+            Scope::Project(root) => {
+                let key = project_key(root);
+                if let Some(active) = self.projects.get_mut(&key) {
+                    active.remove(harness_id);
+                    if active.is_empty() {
+                        self.projects.remove(&key);
+                    }
+                }
+            }
+            _ => self.clear_active_profile(harness_id),
+        }
+    }
+
     pub fn profile_marker_enabled(&self) -> bool {
         self.profile_marker
     }
@@ -142,4 +331,195 @@ impl BridleConfig {
     pub fn set_default_harness(&mut self, harness_id: Option<&str>) {
         self.default_harness = harness_id.map(String::from);
     }
+
+    pub fn registry(&self) -> Option<&str> {
+        self.registry.as_deref()
+    }
+
+    pub fn set_registry(&mut self, location: Option<&str>) {
+        self.registry = location.map(String::from);
+    }
+
+    /// Snippets composed into the rules file of `profile`, in order.
+    pub fn snippets_for(&self, harness_id: &str, profile: &str) -> &[String] {
+        self.snippets
+            .get(harness_id)
+            .and_then(|profiles| profiles.get(profile))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Appends `snippet` to the snippets of `profile`. Returns false if it was already there.
+    pub fn add_snippet(&mut self, harness_id: &str, profile: &str, snippet: &str) -> bool {
+        let names = self
+            .snippets
+            .entry(harness_id.to_string())
+            .or_default()
+            .entry(profile.to_string())
+            .or_default();
+        if names.iter().any(|n| n == snippet) {
+            return false;
+        }
+        names.push(snippet.to_string());
+        true
+    }
+
+    /// Removes `snippet` from the snippets of `profile`. Returns false if it wasn't there.
+    pub fn remove_snippet(&mut self, harness_id: &str, profile: &str, snippet: &str) -> bool {
+        let Some(profiles) = self.snippets.get_mut(harness_id) else {
+            return false;
+        };
+        let Some(names) = profiles.get_mut(profile) else {
+            return false;
+        };
+        let before = names.len();
+        names.retain(|n| n != snippet);
+        let removed = names.len() != before;
+        if names.is_empty() {
+            profiles.remove(profile);
+        }
+        if profiles.is_empty() {
+            self.snippets.remove(harness_id);
+        }
+        removed
+    }
+
+    /// Auto-switch rules matching `dir`, in config order, with their index.
+    pub fn auto_rules_for<'a>(
+        &'a self,
+        dir: &'a Path,
+    ) -> impl Iterator<Item = (usize, &'a AutoRule)> + 'a {
+        self.auto
+            .iter()
+            .enumerate()
+            .filter(move |(_, rule)| rule.matches(dir))
+    }
+}
+
+fn project_key(root: &Path) -> String {
+    root.display().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn project_profiles_are_tracked_per_checkout() {
+        let mut config = BridleConfig::default();
+        let first = Scope::Project(PathBuf::from("/work/first"));
+        let second = Scope::Project(PathBuf::from("/work/second"));
+
+        config.set_active_profile("claude-code", "global");
+        config.set_active_profile_in(&first, "claude-code", "review-mode");
+
+        assert_eq!(
+            config.active_profile_in(&Scope::Global, "claude-code"),
+            Some("global")
+        );
+        assert_eq!(
+            config.active_profile_in(&first, "claude-code"),
+            Some("review-mode")
+        );
+        assert_eq!(config.active_profile_in(&second, "claude-code"), None);
+
+        let content = toml::to_string_pretty(&config).unwrap();
+        let reloaded: BridleConfig = toml::from_str(&content).unwrap();
+        assert_eq!(
+            reloaded.active_profile_in(&first, "claude-code"),
+            Some("review-mode")
+        );
+
+        config.clear_active_profile_in(&first, "claude-code");
+        assert!(config.projects.is_empty());
+        assert_eq!(config.active_profile_for("claude-code"), Some("global"));
+    }
+
+    #[test]
+    fn parses_tui_keys_and_theme() {
+        let config: BridleConfig = toml::from_str(
+            r##"
+            [tui.keys]
+            preset = "vim"
+            quit = "ctrl-q"
+            search = ["/", "ctrl-s"]
+
+            [tui.theme]
+            preset = "colorblind"
+            warning = "#ff8800"
+            "##,
+        )
+        .unwrap();
+
+        assert_eq!(config.tui.keys.preset, KeyPreset::Vim);
+        assert_eq!(config.tui.keys.bindings["quit"].keys(), ["ctrl-q"]);
+        assert_eq!(config.tui.keys.bindings["search"].keys(), ["/", "ctrl-s"]);
+        assert_eq!(config.tui.theme.preset, ThemePreset::Colorblind);
+        assert_eq!(config.tui.theme.colors["warning"], "#ff8800");
+    }
+
+    #[test]
+    fn auto_rules_round_trip_after_other_settings() {
+        let config: BridleConfig = toml::from_str(
+            r#"
+            profile_marker = true
+
+            [[auto]]
+            path = "~/work/**"
+            harness = "claude-code"
+            profile = "work"
+
+            [[auto]]
+            path = "/src/*"
+            harness = "opencode"
+            profile = "oss"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.auto.len(), 2);
+        assert_eq!(config.auto[1].profile, "oss");
+
+        let saved: BridleConfig =
+            toml::from_str(&toml::to_string_pretty(&config).unwrap()).unwrap();
+        assert_eq!(saved.auto, config.auto);
+        assert!(saved.profile_marker);
+
+        let matched: Vec<usize> = saved
+            .auto_rules_for(Path::new("/src/app"))
+            .map(|(index, _)| index)
+            .collect();
+        assert_eq!(matched, [1]);
+    }
+
+    #[test]
+    fn snippets_are_kept_per_profile_and_pruned() {
+        let mut config = BridleConfig::default();
+        assert!(config.add_snippet("claude-code", "work", "style"));
+        assert!(config.add_snippet("claude-code", "work", "security"));
+        assert!(!config.add_snippet("claude-code", "work", "style"));
+        config.auto.push(AutoRule {
+            path: "~/work/**".to_string(),
+            harness: "claude-code".to_string(),
+            profile: "work".to_string(),
+        });
+
+        let saved: BridleConfig =
+            toml::from_str(&toml::to_string_pretty(&config).unwrap()).unwrap();
+        assert_eq!(
+            saved.snippets_for("claude-code", "work"),
+            ["style", "security"]
+        );
+        assert!(saved.snippets_for("opencode", "work").is_empty());
+
+        assert!(config.remove_snippet("claude-code", "work", "style"));
+        assert!(!config.remove_snippet("claude-code", "work", "style"));
+        assert!(config.remove_snippet("claude-code", "work", "security"));
+        assert!(config.snippets.is_empty());
+    }
+
+    #[test]
+    fn default_tui_sections_are_not_saved() {
+        let content = toml::to_string_pretty(&BridleConfig::default()).unwrap();
+        assert!(!content.contains("keys"));
+        assert!(!content.contains("theme"));
+    }
 }
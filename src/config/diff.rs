@@ -0,0 +1,431 @@
+//! Semantic and file-level comparison of two profiles.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use similar::TextDiff;
+
+use super::manager::EXCLUDED_DIRS;
+use super::types::{McpServerInfo, PermissionEffect, ProfileInfo, ResourceSummary};
+
+/// Files larger than this are compared but never rendered as a text diff.
+const MAX_DIFF_BYTES: u64 = 1024 * 1024;
+
+/// A scalar setting that differs between two profiles.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldChange {
+    pub label: &'static str,
+    pub left: Option<String>,
+    pub right: Option<String>,
+}
+
+/// Items of one kind present on only one side, or changed between sides.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SectionDiff {
+    pub label: &'static str,
+    /// Only in the right-hand profile.
+    pub added: Vec<String>,
+    /// Only in the left-hand profile.
+    pub removed: Vec<String>,
+    /// In both, with different settings.
+    pub changed: Vec<String>,
+    /// In both and identical.
+    pub unchanged: Vec<String>,
+}
+
+impl SectionDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Semantic comparison built from two [`ProfileInfo`]s.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfileDiff {
+    pub fields: Vec<FieldChange>,
+    pub sections: Vec<SectionDiff>,
+}
+
+impl ProfileDiff {
+    pub fn new(left: &ProfileInfo, right: &ProfileInfo) -> Self {
+        let mode = |info: &ProfileInfo| {
+            info.permissions
+                .as_ref()
+                .and_then(|p| p.default_mode.clone())
+        };
+        let fields = [
+            ("Model", left.model.clone(), right.model.clone()),
+            ("Theme", left.theme.clone(), right.theme.clone()),
+            ("Permission mode", mode(left), mode(right)),
+        ]
+        .into_iter()
+        .filter(|(_, l, r)| l != r)
+        .map(|(label, left, right)| FieldChange { label, left, right })
+        .collect();
+
+        let empty = ResourceSummary::default();
+        let sections = vec![
+            mcp_section(&left.mcp_servers, &right.mcp_servers),
+            names_section("Skills", &left.skills, &right.skills),
+            names_section(
+                "Agents",
+                left.agents.as_ref().unwrap_or(&empty),
+                right.agents.as_ref().unwrap_or(&empty),
+            ),
+            names_section("Commands", &left.commands, &right.commands),
+            names_section(
+                "Permissions",
+                &permission_summary(left),
+                &permission_summary(right),
+            ),
+            names_section("Hooks", &hook_summary(left), &hook_summary(right)),
+        ];
+
+        Self { fields, sections }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty() && self.sections.iter().all(SectionDiff::is_empty)
+    }
+}
+
+/// A permission rule present in only one of two profiles.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PermissionChange {
+    pub effect: PermissionEffect,
+    pub rule: String,
+    /// Whether the right-hand profile has the rule and the left doesn't.
+    pub added: bool,
+    /// Whether the change lets more tool uses through without a prompt: an
+    /// allow rule added, or an ask or deny rule dropped.
+    pub widens: bool,
+}
+
+/// Permission rules added or removed going from `left` to `right`, grouped by
+/// effect (allow, ask, deny) with removals first.
+pub fn permission_changes(left: &ProfileInfo, right: &ProfileInfo) -> Vec<PermissionChange> {
+    let rules = |info: &ProfileInfo| -> BTreeSet<(PermissionEffect, String)> {
+        info.permissions
+            .iter()
+            .flat_map(|p| p.rules().map(|(effect, rule)| (effect, rule.to_string())))
+            .collect()
+    };
+    let (left, right) = (rules(left), rules(right));
+    let change = |(effect, rule): &(PermissionEffect, String), added: bool| PermissionChange {
+        effect: *effect,
+        rule: rule.clone(),
+        added,
+        widens: added == (*effect == PermissionEffect::Allow),
+    };
+    let mut changes: Vec<PermissionChange> = left
+        .difference(&right)
+        .map(|r| change(r, false))
+        .chain(right.difference(&left).map(|r| change(r, true)))
+        .collect();
+    changes.sort_by_key(|c| c.effect);
+    changes
+}
+
+fn permission_summary(info: &ProfileInfo) -> ResourceSummary {
+    ResourceSummary {
+        items: info
+            .permissions
+            .iter()
+            .flat_map(|p| {
+                p.rules()
+                    .map(|(effect, rule)| format!("{} {rule}", effect.as_str()))
+            })
+            .collect(),
+        directory_exists: true,
+    }
+}
+
+fn hook_summary(info: &ProfileInfo) -> ResourceSummary {
+    ResourceSummary {
+        items: info.hooks.iter().flatten().map(|h| h.to_string()).collect(),
+        directory_exists: true,
+    }
+}
+
+fn mcp_section(left: &[McpServerInfo], right: &[McpServerInfo]) -> SectionDiff {
+    let find = |servers: &[McpServerInfo], name: &str| -> Option<McpServerInfo> {
+        servers.iter().find(|s| s.name == name).cloned()
+    };
+    let mut section = SectionDiff {
+        label: "MCP Servers",
+        ..SectionDiff::default()
+    };
+
+    let names: BTreeSet<&str> = left.iter().chain(right).map(|s| s.name.as_str()).collect();
+    for name in names {
+        match (find(left, name), find(right, name)) {
+            (Some(l), Some(r)) => {
+                let same = l.enabled == r.enabled
+                    && l.server_type == r.server_type
+                    && l.command == r.command
+                    && l.args == r.args
+                    && l.url == r.url;
+                if same {
+                    section.unchanged.push(name.to_string());
+                } else {
+                    section.changed.push(name.to_string());
+                }
+            }
+            (Some(_), None) => section.removed.push(name.to_string()),
+            (None, Some(_)) => section.added.push(name.to_string()),
+            (None, None) => {}
+        }
+    }
+    section
+}
+
+fn names_section(
+    label: &'static str,
+    left: &ResourceSummary,
+    right: &ResourceSummary,
+) -> SectionDiff {
+    let left: BTreeSet<&String> = left.items.iter().collect();
+    let right: BTreeSet<&String> = right.items.iter().collect();
+    SectionDiff {
+        label,
+        added: right.difference(&left).map(|s| s.to_string()).collect(),
+        removed: left.difference(&right).map(|s| s.to_string()).collect(),
+        changed: Vec::new(),
+        unchanged: left.intersection(&right).map(|s| s.to_string()).collect(),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FileStatus {
+    Added,
+    Removed,
+    Modified,
+}
+
+/// A file that differs between two profile directories.
+#[derive(Debug, Clone, Serialize)]
+pub struct FileChange {
+    /// Path relative to both directories.
+    pub path: PathBuf,
+    pub status: FileStatus,
+}
+
+/// Files that were added, removed, or modified going from `left` to `right`.
+///
+/// Bridle marker files and directories that are never copied into profiles
+/// are ignored.
+pub fn changed_files(left: &Path, right: &Path) -> std::io::Result<Vec<FileChange>> {
+    let left_files = relative_files(left)?;
+    let right_files = relative_files(right)?;
+
+    let mut changes = Vec::new();
+    for path in left_files.union(&right_files) {
+        let status = match (left_files.contains(path), right_files.contains(path)) {
+            (true, false) => FileStatus::Removed,
+            (false, true) => FileStatus::Added,
+            _ if files_equal(&left.join(path), &right.join(path))? => continue,
+            _ => FileStatus::Modified,
+        };
+        changes.push(FileChange {
+            path: path.clone(),
+            status,
+        });
+    }
+    Ok(changes)
+}
+
+fn relative_files(root: &Path) -> std::io::Result<BTreeSet<PathBuf>> {
+    let mut files = BTreeSet::new();
+    if !root.exists() {
+        return Ok(files);
+    }
+
+    let mut pending = vec![root.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        for entry in fs::read_dir(&dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            let file_type = entry.file_type()?;
+            let file_name = entry.file_name();
+            if EXCLUDED_DIRS.iter().any(|ex| file_name == *ex) {
+                continue;
+            }
+            if file_type.is_dir() {
+                pending.push(path);
+            } else if file_type.is_file()
+                && !file_name.to_string_lossy().starts_with("BRIDLE_PROFILE_")
+                && let Ok(relative) = path.strip_prefix(root)
+            {
+                files.insert(relative.to_path_buf());
+            }
+        }
+    }
+    Ok(files)
+}
+
+fn files_equal(a: &Path, b: &Path) -> std::io::Result<bool> {
+    if fs::metadata(a)?.len() != fs::metadata(b)?.len() {
+        return Ok(false);
+    }
+    Ok(fs::read(a)? == fs::read(b)?)
+}
+
+/// Unified diff of `relative` between the two directories.
+///
+/// A missing side is treated as empty. Binary or oversized files produce a
+/// one-line note instead of a diff.
+pub fn unified_file_diff(left: &Path, right: &Path, relative: &Path) -> String {
+    let read = |root: &Path| -> Result<String, &'static str> {
+        let path = root.join(relative);
+        match fs::metadata(&path) {
+            Err(_) => Ok(String::new()),
+            Ok(meta) if meta.len() > MAX_DIFF_BYTES => Err("file too large to diff"),
+            Ok(_) => fs::read_to_string(&path).map_err(|_| "binary file"),
+        }
+    };
+
+    let (old, new) = match (read(left), read(right)) {
+        (Ok(old), Ok(new)) => (old, new),
+        (Err(note), _) | (_, Err(note)) => {
+            return format!("{}: {}\n", relative.display(), note);
+        }
+    };
+
+    let name = relative.display().to_string();
+    TextDiff::from_lines(&old, &new)
+        .unified_diff()
+        .context_radius(3)
+        .header(&format!("a/{}", name), &format!("b/{}", name))
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn profile(model: Option<&str>, servers: &[(&str, bool)], skills: &[&str]) -> ProfileInfo {
+        ProfileInfo {
+            model: model.map(String::from),
+            mcp_servers: servers
+                .iter()
+                .map(|(name, enabled)| McpServerInfo {
+                    name: name.to_string(),
+                    enabled: *enabled,
+                    ..McpServerInfo::default()
+                })
+                .collect(),
+            skills: ResourceSummary {
+                items: skills.iter().map(|s| s.to_string()).collect(),
+                directory_exists: true,
+            },
+            ..ProfileInfo::default()
+        }
+    }
+
+    #[test]
+    fn semantic_diff_reports_fields_and_sections() {
+        let left = profile(Some("a"), &[("fs", true), ("gh", true)], &["one", "two"]);
+        let right = profile(
+            Some("b"),
+            &[("fs", false), ("web", true)],
+            &["two", "three"],
+        );
+
+        let diff = ProfileDiff::new(&left, &right);
+        assert_eq!(diff.fields.len(), 1);
+        assert_eq!(diff.fields[0].label, "Model");
+
+        let mcp = &diff.sections[0];
+        assert_eq!(mcp.added, vec!["web"]);
+        assert_eq!(mcp.removed, vec!["gh"]);
+        assert_eq!(mcp.changed, vec!["fs"]);
+
+        let skills = &diff.sections[1];
+        assert_eq!(skills.added, vec!["three"]);
+        assert_eq!(skills.removed, vec!["one"]);
+        assert_eq!(skills.unchanged, vec!["two"]);
+    }
+
+    #[test]
+    fn permission_changes_flag_widening_rules() {
+        use crate::config::types::PermissionRules;
+
+        let with_rules = |allow: &[&str], deny: &[&str], mode: Option<&str>| ProfileInfo {
+            permissions: Some(PermissionRules {
+                allow: allow.iter().map(|r| r.to_string()).collect(),
+                deny: deny.iter().map(|r| r.to_string()).collect(),
+                default_mode: mode.map(String::from),
+                ..PermissionRules::default()
+            }),
+            ..ProfileInfo::default()
+        };
+        let left = with_rules(&["Read"], &["Bash(rm:*)"], None);
+        let right = with_rules(&["Read", "Bash(curl:*)"], &[], Some("acceptEdits"));
+
+        let changes = permission_changes(&left, &right);
+        let summary: Vec<(&str, &str, bool, bool)> = changes
+            .iter()
+            .map(|c| (c.effect.as_str(), c.rule.as_str(), c.added, c.widens))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("allow", "Bash(curl:*)", true, true),
+                ("deny", "Bash(rm:*)", false, true),
+            ]
+        );
+        assert!(permission_changes(&right, &left).iter().all(|c| !c.widens));
+
+        let diff = ProfileDiff::new(&left, &right);
+        assert_eq!(diff.fields[0].label, "Permission mode");
+        let permissions = diff
+            .sections
+            .iter()
+            .find(|s| s.label == "Permissions")
+            .unwrap();
+        assert_eq!(permissions.added, ["allow Bash(curl:*)"]);
+        assert_eq!(permissions.removed, ["deny Bash(rm:*)"]);
+    }
+
+    #[test]
+    fn identical_profiles_have_empty_diff() {
+        let p = profile(Some("a"), &[("fs", true)], &["one"]);
+        assert!(ProfileDiff::new(&p, &p).is_empty());
+    }
+
+    #[test]
+    fn changed_files_and_unified_diff() {
+        let temp = TempDir::new().unwrap();
+        let left = temp.path().join("left");
+        let right = temp.path().join("right");
+        fs::create_dir_all(left.join("skills/a")).unwrap();
+        fs::create_dir_all(&right).unwrap();
+        fs::write(left.join("skills/a/SKILL.md"), "skill").unwrap();
+        fs::write(left.join("config.json"), "{\n  \"model\": \"a\"\n}\n").unwrap();
+        fs::write(right.join("config.json"), "{\n  \"model\": \"b\"\n}\n").unwrap();
+        fs::write(right.join("same.txt"), "x").unwrap();
+        fs::write(left.join("same.txt"), "x").unwrap();
+        fs::write(right.join("BRIDLE_PROFILE_work"), "").unwrap();
+
+        let changes = changed_files(&left, &right).unwrap();
+        let summary: Vec<(String, FileStatus)> = changes
+            .iter()
+            .map(|c| (c.path.display().to_string(), c.status))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("config.json".to_string(), FileStatus::Modified),
+                ("skills/a/SKILL.md".to_string(), FileStatus::Removed),
+            ]
+        );
+
+        let diff = unified_file_diff(&left, &right, Path::new("config.json"));
+        assert!(diff.contains("-  \"model\": \"a\""));
+        assert!(diff.contains("+  \"model\": \"b\""));
+    }
+}
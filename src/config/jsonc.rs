@@ -1,5 +1,7 @@
 //! JSONC parsing utilities for OpenCode config files.
 
+use serde_json::{Map, Value};
+
 pub fn strip_jsonc_comments(input: &str) -> String {
     let mut result = String::with_capacity(input.len());
     let mut chars = input.chars().peekable();
@@ -85,6 +87,363 @@ fn strip_trailing_commas(input: &str) -> String {
     result
 }
 
+/// Rewrites `original` JSONC so it parses to `new`, given that it parses to
+/// `old`, touching only the members that changed.
+///
+/// Comments and formatting elsewhere in the document are kept, and new keys go
+/// at the end of their object. Returns `None` if `original` is not an object.
+pub fn patch(original: &str, old: &Value, new: &Value) -> Option<String> {
+    let mut parser = Parser {
+        text: original,
+        pos: 0,
+    };
+    let root = parser.value()?;
+    if !matches!(root, Node::Object { .. }) {
+        return None;
+    }
+
+    let mut edits = Vec::new();
+    patch_node(original, &root, old, new, &mut edits);
+    // Later edits first so earlier offsets stay valid; at the same offset the
+    // removal goes before the insertion that replaces it.
+    edits.sort_by_key(|e| std::cmp::Reverse((e.start, e.end)));
+    let mut result = original.to_string();
+    for edit in edits {
+        result.replace_range(edit.start..edit.end, &edit.text);
+    }
+    Some(result)
+}
+
+/// Byte ranges of a parsed JSONC value.
+enum Node {
+    Object {
+        start: usize,
+        end: usize,
+        members: Vec<Member>,
+    },
+    Other {
+        start: usize,
+        end: usize,
+    },
+}
+
+impl Node {
+    fn span(&self) -> (usize, usize) {
+        match self {
+            Node::Object { start, end, .. } | Node::Other { start, end } => (*start, *end),
+        }
+    }
+}
+
+struct Member {
+    key: String,
+    start: usize,
+    value: Node,
+    /// Offset of the comma after the value, if any.
+    comma: Option<usize>,
+}
+
+struct Edit {
+    start: usize,
+    end: usize,
+    text: String,
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.pos).copied()
+    }
+
+    fn skip_trivia(&mut self) {
+        loop {
+            let rest = &self.text[self.pos..];
+            if rest.starts_with("//") {
+                self.pos += rest.find('\n').unwrap_or(rest.len());
+            } else if rest.starts_with("/*") {
+                self.pos += rest.find("*/").map_or(rest.len(), |end| end + 2);
+            } else if self.peek().is_some_and(|b| b.is_ascii_whitespace()) {
+                self.pos += 1;
+            } else {
+                return;
+            }
+        }
+    }
+
+    fn value(&mut self) -> Option<Node> {
+        self.skip_trivia();
+        let start = self.pos;
+        match self.peek()? {
+            b'{' => return self.object(),
+            b'[' => {
+                self.pos += 1;
+                loop {
+                    self.skip_trivia();
+                    match self.peek()? {
+                        b']' => break,
+                        b',' => self.pos += 1,
+                        _ => {
+                            self.value()?;
+                        }
+                    }
+                }
+                self.pos += 1;
+            }
+            b'"' => {
+                self.string()?;
+            }
+            _ => {
+                while self
+                    .peek()
+                    .is_some_and(|b| !b.is_ascii_whitespace() && !b",:]}/".contains(&b))
+                {
+                    self.pos += 1;
+                }
+                if self.pos == start {
+                    return None;
+                }
+            }
+        }
+        Some(Node::Other {
+            start,
+            end: self.pos,
+        })
+    }
+
+    fn string(&mut self) -> Option<String> {
+        let start = self.pos;
+        self.pos += 1;
+        loop {
+            match self.peek()? {
+                b'\\' => self.pos += 2,
+                b'"' => break,
+                _ => self.pos += 1,
+            }
+        }
+        self.pos += 1;
+        serde_json::from_str(&self.text[start..self.pos]).ok()
+    }
+
+    fn object(&mut self) -> Option<Node> {
+        let start = self.pos;
+        self.pos += 1;
+        let mut members = Vec::new();
+        loop {
+            self.skip_trivia();
+            if self.peek()? == b'}' {
+                break;
+            }
+            let member_start = self.pos;
+            if self.peek()? != b'"' {
+                return None;
+            }
+            let key = self.string()?;
+            self.skip_trivia();
+            if self.peek()? != b':' {
+                return None;
+            }
+            self.pos += 1;
+            let value = self.value()?;
+            self.skip_trivia();
+            let comma = (self.peek()? == b',').then_some(self.pos);
+            if comma.is_some() {
+                self.pos += 1;
+            }
+            members.push(Member {
+                key,
+                start: member_start,
+                value,
+                comma,
+            });
+        }
+        self.pos += 1;
+        Some(Node::Object {
+            start,
+            end: self.pos,
+            members,
+        })
+    }
+}
+
+fn line_start(text: &str, pos: usize) -> usize {
+    text[..pos].rfind('\n').map_or(0, |i| i + 1)
+}
+
+/// Leading whitespace of the line containing `pos`.
+fn indent_at(text: &str, pos: usize) -> &str {
+    let line = &text[line_start(text, pos)..];
+    &line[..line.len() - line.trim_start().len()]
+}
+
+/// Whether only whitespace precedes `pos` on its line.
+fn starts_line(text: &str, pos: usize) -> bool {
+    text[line_start(text, pos)..pos].trim().is_empty()
+}
+
+fn render(value: &Value, indent: &str, multiline: bool) -> String {
+    let rendered = if multiline {
+        serde_json::to_string_pretty(value)
+    } else {
+        serde_json::to_string(value)
+    };
+    rendered
+        .unwrap_or_default()
+        .replace('\n', &format!("\n{indent}"))
+}
+
+fn patch_node(text: &str, node: &Node, old: &Value, new: &Value, edits: &mut Vec<Edit>) {
+    if old == new {
+        return;
+    }
+    if let (
+        Node::Object {
+            start,
+            end,
+            members,
+        },
+        Value::Object(old),
+        Value::Object(new),
+    ) = (node, old, new)
+    {
+        patch_object(text, (*start, *end), members, old, new, edits);
+        return;
+    }
+    let (start, end) = node.span();
+    edits.push(Edit {
+        start,
+        end,
+        text: render(new, indent_at(text, start), true),
+    });
+}
+
+fn patch_object(
+    text: &str,
+    (start, end): (usize, usize),
+    members: &[Member],
+    old: &Map<String, Value>,
+    new: &Map<String, Value>,
+    edits: &mut Vec<Edit>,
+) {
+    let multiline = members.first().is_none_or(|m| starts_line(text, m.start));
+    let indent = match members.first() {
+        Some(first) if multiline => indent_at(text, first.start).to_string(),
+        _ => format!("{}  ", indent_at(text, start)),
+    };
+
+    let mut last_kept = None;
+    for member in members {
+        match (old.get(&member.key), new.get(&member.key)) {
+            (_, None) => edits.push(removal(text, member)),
+            (Some(old_value), Some(new_value)) => {
+                patch_node(text, &member.value, old_value, new_value, edits);
+                last_kept = Some(member);
+            }
+            (None, Some(_)) => last_kept = Some(member),
+        }
+    }
+
+    let separator = if multiline {
+        format!("\n{indent}")
+    } else {
+        " ".to_string()
+    };
+    let added = new
+        .iter()
+        .filter(|(key, _)| !old.contains_key(*key))
+        .map(|(key, value)| {
+            format!(
+                "{}: {}",
+                Value::from(key.as_str()),
+                render(value, &indent, multiline)
+            )
+        })
+        .map(|member| format!("{separator}{member}"))
+        .collect::<Vec<_>>()
+        .join(",");
+    let trailing_comma = members.last().is_some_and(|m| m.comma.is_some());
+
+    let edit = match last_kept {
+        Some(kept) => match kept.comma {
+            Some(comma) if !added.is_empty() => Edit {
+                start: comma + 1,
+                end: comma + 1,
+                text: if trailing_comma {
+                    format!("{added},")
+                } else {
+                    added
+                },
+            },
+            // Members after it were all removed, leaving a comma JSON doesn't allow.
+            Some(comma) if !trailing_comma => Edit {
+                start: comma,
+                end: comma + 1,
+                text: String::new(),
+            },
+            None if !added.is_empty() => {
+                let (_, value_end) = kept.value.span();
+                Edit {
+                    start: value_end,
+                    end: value_end,
+                    text: format!(",{added}"),
+                }
+            }
+            _ => return,
+        },
+        None if !added.is_empty() => {
+            let mut added = added;
+            if !text[start..end].contains('\n') {
+                added.push('\n');
+                added.push_str(indent_at(text, start));
+            }
+            Edit {
+                start: start + 1,
+                end: start + 1,
+                text: added,
+            }
+        }
+        None => return,
+    };
+    edits.push(edit);
+}
+
+/// The edit removing `member` along with its line, if it has one to itself.
+fn removal(text: &str, member: &Member) -> Edit {
+    let own_line = starts_line(text, member.start);
+    match member.comma {
+        Some(comma) => {
+            let rest = &text[comma + 1..];
+            let spaces = rest.len() - rest.trim_start_matches([' ', '\t']).len();
+            let ends_line = rest[spaces..].starts_with('\n');
+            Edit {
+                start: if own_line && ends_line {
+                    line_start(text, member.start)
+                } else {
+                    member.start
+                },
+                end: comma + 1 + spaces + usize::from(own_line && ends_line),
+                text: String::new(),
+            }
+        }
+        None => {
+            let (_, value_end) = member.value.span();
+            let line = line_start(text, member.start);
+            Edit {
+                start: if own_line && line > 0 {
+                    line - 1
+                } else {
+                    member.start
+                },
+                end: value_end,
+                text: String::new(),
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -112,6 +471,54 @@ mod tests {
         assert_eq!(result, input);
     }
 
+    fn patched(original: &str, edit: impl FnOnce(&mut Value)) -> String {
+        let old: Value = serde_json::from_str(&strip_jsonc_comments(original)).unwrap();
+        let mut new = old.clone();
+        edit(&mut new);
+        patch(original, &old, &new).unwrap()
+    }
+
+    #[test]
+    fn patch_changes_only_edited_members() {
+        let original = "{\n  // theme first\n  \"theme\": \"dark\",\n  \"model\": \"a\", // pinned\n  \"mcp\": {}\n}\n";
+        let result = patched(original, |v| {
+            v["model"] = "b".into();
+            v["mcp"]["fs"] = serde_json::json!({"enabled": false});
+        });
+        assert_eq!(
+            result,
+            "{\n  // theme first\n  \"theme\": \"dark\",\n  \"model\": \"b\", // pinned\n  \"mcp\": {\n    \"fs\": {\n      \"enabled\": false\n    }\n  }\n}\n"
+        );
+    }
+
+    #[test]
+    fn patch_removes_and_appends_members() {
+        let original = "{\n  \"a\": 1,\n  // about b\n  \"b\": 2,\n  \"c\": 3\n}";
+        let without_last = patched(original, |v| {
+            v.as_object_mut().unwrap().shift_remove("c");
+        });
+        assert_eq!(without_last, "{\n  \"a\": 1,\n  // about b\n  \"b\": 2\n}");
+
+        let replaced = patched(original, |v| {
+            let object = v.as_object_mut().unwrap();
+            object.shift_remove("a");
+            object.insert("d".to_string(), 4.into());
+        });
+        assert_eq!(
+            replaced,
+            "{\n  // about b\n  \"b\": 2,\n  \"c\": 3,\n  \"d\": 4\n}"
+        );
+    }
+
+    #[test]
+    fn patch_keeps_trailing_comma_style() {
+        let original = "{\"a\": 1, /* note */ \"b\": 2,}";
+        let result = patched(original, |v| {
+            v["c"] = 3.into();
+        });
+        assert_eq!(result, "{\"a\": 1, /* note */ \"b\": 2, \"c\": 3,}");
+    }
+
     #[test]
     fn strips_trailing_commas() {
         let input = r#"{"a": 1, "b": 2,}"#;
@@ -1,12 +1,12 @@
 use std::path::{Path, PathBuf};
 
 pub use harness_locate::DirectoryStructure;
-use harness_locate::{Harness, Scope};
 
 use crate::config::jsonc::strip_jsonc_comments;
-use crate::config::types::{McpServerInfo, ResourceSummary};
+use crate::config::permissions;
+use crate::config::types::{HookInfo, McpServerInfo, PermissionRules, ResourceSummary};
 use crate::error::{Error, Result};
-use crate::harness::HarnessConfig;
+use crate::harness::{HarnessConfig, lookup, parse_document};
 
 pub fn extract_mcp_from_opencode_config(profile_path: &Path) -> Result<Vec<McpServerInfo>> {
     let config_path = profile_path.join("opencode.jsonc");
@@ -40,9 +40,13 @@ pub fn extract_mcp_from_opencode_config(profile_path: &Path) -> Result<Vec<McpSe
                     .collect()
             });
             let url = value.get("url").and_then(|v| v.as_str()).map(String::from);
+            let enabled = value
+                .get("enabled")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(true);
             McpServerInfo {
                 name: name.clone(),
-                enabled: true,
+                enabled,
                 server_type,
                 command,
                 args,
@@ -63,6 +67,8 @@ pub fn extract_mcp_servers(
         "amp-code" => extract_mcp_from_ampcode_config(profile_path),
         "claude-code" => extract_mcp_from_claudecode_config(profile_path),
         "goose" => extract_mcp_from_goose_config(profile_path),
+        "codex" => extract_mcp_from_codex_config(profile_path),
+        "gemini" => extract_mcp_from_gemini_config(profile_path),
         _ => extract_mcp_generic(harness, profile_path),
     }
 }
@@ -215,6 +221,10 @@ fn extract_mcp_from_ampcode_config(profile_path: &Path) -> Result<Vec<McpServerI
     let servers = mcp_obj
         .iter()
         .map(|(name, value)| {
+            let disabled = value
+                .get("disabled")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
             let command = value
                 .get("command")
                 .and_then(|v| v.as_str())
@@ -227,7 +237,7 @@ fn extract_mcp_from_ampcode_config(profile_path: &Path) -> Result<Vec<McpServerI
             let url = value.get("url").and_then(|v| v.as_str()).map(String::from);
             McpServerInfo {
                 name: name.clone(),
-                enabled: true,
+                enabled: !disabled,
                 server_type: Some("stdio".to_string()),
                 command,
                 args,
@@ -239,6 +249,109 @@ fn extract_mcp_from_ampcode_config(profile_path: &Path) -> Result<Vec<McpServerI
     Ok(servers)
 }
 
+fn extract_mcp_from_codex_config(profile_path: &Path) -> Result<Vec<McpServerInfo>> {
+    let config_path = profile_path.join("config.toml");
+    if !config_path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content = std::fs::read_to_string(&config_path)
+        .map_err(|e| Error::Config(format!("Failed to read config.toml: {}", e)))?;
+
+    let config: toml::Table = toml::from_str(&content)
+        .map_err(|e| Error::Config(format!("Failed to parse config.toml: {}", e)))?;
+
+    let mcp_table = match config.get("mcp_servers").and_then(|v| v.as_table()) {
+        Some(table) => table,
+        None => return Ok(Vec::new()),
+    };
+
+    let servers = mcp_table
+        .iter()
+        .map(|(name, value)| {
+            let enabled = value
+                .get("enabled")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(true);
+            let command = value
+                .get("command")
+                .and_then(|v| v.as_str())
+                .map(String::from);
+            let args = value.get("args").and_then(|v| v.as_array()).map(|arr| {
+                arr.iter()
+                    .filter_map(|a| a.as_str().map(String::from))
+                    .collect()
+            });
+            let url = value.get("url").and_then(|v| v.as_str()).map(String::from);
+            let server_type = if url.is_some() { "http" } else { "stdio" };
+            McpServerInfo {
+                name: name.clone(),
+                enabled,
+                server_type: Some(server_type.to_string()),
+                command,
+                args,
+                url,
+            }
+        })
+        .collect();
+
+    Ok(servers)
+}
+
+fn extract_mcp_from_gemini_config(profile_path: &Path) -> Result<Vec<McpServerInfo>> {
+    let config_path = profile_path.join("settings.json");
+    if !config_path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content = std::fs::read_to_string(&config_path)
+        .map_err(|e| Error::Config(format!("Failed to read settings.json: {}", e)))?;
+    let content = strip_jsonc_comments(&content);
+
+    let config: serde_json::Value = serde_json::from_str(&content)
+        .map_err(|e| Error::Config(format!("Failed to parse settings.json: {}", e)))?;
+
+    let mcp_obj = match config.get("mcpServers").and_then(|v| v.as_object()) {
+        Some(obj) => obj,
+        None => return Ok(Vec::new()),
+    };
+
+    let servers = mcp_obj
+        .iter()
+        .map(|(name, value)| {
+            let disabled = value
+                .get("disabled")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+            let command = value
+                .get("command")
+                .and_then(|v| v.as_str())
+                .map(String::from);
+            let args = value.get("args").and_then(|v| v.as_array()).map(|arr| {
+                arr.iter()
+                    .filter_map(|a| a.as_str().map(String::from))
+                    .collect()
+            });
+            // `httpUrl` is streamable HTTP, `url` is SSE.
+            let (server_type, url) = match (value.get("httpUrl"), value.get("url")) {
+                (Some(url), _) => ("http", url.as_str().map(String::from)),
+                (None, Some(url)) => ("sse", url.as_str().map(String::from)),
+                (None, None) => ("stdio", None),
+            };
+            McpServerInfo {
+                name: name.clone(),
+                enabled: !disabled,
+                server_type: Some(server_type.to_string()),
+                command,
+                args,
+                url,
+            }
+        })
+        .collect();
+
+    Ok(servers)
+}
+
 pub fn extract_theme(harness: &dyn HarnessConfig, profile_path: &Path) -> Option<String> {
     match harness.id() {
         "opencode" => {
@@ -291,10 +404,19 @@ pub fn extract_model(harness: &dyn HarnessConfig, profile_path: &Path) -> Option
         "claude-code" => extract_model_claude_code(profile_path),
         "goose" => extract_model_goose(profile_path),
         "amp-code" => extract_model_ampcode(profile_path),
-        _ => None,
+        _ => extract_model_from_setting(harness, profile_path),
     }
 }
 
+fn extract_model_from_setting(harness: &dyn HarnessConfig, profile_path: &Path) -> Option<String> {
+    let setting = harness.model_setting()?;
+    let content = std::fs::read_to_string(profile_path.join(&setting.file)).ok()?;
+    let parsed = parse_document(&content, &setting.file).ok()?;
+    lookup(&parsed, &setting.key)
+        .and_then(|v| v.as_str())
+        .map(String::from)
+}
+
 fn extract_model_opencode(profile_path: &Path) -> Option<String> {
     let config_path = profile_path.join("opencode.jsonc");
     let content = std::fs::read_to_string(&config_path).ok()?;
@@ -352,14 +474,18 @@ fn extract_model_ampcode(profile_path: &Path) -> Option<String> {
         .map(String::from)
 }
 
-pub fn extract_skills(harness: &Harness, profile_path: &Path) -> (ResourceSummary, Option<String>) {
+pub fn extract_skills(
+    harness: &dyn HarnessConfig,
+    profile_path: &Path,
+) -> (ResourceSummary, Option<String>) {
     if harness.id() == "amp-code" {
         return extract_ampcode_skills(profile_path);
     }
 
-    match harness.skills(&Scope::Global) {
+    let subdir = harness.profile_subdir("skills");
+    match harness.skills_dir() {
         Ok(Some(dir)) => {
-            let summary = extract_resource_summary(profile_path, "skills", &dir.structure);
+            let summary = extract_resource_summary(profile_path, &subdir, &dir.structure);
             if !summary.items.is_empty() {
                 return (summary, None);
             }
@@ -417,7 +543,7 @@ fn extract_ampcode_skills(profile_path: &Path) -> (ResourceSummary, Option<Strin
 }
 
 pub fn extract_commands(
-    harness: &Harness,
+    harness: &dyn HarnessConfig,
     profile_path: &Path,
 ) -> (ResourceSummary, Option<String>) {
     if harness.id() == "goose" {
@@ -428,9 +554,13 @@ pub fn extract_commands(
         return extract_ampcode_commands(profile_path);
     }
 
-    let dir_result = match harness.commands(&Scope::Global) {
+    let dir_result = match harness.commands_dir() {
         Ok(Some(dir)) => (
-            extract_resource_summary(profile_path, "commands", &dir.structure),
+            extract_resource_summary(
+                profile_path,
+                &harness.profile_subdir("commands"),
+                &dir.structure,
+            ),
             None,
         ),
         Ok(None) => (ResourceSummary::default(), None),
@@ -578,7 +708,7 @@ fn extract_ampcode_commands(profile_path: &Path) -> (ResourceSummary, Option<Str
 }
 
 pub fn extract_plugins(
-    harness: &Harness,
+    harness: &dyn HarnessConfig,
     profile_path: &Path,
 ) -> (Option<ResourceSummary>, Option<String>) {
     if harness.id() == "opencode" {
@@ -589,7 +719,7 @@ pub fn extract_plugins(
         return extract_claude_code_plugins(profile_path);
     }
 
-    match harness.plugins(&Scope::Global) {
+    match harness.plugins_dir() {
         Ok(Some(dir)) => (
             Some(extract_resource_summary(
                 profile_path,
@@ -724,13 +854,43 @@ fn parse_marketplace_json(path: &Path) -> Option<(Option<ResourceSummary>, Optio
     }
 }
 
+/// Permission rules and hooks from Claude Code's `settings.json`; `None` for
+/// other harnesses.
+pub fn extract_permissions_and_hooks(
+    harness: &dyn HarnessConfig,
+    profile_path: &Path,
+) -> (Option<PermissionRules>, Option<Vec<HookInfo>>, Option<String>) {
+    if harness.id() != "claude-code" {
+        return (None, None, None);
+    }
+    let settings = match std::fs::read_to_string(profile_path.join("settings.json")) {
+        Ok(content) => match serde_json::from_str(&content) {
+            Ok(settings) => settings,
+            Err(e) => {
+                return (
+                    Some(PermissionRules::default()),
+                    Some(Vec::new()),
+                    Some(format!("settings.json: {}", e)),
+                );
+            }
+        },
+        Err(_) => serde_json::Value::Null,
+    };
+    (
+        Some(permissions::parse_permissions(&settings)),
+        Some(permissions::parse_hooks(&settings)),
+        None,
+    )
+}
+
 pub fn extract_agents(
-    harness: &Harness,
+    harness: &dyn HarnessConfig,
     profile_path: &Path,
 ) -> (Option<ResourceSummary>, Option<String>) {
-    let dir_result = match harness.agents(&Scope::Global) {
+    let subdir = harness.profile_subdir("agents");
+    let dir_result = match harness.agents_dir() {
         Ok(Some(dir)) => {
-            let summary = extract_resource_summary(profile_path, "agents", &dir.structure);
+            let summary = extract_resource_summary(profile_path, &subdir, &dir.structure);
             if !summary.items.is_empty() {
                 (Some(summary), None)
             } else {
@@ -832,10 +992,14 @@ fn extract_agents_fallback(profile_path: &Path) -> (Option<ResourceSummary>, Opt
 }
 
 pub fn extract_rules_file(
-    harness: &Harness,
+    harness: &dyn HarnessConfig,
     profile_path: &Path,
 ) -> (Option<PathBuf>, Option<String>) {
-    match harness.rules(&Scope::Global) {
+    if let Some(file) = harness.rules_file() {
+        let path = profile_path.join(file);
+        return (path.is_file().then_some(path), None);
+    }
+    match harness.rules_dir() {
         Ok(Some(dir)) => {
             let rules_path = match &dir.structure {
                 DirectoryStructure::Flat { file_pattern } => {
@@ -7,7 +7,7 @@ use crate::harness::HarnessConfig;
 use crate::install::installer::{sanitize_name_for_opencode, transform_skill_for_opencode};
 
 /// Directories to skip when copying profiles
-const EXCLUDED_DIRS: &[&str] = &[
+pub(crate) const EXCLUDED_DIRS: &[&str] = &[
     ".git",
     ".DS_Store",
     "Thumbs.db",
@@ -58,6 +58,14 @@ pub fn copy_config_files(
                 std::fs::copy(&mcp_path, dest)?;
             }
         }
+
+        for path in harness.extra_files() {
+            if path.is_file()
+                && let Some(filename) = path.file_name()
+            {
+                std::fs::copy(&path, profile_path.join(filename))?;
+            }
+        }
     } else {
         if !config_dir.exists() {
             std::fs::create_dir_all(&config_dir)?;
@@ -66,6 +74,7 @@ pub fn copy_config_files(
         let mcp_filename = harness
             .mcp_config_path()
             .and_then(|p| p.file_name().map(|f| f.to_os_string()));
+        let extra_files = harness.extra_files();
 
         for entry in std::fs::read_dir(profile_path)? {
             let entry = entry?;
@@ -80,6 +89,14 @@ pub fn copy_config_files(
                     continue;
                 }
 
+                if let Some(extra) = extra_files
+                    .iter()
+                    .find(|p| p.file_name() == Some(filename.as_os_str()))
+                {
+                    std::fs::copy(entry.path(), extra)?;
+                    continue;
+                }
+
                 let dest = config_dir.join(&filename);
                 std::fs::copy(entry.path(), dest)?;
             }
@@ -244,27 +261,26 @@ fn copy_skills_for_opencode(src: &Path, dst: &Path) -> Result<()> {
 /// Uses canonical names inside profiles for cross-harness portability.
 pub fn copy_resource_directories(
     harness: &Harness,
+    scope: &Scope,
     to_profile: bool,
     profile_path: &Path,
 ) -> Result<()> {
-    let scope = Scope::Global;
-
     let resources: Vec<(&str, Option<std::path::PathBuf>)> = vec![
         (
             CANONICAL_COMMANDS_DIR,
-            harness.commands(&scope).ok().flatten().map(|r| r.path),
+            harness.commands(scope).ok().flatten().map(|r| r.path),
         ),
         (
             CANONICAL_AGENTS_DIR,
-            harness.agents(&scope).ok().flatten().map(|r| r.path),
+            harness.agents(scope).ok().flatten().map(|r| r.path),
         ),
         (
             CANONICAL_SKILLS_DIR,
-            harness.skills(&scope).ok().flatten().map(|r| r.path),
+            harness.skills(scope).ok().flatten().map(|r| r.path),
         ),
         (
             CANONICAL_PLUGINS_DIR,
-            harness.plugins(&scope).ok().flatten().map(|r| r.path),
+            harness.plugins(scope).ok().flatten().map(|r| r.path),
         ),
     ];
 
@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use chrono::Local;
 use harness_locate::Harness;
@@ -6,7 +6,9 @@ use harness_locate::Harness;
 use super::ProfileManager;
 use super::files;
 use crate::config::BridleConfig;
+use crate::config::diff::{FileChange, FileStatus, changed_files};
 use crate::config::profile_name::ProfileName;
+use crate::config::rules;
 use crate::error::{Error, Result};
 use crate::harness::HarnessConfig;
 
@@ -26,8 +28,9 @@ impl ProfileManager {
             .mcp_config_path()
             .map(|p| p.exists())
             .unwrap_or(false);
+        let has_extra = harness.extra_files().iter().any(|p| p.exists());
 
-        if !has_config_dir && !has_mcp {
+        if !has_config_dir && !has_mcp && !has_extra {
             return Err(Error::NoConfigFound(format!(
                 "No config found for {}",
                 harness.id()
@@ -83,10 +86,70 @@ impl ProfileManager {
             let dest = profile_path.join(filename);
             std::fs::copy(&mcp_path, dest)?;
         }
+        for path in harness.extra_files() {
+            if path.is_file()
+                && let Some(filename) = path.file_name()
+            {
+                std::fs::copy(&path, profile_path.join(filename))?;
+            }
+        }
         let _ = harness_for_resources;
         Ok(())
     }
 
+    /// Files where the live config differs from the stored copy of `name`.
+    ///
+    /// An empty result means the live config has no unsaved changes.
+    pub fn live_drift(
+        &self,
+        harness: &dyn HarnessConfig,
+        name: &ProfileName,
+    ) -> Result<Vec<FileChange>> {
+        let profile_path = self.profile_path(harness, name);
+        if !profile_path.exists() {
+            return Err(Error::ProfileNotFound(name.as_str().to_string()));
+        }
+
+        let config_dir = harness.config_dir()?;
+        let mut changes = changed_files(&profile_path, &config_dir)?;
+        let runtime = harness.runtime_entries();
+        changes.retain(|c| {
+            !c.path
+                .components()
+                .next()
+                .is_some_and(|first| runtime.iter().any(|e| first.as_os_str() == e.as_str()))
+        });
+
+        let outside = harness
+            .mcp_config_path()
+            .filter(|p| !p.starts_with(&config_dir))
+            .into_iter()
+            .chain(harness.extra_files());
+        for live in outside {
+            let Some(filename) = live.file_name() else {
+                continue;
+            };
+            let stored = profile_path.join(filename);
+            let status = match (stored.is_file(), live.is_file()) {
+                (true, false) => Some(FileStatus::Removed),
+                (false, true) => Some(FileStatus::Added),
+                (true, true) if std::fs::read(&stored)? != std::fs::read(&live)? => {
+                    Some(FileStatus::Modified)
+                }
+                _ => None,
+            };
+            changes.retain(|c| c.path.as_os_str() != filename);
+            if let Some(status) = status {
+                changes.push(FileChange {
+                    path: PathBuf::from(filename),
+                    status,
+                });
+            }
+        }
+
+        Ok(changes)
+    }
+
     pub fn switch_profile(
         &self,
         harness: &dyn HarnessConfig,
@@ -107,15 +170,113 @@ impl ProfileManager {
             return Err(Error::ProfileNotFound(name.as_str().to_string()));
         }
 
+        // The outgoing profile keeps its live edits, including when `name` is
+        // already active; `reset_live` is the way to discard them.
         let harness_id = harness.id();
         if let Ok(config) = BridleConfig::load()
-            && let Some(active_name) = config.active_profile_for(harness_id)
+            && let Some(active_name) = config.active_profile_in(&harness.scope(), harness_id)
             && let Ok(active_profile) = ProfileName::new(active_name)
-            && active_profile.as_str() != name.as_str()
         {
             let _ = self.save_to_profile(harness, harness_for_resources, &active_profile);
         }
 
+        let _ = harness_for_resources;
+        self.materialize(harness, name)
+    }
+
+    /// Commits the live config into the stored copy of `name`.
+    pub fn save_live(&self, harness: &dyn HarnessConfig, name: &ProfileName) -> Result<PathBuf> {
+        let profile_path = self.profile_path(harness, name);
+        if !profile_path.exists() {
+            return Err(Error::ProfileNotFound(name.as_str().to_string()));
+        }
+        self.save_to_profile(harness, None, name)?;
+        Ok(profile_path)
+    }
+
+    /// Discards live changes by re-applying the stored copy of `name`.
+    ///
+    /// Unlike a switch, the live config is not saved back first.
+    pub fn reset_live(&self, harness: &dyn HarnessConfig, name: &ProfileName) -> Result<PathBuf> {
+        if !self.profile_exists(harness, name) {
+            return Err(Error::ProfileNotFound(name.as_str().to_string()));
+        }
+        self.materialize(harness, name)
+    }
+
+    /// Puts back the live config saved by [`Self::backup_current`] and marks
+    /// `active` as the active profile again (or none).
+    pub fn restore_backup(
+        &self,
+        harness: &dyn HarnessConfig,
+        backup: &Path,
+        active: Option<&ProfileName>,
+    ) -> Result<PathBuf> {
+        if !backup.is_dir() {
+            return Err(Error::NoConfigFound(format!(
+                "Backup not found: {}",
+                backup.display()
+            )));
+        }
+        let target_dir = Self::apply_dir(harness, backup)?;
+
+        // A backup without the MCP file means there was none before.
+        let outside = harness
+            .mcp_config_path()
+            .filter(|p| !p.starts_with(&target_dir))
+            .into_iter()
+            .chain(harness.extra_files());
+        for path in outside {
+            if let Some(filename) = path.file_name()
+                && !backup.join(filename).exists()
+                && path.is_file()
+            {
+                std::fs::remove_file(&path)?;
+            }
+        }
+
+        let scope = harness.scope();
+        let mut config = BridleConfig::load().unwrap_or_default();
+        match active {
+            Some(name) => config.set_active_profile_in(&scope, harness.id(), name.as_str()),
+            None => config.clear_active_profile_in(&scope, harness.id()),
+        }
+        config.save()?;
+
+        Self::delete_marker_files(&target_dir)?;
+        if let (true, Some(name)) = (config.profile_marker_enabled(), active) {
+            Self::create_marker_file(&target_dir, name.as_str())?;
+        }
+
+        Ok(target_dir)
+    }
+
+    /// Replaces the live config with the contents of profile `name` and marks it active,
+    /// after composing its snippets into its rules file.
+    fn materialize(&self, harness: &dyn HarnessConfig, name: &ProfileName) -> Result<PathBuf> {
+        let profile_path = self.profile_path(harness, name);
+        let mut config = BridleConfig::load().unwrap_or_default();
+        rules::apply_snippets(
+            harness,
+            &profile_path,
+            config.snippets_for(harness.id(), name.as_str()),
+        )?;
+        let target_dir = Self::apply_dir(harness, &profile_path)?;
+
+        config.set_active_profile_in(&harness.scope(), harness.id(), name.as_str());
+        config.save()?;
+
+        Self::delete_marker_files(&target_dir)?;
+        if config.profile_marker_enabled() {
+            Self::create_marker_file(&target_dir, name.as_str())?;
+        }
+
+        Ok(target_dir)
+    }
+
+    /// Replaces the live config dir with the contents of `source`, including
+    /// the MCP file when it lives elsewhere.
+    fn apply_dir(harness: &dyn HarnessConfig, source: &Path) -> Result<PathBuf> {
         let target_dir = harness.config_dir()?;
 
         if target_dir.exists() {
@@ -132,26 +293,29 @@ impl ProfileManager {
             std::fs::create_dir_all(&target_dir)?;
         }
 
-        files::copy_all_contents(&profile_path, &target_dir)?;
+        files::copy_all_contents(source, &target_dir)?;
 
         if let Some(mcp_path) = harness.mcp_config_path()
             && let Some(filename) = mcp_path.file_name()
         {
-            let mcp_in_profile = profile_path.join(filename);
-            if mcp_in_profile.exists() {
-                std::fs::copy(&mcp_in_profile, &mcp_path)?;
+            let mcp_in_source = source.join(filename);
+            if mcp_in_source.exists() {
+                std::fs::copy(&mcp_in_source, &mcp_path)?;
             }
         }
 
-        let _ = harness_for_resources;
-
-        let mut config = BridleConfig::load().unwrap_or_default();
-        config.set_active_profile(harness.id(), name.as_str());
-        config.save()?;
-
-        Self::delete_marker_files(&target_dir)?;
-        if config.profile_marker_enabled() {
-            Self::create_marker_file(&target_dir, name.as_str())?;
+        for path in harness.extra_files() {
+            let Some(filename) = path.file_name() else {
+                continue;
+            };
+            // Stored next to the config files, but lives outside the config dir.
+            let stray = target_dir.join(filename);
+            if stray != path && stray.is_file() {
+                std::fs::remove_file(&stray)?;
+            }
+            if source.join(filename).is_file() {
+                std::fs::copy(source.join(filename), &path)?;
+            }
         }
 
         Ok(target_dir)
@@ -6,6 +6,10 @@
 mod extraction;
 mod files;
 mod lifecycle;
+mod trash;
+
+pub(super) use files::EXCLUDED_DIRS;
+pub use trash::TrashEntry;
 
 use std::path::PathBuf;
 
@@ -35,7 +39,7 @@ use crate::harness::HarnessConfig;
 /// └── goose/
 ///     └── default/
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ProfileManager {
     profiles_dir: PathBuf,
 }
@@ -156,11 +160,11 @@ impl ProfileManager {
         let profile_path = self.create_profile(harness, name)?;
         files::copy_config_files(harness, true, &profile_path)?;
         if let Some(h) = harness_for_resources {
-            files::copy_resource_directories(h, true, &profile_path)?;
+            files::copy_resource_directories(h, &harness.scope(), true, &profile_path)?;
         }
 
         if let Ok(mut config) = BridleConfig::load() {
-            config.set_active_profile(harness.id(), name.as_str());
+            config.set_active_profile_in(&harness.scope(), harness.id(), name.as_str());
             let _ = config.save();
         }
 
@@ -188,37 +192,60 @@ impl ProfileManager {
         Ok(true)
     }
 
-    /// Deletes a profile and all its contents.
+    /// Moves a profile to the trash and returns where it went.
     ///
     /// # Errors
     /// Returns [`Error::ProfileNotFound`] if profile doesn't exist.
-    pub fn delete_profile(&self, harness: &dyn HarnessConfig, name: &ProfileName) -> Result<()> {
+    pub fn delete_profile(
+        &self,
+        harness: &dyn HarnessConfig,
+        name: &ProfileName,
+    ) -> Result<PathBuf> {
         let path = self.profile_path(harness, name);
 
         if !path.exists() {
             return Err(Error::ProfileNotFound(name.as_str().to_string()));
         }
 
-        std::fs::remove_dir_all(&path)?;
-        Ok(())
+        self.move_to_trash(harness, name)
     }
 
     /// Extracts and returns detailed information about a profile.
     ///
     /// # Errors
     /// Returns [`Error::ProfileNotFound`] if profile doesn't exist.
-    pub fn show_profile(&self, harness: &Harness, name: &ProfileName) -> Result<ProfileInfo> {
+    pub fn show_profile(&self, harness: &dyn HarnessConfig, name: &ProfileName) -> Result<ProfileInfo> {
         let path = self.profile_path(harness, name);
 
         if !path.exists() {
             return Err(Error::ProfileNotFound(name.as_str().to_string()));
         }
 
-        let harness_id = harness.id().to_string();
         let is_active = BridleConfig::load()
-            .map(|c| c.active_profile_for(&harness_id) == Some(name.as_str()))
+            .map(|c| c.active_profile_in(&harness.scope(), harness.id()) == Some(name.as_str()))
             .unwrap_or(false);
 
+        Ok(Self::profile_info(harness, name.as_str(), path, is_active))
+    }
+
+    /// Summarizes the harness's live config directory as if it were a profile named `live`.
+    pub fn show_live(&self, harness: &dyn HarnessConfig) -> Result<ProfileInfo> {
+        let path = harness
+            .config_dir()
+            .map_err(|e| Error::NoConfigFound(e.to_string()))?;
+        if !path.exists() {
+            return Err(Error::NoConfigFound(path.display().to_string()));
+        }
+        Ok(Self::profile_info(harness, "live", path, false))
+    }
+
+    fn profile_info(
+        harness: &dyn HarnessConfig,
+        name: &str,
+        path: PathBuf,
+        is_active: bool,
+    ) -> ProfileInfo {
+        let harness_id = harness.id().to_string();
         let theme = extraction::extract_theme(harness, &path);
         let model = extraction::extract_model(harness, &path);
 
@@ -257,8 +284,13 @@ impl ProfileManager {
             extraction_errors.push(e);
         }
 
-        Ok(ProfileInfo {
-            name: name.as_str().to_string(),
+        let (permissions, hooks, err) = extraction::extract_permissions_and_hooks(harness, &path);
+        if let Some(e) = err {
+            extraction_errors.push(e);
+        }
+
+        ProfileInfo {
+            name: name.to_string(),
             harness_id,
             is_active,
             path,
@@ -270,8 +302,10 @@ impl ProfileManager {
             rules_file,
             theme,
             model,
+            permissions,
+            hooks,
             extraction_errors,
-        })
+        }
     }
 }
 
@@ -281,6 +315,7 @@ mod tests {
         DirectoryStructure, extract_resource_summary, list_files_matching, list_subdirs_with_file,
     };
     use super::*;
+    use crate::config::diff::FileStatus;
     use std::fs;
     use tempfile::TempDir;
 
@@ -288,6 +323,8 @@ mod tests {
         id: String,
         config_dir: PathBuf,
         mcp_path: Option<PathBuf>,
+        extra_files: Vec<PathBuf>,
+        runtime: Vec<String>,
     }
 
     impl MockHarness {
@@ -296,6 +333,8 @@ mod tests {
                 id: id.to_string(),
                 config_dir,
                 mcp_path: None,
+                extra_files: Vec::new(),
+                runtime: Vec::new(),
             }
         }
 
@@ -303,6 +342,16 @@ mod tests {
             self.mcp_path = Some(mcp_path);
             self
         }
+
+        fn with_extra_file(mut self, path: PathBuf) -> Self {
+            self.extra_files.push(path);
+            self
+        }
+
+        fn with_runtime_entry(mut self, entry: &str) -> Self {
+            self.runtime.push(entry.to_string());
+            self
+        }
     }
 
     impl HarnessConfig for MockHarness {
@@ -336,6 +385,14 @@ mod tests {
         ) -> Result<Vec<(String, bool)>> {
             Ok(vec![])
         }
+
+        fn extra_files(&self) -> Vec<PathBuf> {
+            self.extra_files.clone()
+        }
+
+        fn runtime_entries(&self) -> Vec<String> {
+            self.runtime.clone()
+        }
     }
 
     #[test]
@@ -410,6 +467,92 @@ mod tests {
         );
     }
 
+    #[test]
+    fn live_drift_reports_unsaved_changes() {
+        let temp = TempDir::new().unwrap();
+        let profiles_dir = temp.path().join("profiles");
+        let live_config = temp.path().join("live_config");
+        let mcp_file = temp.path().join(".mcp.json");
+
+        fs::create_dir_all(&live_config).unwrap();
+        fs::write(live_config.join("config.txt"), "original").unwrap();
+        fs::write(&mcp_file, r#"{"servers": {}}"#).unwrap();
+
+        let harness = MockHarness::new("test-drift", live_config.clone()).with_mcp(mcp_file.clone());
+        let manager = ProfileManager::new(profiles_dir);
+        let name = ProfileName::new("drift").unwrap();
+        manager.create_from_current(&harness, &name).unwrap();
+
+        assert!(manager.live_drift(&harness, &name).unwrap().is_empty());
+
+        fs::write(live_config.join("config.txt"), "edited").unwrap();
+        fs::write(live_config.join("new.txt"), "new").unwrap();
+        fs::write(&mcp_file, r#"{"servers": {"a": {}}}"#).unwrap();
+
+        let drift: Vec<_> = manager
+            .live_drift(&harness, &name)
+            .unwrap()
+            .into_iter()
+            .map(|c| (c.path.display().to_string(), c.status))
+            .collect();
+        assert_eq!(
+            drift,
+            vec![
+                ("config.txt".to_string(), FileStatus::Modified),
+                ("new.txt".to_string(), FileStatus::Added),
+                (".mcp.json".to_string(), FileStatus::Modified),
+            ]
+        );
+    }
+
+    #[test]
+    fn live_drift_ignores_runtime_entries() {
+        let temp = TempDir::new().unwrap();
+        let profiles_dir = temp.path().join("profiles");
+        let live_config = temp.path().join("live_config");
+        fs::create_dir_all(live_config.join("projects")).unwrap();
+        fs::write(live_config.join("settings.json"), "{}").unwrap();
+
+        let harness =
+            MockHarness::new("test-runtime", live_config.clone()).with_runtime_entry("projects");
+        let manager = ProfileManager::new(profiles_dir);
+        let name = ProfileName::new("runtime").unwrap();
+        manager.create_from_current(&harness, &name).unwrap();
+
+        fs::write(live_config.join("projects").join("session.jsonl"), "{}").unwrap();
+        assert!(manager.live_drift(&harness, &name).unwrap().is_empty());
+
+        fs::write(live_config.join("settings.json"), r#"{"model": "opus"}"#).unwrap();
+        let drift = manager.live_drift(&harness, &name).unwrap();
+        assert_eq!(drift.len(), 1);
+        assert_eq!(drift[0].path, PathBuf::from("settings.json"));
+    }
+
+    #[test]
+    fn save_and_reset_live_changes() {
+        let temp = TempDir::new().unwrap();
+        let profiles_dir = temp.path().join("profiles");
+        let live_config = temp.path().join("live_config");
+        fs::create_dir_all(&live_config).unwrap();
+        fs::write(live_config.join("config.txt"), "original").unwrap();
+
+        let harness = MockHarness::new("test-save-reset", live_config.clone());
+        let manager = ProfileManager::new(profiles_dir);
+        let name = ProfileName::new("work").unwrap();
+        let profile_path = manager.create_from_current(&harness, &name).unwrap();
+
+        fs::write(live_config.join("config.txt"), "discard me").unwrap();
+        fs::write(live_config.join("scratch.txt"), "scratch").unwrap();
+        manager.reset_live(&harness, &name).unwrap();
+        assert_eq!(fs::read_to_string(live_config.join("config.txt")).unwrap(), "original");
+        assert!(!live_config.join("scratch.txt").exists());
+
+        fs::write(live_config.join("config.txt"), "keep me").unwrap();
+        manager.save_live(&harness, &name).unwrap();
+        assert_eq!(fs::read_to_string(profile_path.join("config.txt")).unwrap(), "keep me");
+        assert!(manager.live_drift(&harness, &name).unwrap().is_empty());
+    }
+
     #[test]
     fn switch_profile_restores_mcp_config() {
         let temp = TempDir::new().unwrap();
@@ -459,6 +602,8 @@ mod tests {
         fs::write(live_config.join("known.txt"), "profile content").unwrap();
         let profile_a = ProfileName::new("profile-a").unwrap();
         manager.create_from_current(&harness, &profile_a).unwrap();
+        let profile_b = ProfileName::new("profile-b").unwrap();
+        manager.create_from_current(&harness, &profile_b).unwrap();
 
         fs::write(live_config.join("extra.txt"), "extra data").unwrap();
         fs::create_dir_all(live_config.join("extra-dir")).unwrap();
@@ -480,6 +625,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn reswitching_to_active_profile_keeps_live_edits() {
+        let temp = TempDir::new().unwrap();
+        let profiles_dir = temp.path().join("profiles");
+        let live_config = temp.path().join("live_config");
+        fs::create_dir_all(&live_config).unwrap();
+        fs::write(live_config.join("config.txt"), "original").unwrap();
+
+        let harness = MockHarness::new("test-reswitch", live_config.clone());
+        let manager = ProfileManager::new(profiles_dir.clone());
+        let name = ProfileName::new("work").unwrap();
+        manager.create_from_current(&harness, &name).unwrap();
+
+        fs::write(live_config.join("config.txt"), "edited").unwrap();
+        fs::write(live_config.join("notes.txt"), "notes").unwrap();
+        manager.switch_profile(&harness, &name).unwrap();
+
+        assert_eq!(fs::read_to_string(live_config.join("config.txt")).unwrap(), "edited");
+        assert!(live_config.join("notes.txt").exists());
+        let profile_path = profiles_dir.join("test-reswitch/work");
+        assert_eq!(fs::read_to_string(profile_path.join("config.txt")).unwrap(), "edited");
+    }
+
     #[test]
     fn save_to_profile_captures_everything() {
         let temp = TempDir::new().unwrap();
@@ -671,6 +839,117 @@ mod tests {
         }
     }
 
+    #[test]
+    fn profiles_carry_extra_files() {
+        let temp = TempDir::new().unwrap();
+        let profiles_dir = temp.path().join("profiles");
+        let live_config = temp.path().join(".claude");
+        let instructions = temp.path().join("CLAUDE.md");
+
+        fs::create_dir_all(&live_config).unwrap();
+        fs::write(live_config.join("settings.json"), "{}").unwrap();
+        fs::write(&instructions, "be brief").unwrap();
+
+        let harness = MockHarness::new("test-extra-files", live_config)
+            .with_extra_file(instructions.clone());
+        let manager = ProfileManager::new(profiles_dir);
+        let name = ProfileName::new("project").unwrap();
+        let profile_path = manager.create_from_current(&harness, &name).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(profile_path.join("CLAUDE.md")).unwrap(),
+            "be brief"
+        );
+
+        fs::write(&instructions, "be thorough").unwrap();
+        let drift: Vec<_> = manager
+            .live_drift(&harness, &name)
+            .unwrap()
+            .into_iter()
+            .map(|c| (c.path.display().to_string(), c.status))
+            .collect();
+        assert_eq!(
+            drift,
+            vec![("CLAUDE.md".to_string(), FileStatus::Modified)]
+        );
+
+        manager.reset_live(&harness, &name).unwrap();
+        assert_eq!(fs::read_to_string(&instructions).unwrap(), "be brief");
+        assert!(!temp.path().join(".claude/CLAUDE.md").exists());
+    }
+
+    #[test]
+    fn delete_moves_profile_to_trash_and_restores() {
+        let temp = TempDir::new().unwrap();
+        let profiles_dir = temp.path().join("profiles");
+        let live_config = temp.path().join("live_config");
+        fs::create_dir_all(&live_config).unwrap();
+        fs::write(live_config.join("config.txt"), "kept").unwrap();
+
+        let harness = MockHarness::new("test-trash", live_config);
+        let manager = ProfileManager::new(profiles_dir);
+        let name = ProfileName::new("doomed").unwrap();
+        manager.create_from_current(&harness, &name).unwrap();
+
+        let trashed = manager.delete_profile(&harness, &name).unwrap();
+        assert!(!manager.profile_exists(&harness, &name));
+        assert!(trashed.starts_with(manager.trash_dir()));
+
+        let entries = manager.list_trash(Some(&harness)).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].name, "doomed");
+        assert_eq!(entries[0].harness_id, "test-trash");
+
+        let restored = manager.restore_from_trash(&harness, &name).unwrap();
+        assert_eq!(
+            fs::read_to_string(restored.join("config.txt")).unwrap(),
+            "kept"
+        );
+        assert!(manager.list_trash(Some(&harness)).unwrap().is_empty());
+    }
+
+    #[test]
+    fn restore_from_trash_refuses_to_overwrite() {
+        let temp = TempDir::new().unwrap();
+        let profiles_dir = temp.path().join("profiles");
+        let live_config = temp.path().join("live_config");
+        fs::create_dir_all(&live_config).unwrap();
+
+        let harness = MockHarness::new("test-trash-conflict", live_config);
+        let manager = ProfileManager::new(profiles_dir);
+        let name = ProfileName::new("twice").unwrap();
+        manager.create_profile(&harness, &name).unwrap();
+        manager.delete_profile(&harness, &name).unwrap();
+        manager.create_profile(&harness, &name).unwrap();
+
+        let result = manager.restore_from_trash(&harness, &name);
+        assert!(matches!(result, Err(Error::ProfileExists(_))));
+        assert_eq!(manager.list_trash(Some(&harness)).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn empty_trash_removes_entries() {
+        let temp = TempDir::new().unwrap();
+        let profiles_dir = temp.path().join("profiles");
+        let live_config = temp.path().join("live_config");
+        fs::create_dir_all(&live_config).unwrap();
+
+        let harness = MockHarness::new("test-trash-empty", live_config);
+        let manager = ProfileManager::new(profiles_dir);
+        for n in ["one", "two"] {
+            let name = ProfileName::new(n).unwrap();
+            manager.create_profile(&harness, &name).unwrap();
+            manager.delete_profile(&harness, &name).unwrap();
+        }
+
+        assert_eq!(manager.empty_trash(Some(&harness)).unwrap(), 2);
+        assert!(manager.list_trash(None).unwrap().is_empty());
+        assert!(matches!(
+            manager.restore_from_trash(&harness, &ProfileName::new("one").unwrap()),
+            Err(Error::ProfileNotFound(_))
+        ));
+    }
+
     #[test]
     fn list_files_matching_finds_files_with_extension() {
         let temp = TempDir::new().unwrap();
@@ -0,0 +1,168 @@
+//! Deleted profiles, kept until the trash is emptied.
+//!
+//! ```text
+//! ~/.config/bridle/trash/
+//! └── opencode/
+//!     └── 20260101_120000_000/
+//!         └── work/
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use chrono::{Local, NaiveDateTime};
+use serde::Serialize;
+
+use super::ProfileManager;
+use super::files;
+use crate::config::profile_name::ProfileName;
+use crate::error::{Error, Result};
+use crate::harness::HarnessConfig;
+
+const TIMESTAMP_FORMAT: &str = "%Y%m%d_%H%M%S_%3f";
+
+/// A deleted profile waiting in the trash.
+#[derive(Debug, Clone, Serialize)]
+pub struct TrashEntry {
+    pub harness_id: String,
+    pub name: String,
+    /// When the profile was deleted, as `YYYY-MM-DD HH:MM:SS`.
+    pub deleted_at: String,
+    pub path: PathBuf,
+}
+
+impl ProfileManager {
+    pub fn trash_dir(&self) -> PathBuf {
+        self.profiles_dir
+            .parent()
+            .map(|p| p.to_path_buf())
+            .unwrap_or_else(|| self.profiles_dir.clone())
+            .join("trash")
+    }
+
+    /// Moves profile `name` into the trash and returns its new location.
+    pub(super) fn move_to_trash(
+        &self,
+        harness: &dyn HarnessConfig,
+        name: &ProfileName,
+    ) -> Result<PathBuf> {
+        let source = self.profile_path(harness, name);
+        let timestamp = Local::now().format(TIMESTAMP_FORMAT).to_string();
+        let target = self
+            .trash_dir()
+            .join(harness.id())
+            .join(timestamp)
+            .join(name.as_str());
+        move_dir(&source, &target)?;
+        Ok(target)
+    }
+
+    /// Trashed profiles, newest first; limited to `harness` when given.
+    pub fn list_trash(&self, harness: Option<&dyn HarnessConfig>) -> Result<Vec<TrashEntry>> {
+        let trash_dir = self.trash_dir();
+        if !trash_dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let harness_dirs: Vec<PathBuf> = match harness {
+            Some(h) => vec![trash_dir.join(h.id())],
+            None => std::fs::read_dir(&trash_dir)?
+                .filter_map(|e| e.ok().map(|e| e.path()))
+                .collect(),
+        };
+
+        let mut entries = Vec::new();
+        for harness_dir in harness_dirs.iter().filter(|d| d.is_dir()) {
+            let harness_id = dir_name(harness_dir);
+            for stamp in std::fs::read_dir(harness_dir)?.flatten() {
+                let deleted_at =
+                    NaiveDateTime::parse_from_str(&dir_name(&stamp.path()), TIMESTAMP_FORMAT)
+                        .map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string())
+                        .unwrap_or_default();
+                for profile in std::fs::read_dir(stamp.path())?.flatten() {
+                    if profile.path().is_dir() {
+                        entries.push(TrashEntry {
+                            harness_id: harness_id.clone(),
+                            name: dir_name(&profile.path()),
+                            deleted_at: deleted_at.clone(),
+                            path: profile.path(),
+                        });
+                    }
+                }
+            }
+        }
+
+        entries.sort_by(|a, b| b.path.cmp(&a.path));
+        Ok(entries)
+    }
+
+    /// Moves a trashed profile at `trashed` back into the profiles directory.
+    ///
+    /// # Errors
+    /// Returns [`Error::ProfileExists`] if a profile with that name was created since.
+    pub fn restore_trashed(
+        &self,
+        harness: &dyn HarnessConfig,
+        trashed: &Path,
+    ) -> Result<ProfileName> {
+        let name = ProfileName::new(&dir_name(trashed))
+            .map_err(|_| Error::InvalidProfileName(trashed.display().to_string()))?;
+        if self.profile_exists(harness, &name) {
+            return Err(Error::ProfileExists(name.as_str().to_string()));
+        }
+
+        move_dir(trashed, &self.profile_path(harness, &name))?;
+        if let Some(stamp) = trashed.parent() {
+            let _ = std::fs::remove_dir(stamp);
+        }
+        Ok(name)
+    }
+
+    /// Restores the most recently deleted profile called `name`.
+    ///
+    /// # Errors
+    /// Returns [`Error::ProfileNotFound`] if the trash holds no such profile.
+    pub fn restore_from_trash(
+        &self,
+        harness: &dyn HarnessConfig,
+        name: &ProfileName,
+    ) -> Result<PathBuf> {
+        let entry = self
+            .list_trash(Some(harness))?
+            .into_iter()
+            .find(|e| e.name == name.as_str())
+            .ok_or_else(|| Error::ProfileNotFound(format!("{} (in trash)", name.as_str())))?;
+        self.restore_trashed(harness, &entry.path)?;
+        Ok(self.profile_path(harness, name))
+    }
+
+    /// Permanently deletes trashed profiles and returns how many were removed.
+    pub fn empty_trash(&self, harness: Option<&dyn HarnessConfig>) -> Result<usize> {
+        let count = self.list_trash(harness)?.len();
+        let dir = match harness {
+            Some(h) => self.trash_dir().join(h.id()),
+            None => self.trash_dir(),
+        };
+        if dir.exists() {
+            std::fs::remove_dir_all(&dir)?;
+        }
+        Ok(count)
+    }
+}
+
+fn dir_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// Renames `source` to `target`, copying instead when they sit on different filesystems.
+fn move_dir(source: &Path, target: &Path) -> Result<()> {
+    if let Some(parent) = target.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    if std::fs::rename(source, target).is_err() {
+        files::copy_dir_recursive(source, target)?;
+        std::fs::remove_dir_all(source)?;
+    }
+    Ok(())
+}
@@ -3,13 +3,27 @@
 #![allow(dead_code)]
 #![allow(unused_imports)]
 
+mod auto;
 mod bridle;
-mod jsonc;
+pub mod diff;
+pub(crate) mod jsonc;
 mod manager;
+pub mod model;
+pub mod permissions;
+pub mod plugins;
 mod profile_name;
+pub mod rules;
 mod types;
+pub(crate) mod yaml;
 
-pub use bridle::{BridleConfig, TuiConfig, ViewPreference};
-pub use manager::ProfileManager;
+pub use auto::AutoRule;
+pub(crate) use auto::expand_home;
+pub use bridle::{
+    BridleConfig, KeyConfig, KeyList, KeyPreset, ThemeConfig, ThemePreset, TuiConfig,
+    ViewPreference,
+};
+pub use manager::{ProfileManager, TrashEntry};
 pub use profile_name::{InvalidProfileName, ProfileName};
-pub use types::{McpServerInfo, ProfileInfo, ResourceSummary};
+pub use types::{
+    HookInfo, McpServerInfo, PermissionEffect, PermissionRules, ProfileInfo, ResourceSummary,
+};
@@ -0,0 +1,274 @@
+//! Reading and writing a harness's model setting, and the model alias table.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use super::BridleConfig;
+use crate::error::{Error, Result};
+use crate::harness::{HarnessConfig, SettingKey, assign, lookup, parse_document, write_document};
+
+/// Built-in aliases: a logical model and its identifier in each harness.
+///
+/// Identifiers for harnesses with a separate provider setting (Goose) are
+/// written as `provider/model`.
+const ALIASES: &[(&str, &[(&str, &str)])] = &[
+    (
+        "sonnet",
+        &[
+            ("claude-code", "sonnet"),
+            ("opencode", "anthropic/claude-sonnet-4-5"),
+            ("goose", "anthropic/claude-sonnet-4-5"),
+        ],
+    ),
+    (
+        "opus",
+        &[
+            ("claude-code", "opus"),
+            ("opencode", "anthropic/claude-opus-4-1"),
+            ("goose", "anthropic/claude-opus-4-1"),
+        ],
+    ),
+    (
+        "haiku",
+        &[
+            ("claude-code", "haiku"),
+            ("opencode", "anthropic/claude-haiku-4-5"),
+            ("goose", "anthropic/claude-haiku-4-5"),
+        ],
+    ),
+    (
+        "gpt-5",
+        &[
+            ("opencode", "openai/gpt-5"),
+            ("goose", "openai/gpt-5"),
+            ("codex", "gpt-5"),
+        ],
+    ),
+    (
+        "gemini-pro",
+        &[
+            ("opencode", "google/gemini-2.5-pro"),
+            ("goose", "google/gemini-2.5-pro"),
+            ("gemini", "gemini-2.5-pro"),
+        ],
+    ),
+    (
+        "gemini-flash",
+        &[
+            ("opencode", "google/gemini-2.5-flash"),
+            ("goose", "google/gemini-2.5-flash"),
+            ("gemini", "gemini-2.5-flash"),
+        ],
+    ),
+];
+
+/// The built-in aliases with the `[models]` entries of `config` merged over them.
+pub fn aliases(config: &BridleConfig) -> BTreeMap<String, BTreeMap<String, String>> {
+    let mut table: BTreeMap<String, BTreeMap<String, String>> = ALIASES
+        .iter()
+        .map(|(alias, models)| {
+            let models = models
+                .iter()
+                .map(|(harness, model)| (harness.to_string(), model.to_string()))
+                .collect();
+            (alias.to_string(), models)
+        })
+        .collect();
+    for (alias, models) in &config.models {
+        table
+            .entry(alias.clone())
+            .or_default()
+            .extend(models.iter().map(|(h, m)| (h.clone(), m.clone())));
+    }
+    table
+}
+
+/// The identifier `model` stands for in `harness_id`.
+///
+/// Names that aren't aliases are taken literally. An alias without an entry
+/// for the harness is an error rather than being written as-is.
+pub fn resolve(config: &BridleConfig, model: &str, harness_id: &str) -> Result<String> {
+    let table = aliases(config);
+    let Some(models) = table.get(model) else {
+        return Ok(model.to_string());
+    };
+    models.get(harness_id).cloned().ok_or_else(|| {
+        Error::InvalidValue(format!(
+            "model alias '{model}' has no model for {harness_id}"
+        ))
+    })
+}
+
+fn read_setting(dir: &Path, setting: &SettingKey) -> Option<String> {
+    let content = std::fs::read_to_string(dir.join(&setting.file)).ok()?;
+    let document = parse_document(&content, &setting.file).ok()?;
+    lookup(&document, &setting.key)
+        .and_then(|v| v.as_str())
+        .map(String::from)
+}
+
+/// The provider stored in `dir`, for harnesses that keep it apart from the model.
+pub fn read_provider(harness: &dyn HarnessConfig, dir: &Path) -> Option<String> {
+    read_setting(dir, &harness.provider_setting()?)
+}
+
+fn write_setting(dir: &Path, setting: &SettingKey, value: &str) -> Result<PathBuf> {
+    let path = dir.join(&setting.file);
+    let mut document = match std::fs::read_to_string(&path) {
+        Ok(content) if !content.trim().is_empty() => parse_document(&content, &setting.file)?,
+        Ok(_) => serde_json::Value::Object(Default::default()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            serde_json::Value::Object(Default::default())
+        }
+        Err(e) => return Err(e.into()),
+    };
+    assign(&mut document, &setting.key, serde_json::Value::from(value))?;
+    write_document(&path, &document)?;
+    Ok(path)
+}
+
+/// Writes `model` into the harness's config files under `dir` (a profile or
+/// the live config directory). For harnesses with a provider setting,
+/// `provider/model` sets both.
+///
+/// Returns the files written.
+pub fn write_model(harness: &dyn HarnessConfig, dir: &Path, model: &str) -> Result<Vec<PathBuf>> {
+    let setting = harness
+        .model_setting()
+        .ok_or_else(|| Error::Config(format!("{} has no model setting", harness.id())))?;
+
+    let mut written = Vec::new();
+    let model = match (harness.provider_setting(), model.split_once('/')) {
+        (Some(provider_setting), Some((provider, model))) => {
+            written.push(write_setting(dir, &provider_setting, provider)?);
+            model
+        }
+        _ => model,
+    };
+    let path = write_setting(dir, &setting, model)?;
+    if !written.contains(&path) {
+        written.push(path);
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use harness_locate::{Harness, HarnessKind};
+    use tempfile::TempDir;
+
+    #[test]
+    fn aliases_resolve_per_harness_with_overrides() {
+        let mut config = BridleConfig::default();
+        assert_eq!(resolve(&config, "sonnet", "claude-code").unwrap(), "sonnet");
+        assert_eq!(
+            resolve(&config, "sonnet", "opencode").unwrap(),
+            "anthropic/claude-sonnet-4-5"
+        );
+        assert_eq!(resolve(&config, "o3", "codex").unwrap(), "o3");
+        assert!(resolve(&config, "sonnet", "codex").is_err());
+
+        config.models.insert(
+            "sonnet".to_string(),
+            BTreeMap::from([("amp-code".to_string(), "claude-sonnet".to_string())]),
+        );
+        assert_eq!(
+            resolve(&config, "sonnet", "amp-code").unwrap(),
+            "claude-sonnet"
+        );
+        assert_eq!(resolve(&config, "sonnet", "claude-code").unwrap(), "sonnet");
+    }
+
+    #[test]
+    fn writes_model_keys_for_builtin_harnesses() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path();
+        std::fs::write(
+            dir.join("settings.json"),
+            r#"{"amp.model.default": "old", "amp.mcpServers": {}}"#,
+        )
+        .unwrap();
+        write_model(&Harness::new(HarnessKind::AmpCode), dir, "fast").unwrap();
+        let amp: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(dir.join("settings.json")).unwrap())
+                .unwrap();
+        assert_eq!(amp["amp.model.default"], "fast");
+        assert!(amp["amp.mcpServers"].is_object());
+
+        std::fs::write(
+            dir.join("config.yaml"),
+            "GOOSE_PROVIDER: openai\nextensions: {}\n",
+        )
+        .unwrap();
+        let goose = Harness::new(HarnessKind::Goose);
+        let written = write_model(&goose, dir, "anthropic/claude-sonnet-4-5").unwrap();
+        assert_eq!(written, [dir.join("config.yaml")]);
+        assert_eq!(read_provider(&goose, dir).as_deref(), Some("anthropic"));
+        assert_eq!(
+            read_setting(dir, &goose.model_setting().unwrap()).as_deref(),
+            Some("claude-sonnet-4-5")
+        );
+
+        write_model(&Harness::new(HarnessKind::OpenCode), dir, "openai/gpt-5").unwrap();
+        assert!(
+            std::fs::read_to_string(dir.join("opencode.jsonc"))
+                .unwrap()
+                .contains(r#""model": "openai/gpt-5""#)
+        );
+    }
+
+    #[test]
+    fn model_write_keeps_other_keys_comments_and_order() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path();
+        let original = r#"{
+  // Shared across machines.
+  "theme": "tokyonight",
+  "model": "anthropic/claude-sonnet-4-5", // day to day
+  "mcp": {
+    "fs": { "type": "local", "command": ["fs"] },
+  },
+  "autoupdate": false,
+}
+"#;
+        std::fs::write(dir.join("opencode.jsonc"), original).unwrap();
+        write_model(&Harness::new(HarnessKind::OpenCode), dir, "openai/gpt-5").unwrap();
+        assert_eq!(
+            std::fs::read_to_string(dir.join("opencode.jsonc")).unwrap(),
+            original.replace("anthropic/claude-sonnet-4-5", "openai/gpt-5")
+        );
+
+        std::fs::write(
+            dir.join("settings.json"),
+            r#"{"theme": "dark", "model": "sonnet", "permissions": {"allow": []}, "env": {}}"#,
+        )
+        .unwrap();
+        write_model(&Harness::new(HarnessKind::ClaudeCode), dir, "opus").unwrap();
+        let content = std::fs::read_to_string(dir.join("settings.json")).unwrap();
+        let settings: serde_json::Value = serde_json::from_str(&content).unwrap();
+        let keys: Vec<&str> = settings
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .collect();
+        assert_eq!(keys, ["theme", "model", "permissions", "env"]);
+        assert_eq!(settings["model"], "opus");
+
+        let original = "# Goose settings\nGOOSE_PROVIDER: openai # work account\nGOOSE_MODEL: gpt-4o\nextensions:\n  # local tools\n  fetch:\n    cmd: uvx\n";
+        std::fs::write(dir.join("config.yaml"), original).unwrap();
+        write_model(
+            &Harness::new(HarnessKind::Goose),
+            dir,
+            "anthropic/claude-sonnet-4-5",
+        )
+        .unwrap();
+        assert_eq!(
+            std::fs::read_to_string(dir.join("config.yaml")).unwrap(),
+            original
+                .replace("openai", "anthropic")
+                .replace("gpt-4o", "claude-sonnet-4-5")
+        );
+    }
+}
@@ -0,0 +1,364 @@
+//! Permission rules and hooks in Claude Code's `settings.json`.
+//!
+//! Editing functions take a Claude Code config directory: a profile or `~/.claude`.
+
+use std::path::Path;
+
+use serde_json::{Map, Value, json};
+
+use super::plugins::{read_json, write_json};
+use super::types::{HookInfo, PermissionEffect, PermissionRules};
+use crate::error::{Error, Result};
+
+const SETTINGS: &str = "settings.json";
+
+/// Hook events Claude Code runs commands on.
+pub const HOOK_EVENTS: &[&str] = &[
+    "PreToolUse",
+    "PostToolUse",
+    "Notification",
+    "UserPromptSubmit",
+    "Stop",
+    "SubagentStop",
+    "PreCompact",
+    "SessionStart",
+    "SessionEnd",
+];
+
+/// The `permissions` object of a parsed `settings.json`.
+pub fn parse_permissions(settings: &Value) -> PermissionRules {
+    let permissions = settings.get("permissions");
+    let list = |effect: PermissionEffect| -> Vec<String> {
+        permissions
+            .and_then(|p| p.get(effect.as_str()))
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|rule| rule.as_str().map(String::from))
+            .collect()
+    };
+    PermissionRules {
+        allow: list(PermissionEffect::Allow),
+        ask: list(PermissionEffect::Ask),
+        deny: list(PermissionEffect::Deny),
+        default_mode: permissions
+            .and_then(|p| p.get("defaultMode"))
+            .and_then(Value::as_str)
+            .map(String::from),
+    }
+}
+
+/// The command hooks of a parsed `settings.json`, in file order per event.
+pub fn parse_hooks(settings: &Value) -> Vec<HookInfo> {
+    let Some(events) = settings.get("hooks").and_then(Value::as_object) else {
+        return Vec::new();
+    };
+    let mut hooks = Vec::new();
+    for (event, groups) in events {
+        for group in groups.as_array().into_iter().flatten() {
+            let matcher = group
+                .get("matcher")
+                .and_then(Value::as_str)
+                .filter(|m| !m.is_empty())
+                .map(String::from);
+            let commands = group.get("hooks").and_then(Value::as_array);
+            for command in commands.into_iter().flatten() {
+                if let Some(command) = command.get("command").and_then(Value::as_str) {
+                    hooks.push(HookInfo {
+                        event: event.clone(),
+                        matcher: matcher.clone(),
+                        command: command.to_string(),
+                    });
+                }
+            }
+        }
+    }
+    hooks
+}
+
+fn edit_settings<T>(
+    dir: &Path,
+    edit: impl FnOnce(&mut Map<String, Value>) -> Result<T>,
+) -> Result<T> {
+    let path = dir.join(SETTINGS);
+    let mut settings = read_json(&path)?;
+    let object = settings
+        .as_object_mut()
+        .ok_or_else(|| Error::Config(format!("{}: not an object", path.display())))?;
+    let result = edit(object)?;
+    write_json(&path, &settings)?;
+    Ok(result)
+}
+
+/// The object at `key` in `object`, created if missing.
+fn child_object<'a>(
+    object: &'a mut Map<String, Value>,
+    key: &str,
+) -> Result<&'a mut Map<String, Value>> {
+    object
+        .entry(key)
+        .or_insert_with(|| Value::Object(Map::new()))
+        .as_object_mut()
+        .ok_or_else(|| Error::Config(format!("settings.json: '{key}' is not an object")))
+}
+
+/// Removes `rule` from every rule list. Returns the effect it had.
+fn take_rule(permissions: &mut Map<String, Value>, rule: &str) -> Option<PermissionEffect> {
+    let mut previous = None;
+    for effect in PermissionEffect::ALL {
+        let Some(list) = permissions
+            .get_mut(effect.as_str())
+            .and_then(Value::as_array_mut)
+        else {
+            continue;
+        };
+        let before = list.len();
+        list.retain(|r| r.as_str() != Some(rule));
+        if list.len() != before {
+            previous = Some(effect);
+        }
+        if list.is_empty() {
+            permissions.shift_remove(effect.as_str());
+        }
+    }
+    previous
+}
+
+/// Adds `rule` to the `effect` list, moving it out of the other lists so each
+/// rule has one effect. Returns the effect it had before.
+pub fn add_rule(
+    dir: &Path,
+    effect: PermissionEffect,
+    rule: &str,
+) -> Result<Option<PermissionEffect>> {
+    if rule.trim().is_empty() {
+        return Err(Error::InvalidValue("permission rule is empty".to_string()));
+    }
+    edit_settings(dir, |settings| {
+        let permissions = child_object(settings, "permissions")?;
+        let previous = take_rule(permissions, rule);
+        permissions
+            .entry(effect.as_str())
+            .or_insert_with(|| json!([]))
+            .as_array_mut()
+            .ok_or_else(|| {
+                Error::Config(format!(
+                    "settings.json: 'permissions.{}' is not a list",
+                    effect.as_str()
+                ))
+            })?
+            .push(rule.into());
+        Ok(previous)
+    })
+}
+
+/// Removes `rule` from whichever list holds it. Returns the effect it had.
+pub fn remove_rule(dir: &Path, rule: &str) -> Result<Option<PermissionEffect>> {
+    edit_settings(dir, |settings| {
+        let Some(permissions) = settings
+            .get_mut("permissions")
+            .and_then(Value::as_object_mut)
+        else {
+            return Ok(None);
+        };
+        let previous = take_rule(permissions, rule);
+        if permissions.is_empty() {
+            settings.shift_remove("permissions");
+        }
+        Ok(previous)
+    })
+}
+
+fn check_event(event: &str) -> Result<()> {
+    if HOOK_EVENTS.contains(&event) {
+        return Ok(());
+    }
+    Err(Error::InvalidValue(format!(
+        "unknown hook event: {event} (expected one of {})",
+        HOOK_EVENTS.join(", ")
+    )))
+}
+
+fn same_matcher(group: &Value, matcher: Option<&str>) -> bool {
+    let existing = group
+        .get("matcher")
+        .and_then(Value::as_str)
+        .filter(|m| !m.is_empty());
+    existing == matcher.filter(|m| !m.is_empty())
+}
+
+/// Adds a command hook for `event`, limited to tools matching `matcher`.
+/// Returns false if the same hook already exists.
+pub fn add_hook(dir: &Path, event: &str, matcher: Option<&str>, command: &str) -> Result<bool> {
+    check_event(event)?;
+    edit_settings(dir, |settings| {
+        let groups = child_object(settings, "hooks")?
+            .entry(event)
+            .or_insert_with(|| json!([]))
+            .as_array_mut()
+            .ok_or_else(|| {
+                Error::Config(format!("settings.json: 'hooks.{event}' is not a list"))
+            })?;
+
+        let index = match groups.iter().position(|g| same_matcher(g, matcher)) {
+            Some(index) => index,
+            None => {
+                let mut group = Map::new();
+                if let Some(matcher) = matcher {
+                    group.insert("matcher".to_string(), matcher.into());
+                }
+                group.insert("hooks".to_string(), json!([]));
+                groups.push(Value::Object(group));
+                groups.len() - 1
+            }
+        };
+        let commands = groups[index]
+            .as_object_mut()
+            .and_then(|g| g.entry("hooks").or_insert_with(|| json!([])).as_array_mut())
+            .ok_or_else(|| Error::Config(format!("settings.json: malformed '{event}' hook")))?;
+        if commands
+            .iter()
+            .any(|c| c.get("command").and_then(Value::as_str) == Some(command))
+        {
+            return Ok(false);
+        }
+        commands.push(json!({"type": "command", "command": command}));
+        Ok(true)
+    })
+}
+
+/// Removes `command` from the hooks of `event`, only under `matcher` if one is
+/// given. Empty matcher groups and events are dropped. Returns how many hooks
+/// were removed.
+pub fn remove_hook(dir: &Path, event: &str, matcher: Option<&str>, command: &str) -> Result<usize> {
+    check_event(event)?;
+    edit_settings(dir, |settings| {
+        let Some(hooks) = settings.get_mut("hooks").and_then(Value::as_object_mut) else {
+            return Ok(0);
+        };
+        let Some(groups) = hooks.get_mut(event).and_then(Value::as_array_mut) else {
+            return Ok(0);
+        };
+
+        let mut removed = 0;
+        for group in groups.iter_mut() {
+            if matcher.is_some() && !same_matcher(group, matcher) {
+                continue;
+            }
+            if let Some(commands) = group.get_mut("hooks").and_then(Value::as_array_mut) {
+                let before = commands.len();
+                commands.retain(|c| c.get("command").and_then(Value::as_str) != Some(command));
+                removed += before - commands.len();
+            }
+        }
+        groups.retain(|g| {
+            g.get("hooks")
+                .and_then(Value::as_array)
+                .is_some_and(|c| !c.is_empty())
+        });
+        if groups.is_empty() {
+            hooks.shift_remove(event);
+        }
+        if hooks.is_empty() {
+            settings.shift_remove("hooks");
+        }
+        Ok(removed)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn settings(dir: &Path) -> Value {
+        read_json(&dir.join(SETTINGS)).unwrap()
+    }
+
+    #[test]
+    fn rules_move_between_lists_and_prune() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path();
+        std::fs::write(
+            dir.join(SETTINGS),
+            r#"{"model": "opus", "permissions": {"deny": ["Bash(rm:*)"], "defaultMode": "acceptEdits"}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            add_rule(dir, PermissionEffect::Allow, "Bash(git:*)").unwrap(),
+            None
+        );
+        assert_eq!(
+            add_rule(dir, PermissionEffect::Ask, "Bash(rm:*)").unwrap(),
+            Some(PermissionEffect::Deny)
+        );
+        let rules = parse_permissions(&settings(dir));
+        assert_eq!(rules.allow, ["Bash(git:*)"]);
+        assert_eq!(rules.ask, ["Bash(rm:*)"]);
+        assert!(rules.deny.is_empty());
+        assert_eq!(rules.default_mode.as_deref(), Some("acceptEdits"));
+
+        assert_eq!(
+            remove_rule(dir, "Bash(git:*)").unwrap(),
+            Some(PermissionEffect::Allow)
+        );
+        assert_eq!(remove_rule(dir, "Bash(git:*)").unwrap(), None);
+        let document = settings(dir);
+        assert_eq!(document["model"], "opus");
+        assert!(document["permissions"].get("allow").is_none());
+    }
+
+    #[test]
+    fn hooks_group_by_matcher_and_prune() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path();
+
+        assert!(add_hook(dir, "PreToolUse", Some("Bash"), "./check.sh").unwrap());
+        assert!(add_hook(dir, "PreToolUse", Some("Bash"), "./audit.sh").unwrap());
+        assert!(!add_hook(dir, "PreToolUse", Some("Bash"), "./check.sh").unwrap());
+        assert!(add_hook(dir, "Stop", None, "notify-send done").unwrap());
+        assert!(add_hook(dir, "Teardown", None, "x").is_err());
+
+        let document = settings(dir);
+        assert_eq!(document["hooks"]["PreToolUse"].as_array().unwrap().len(), 1);
+        assert_eq!(
+            parse_hooks(&document),
+            [
+                HookInfo {
+                    event: "PreToolUse".to_string(),
+                    matcher: Some("Bash".to_string()),
+                    command: "./check.sh".to_string(),
+                },
+                HookInfo {
+                    event: "PreToolUse".to_string(),
+                    matcher: Some("Bash".to_string()),
+                    command: "./audit.sh".to_string(),
+                },
+                HookInfo {
+                    event: "Stop".to_string(),
+                    matcher: None,
+                    command: "notify-send done".to_string(),
+                },
+            ]
+        );
+
+        assert_eq!(
+            remove_hook(dir, "PreToolUse", Some("Edit"), "./check.sh").unwrap(),
+            0
+        );
+        assert_eq!(
+            remove_hook(dir, "PreToolUse", None, "./check.sh").unwrap(),
+            1
+        );
+        assert_eq!(
+            remove_hook(dir, "PreToolUse", None, "./audit.sh").unwrap(),
+            1
+        );
+        assert_eq!(
+            remove_hook(dir, "Stop", None, "notify-send done").unwrap(),
+            1
+        );
+        assert!(settings(dir).get("hooks").is_none());
+    }
+}
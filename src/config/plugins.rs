@@ -0,0 +1,407 @@
+//! Claude Code plugin state: `enabledPlugins` in `settings.json`, the
+//! marketplaces in `plugins/known_marketplaces.json`, and the installs in
+//! `plugins/installed_plugins.json`.
+//!
+//! Every function takes a Claude Code config directory: a profile or `~/.claude`.
+
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use serde_json::{Map, Value, json};
+
+use crate::error::{Error, Result};
+use crate::harness::{parse_document, write_document};
+
+const SETTINGS: &str = "settings.json";
+const KNOWN_MARKETPLACES: &str = "plugins/known_marketplaces.json";
+const INSTALLED_PLUGINS: &str = "plugins/installed_plugins.json";
+const MARKETPLACES_DIR: &str = "plugins/marketplaces";
+
+/// A plugin that is enabled, disabled, or installed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PluginEntry {
+    /// `name@marketplace`, as used in `enabledPlugins`.
+    pub id: String,
+    pub enabled: bool,
+    pub installed: bool,
+}
+
+/// A marketplace plugins can be installed from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MarketplaceEntry {
+    pub name: String,
+    /// The GitHub repo, git URL, or directory the marketplace comes from.
+    pub source: String,
+}
+
+/// Splits `name@marketplace`.
+pub fn split_id(id: &str) -> Result<(&str, &str)> {
+    id.rsplit_once('@')
+        .filter(|(name, marketplace)| !name.is_empty() && !marketplace.is_empty())
+        .ok_or_else(|| {
+            Error::InvalidValue(format!("plugin must be given as name@marketplace: {id}"))
+        })
+}
+
+/// The JSON document at `path`: an empty object if it is missing or blank.
+pub(super) fn read_json(path: &Path) -> Result<Value> {
+    match std::fs::read_to_string(path) {
+        Ok(content) if !content.trim().is_empty() => {
+            let filename = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or_default();
+            parse_document(&content, filename)
+        }
+        Ok(_) => Ok(Value::Object(Map::new())),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Value::Object(Map::new())),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Writes `value` to `path`, keeping key order and any comments.
+pub(super) fn write_json(path: &Path, value: &Value) -> Result<()> {
+    write_document(path, value)
+}
+
+/// The object at `key` in `value`, created if missing.
+fn object_at<'a>(
+    value: &'a mut Value,
+    key: &str,
+    path: &Path,
+) -> Result<&'a mut Map<String, Value>> {
+    let not_object = || Error::Config(format!("{}: '{key}' is not an object", path.display()));
+    value
+        .as_object_mut()
+        .ok_or_else(not_object)?
+        .entry(key)
+        .or_insert_with(|| Value::Object(Map::new()))
+        .as_object_mut()
+        .ok_or_else(not_object)
+}
+
+/// `installPath` of each installed plugin. Handles both the single-entry and
+/// the per-scope list layouts of `installed_plugins.json`.
+fn installed(dir: &Path) -> Result<Vec<(String, Option<PathBuf>)>> {
+    let document = read_json(&dir.join(INSTALLED_PLUGINS))?;
+    let Some(plugins) = document.get("plugins").and_then(Value::as_object) else {
+        return Ok(Vec::new());
+    };
+    Ok(plugins
+        .iter()
+        .map(|(id, entry)| {
+            let entry = entry.as_array().and_then(|a| a.first()).unwrap_or(entry);
+            let path = entry
+                .get("installPath")
+                .and_then(Value::as_str)
+                .map(PathBuf::from);
+            (id.clone(), path)
+        })
+        .collect())
+}
+
+/// Plugins in `enabledPlugins` or `installed_plugins.json`, sorted by id.
+pub fn plugins(dir: &Path) -> Result<Vec<PluginEntry>> {
+    let settings = read_json(&dir.join(SETTINGS))?;
+    let mut entries: Vec<PluginEntry> = settings
+        .get("enabledPlugins")
+        .and_then(Value::as_object)
+        .into_iter()
+        .flatten()
+        .map(|(id, enabled)| PluginEntry {
+            id: id.clone(),
+            enabled: enabled.as_bool().unwrap_or(false),
+            installed: false,
+        })
+        .collect();
+    for (id, _) in installed(dir)? {
+        match entries.iter_mut().find(|e| e.id == id) {
+            Some(entry) => entry.installed = true,
+            None => entries.push(PluginEntry {
+                id,
+                enabled: false,
+                installed: true,
+            }),
+        }
+    }
+    entries.sort_by(|a, b| a.id.cmp(&b.id));
+    Ok(entries)
+}
+
+/// Marketplaces in `known_marketplaces.json`, sorted by name.
+pub fn marketplaces(dir: &Path) -> Result<Vec<MarketplaceEntry>> {
+    let document = read_json(&dir.join(KNOWN_MARKETPLACES))?;
+    let mut entries: Vec<MarketplaceEntry> = document
+        .as_object()
+        .into_iter()
+        .flatten()
+        .map(|(name, entry)| {
+            let source = entry.get("source").unwrap_or(&Value::Null);
+            let location = ["repo", "url", "path"]
+                .iter()
+                .find_map(|key| source.get(key).and_then(Value::as_str))
+                .unwrap_or_default();
+            MarketplaceEntry {
+                name: name.clone(),
+                source: location.to_string(),
+            }
+        })
+        .collect();
+    entries.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(entries)
+}
+
+/// Sets `enabledPlugins[id]` in `settings.json`.
+pub fn set_enabled(dir: &Path, id: &str, enabled: bool) -> Result<()> {
+    split_id(id)?;
+    let path = dir.join(SETTINGS);
+    let mut settings = read_json(&path)?;
+    object_at(&mut settings, "enabledPlugins", &path)?.insert(id.to_string(), enabled.into());
+    write_json(&path, &settings)
+}
+
+/// Parses a marketplace source: a local directory, a git URL, or `owner/repo`
+/// on GitHub, in the shape `known_marketplaces.json` stores it.
+pub fn parse_source(source: &str) -> Result<Value> {
+    let path = Path::new(source);
+    if path.is_dir() {
+        let path = path.canonicalize()?;
+        return Ok(json!({"source": "directory", "path": path.display().to_string()}));
+    }
+    if source.contains("://") || source.starts_with("git@") || source.ends_with(".git") {
+        return Ok(json!({"source": "git", "url": source}));
+    }
+    match source.split('/').collect::<Vec<_>>().as_slice() {
+        [owner, repo] if !owner.is_empty() && !repo.is_empty() => {
+            Ok(json!({"source": "github", "repo": source}))
+        }
+        _ => Err(Error::InvalidValue(format!(
+            "marketplace source must be owner/repo, a git URL, or a directory: {source}"
+        ))),
+    }
+}
+
+/// The name a marketplace from `source` goes by: the `name` in a local
+/// marketplace's `.claude-plugin/marketplace.json`, else the repository name.
+pub fn marketplace_name(source: &Value) -> Option<String> {
+    if let Some(path) = source.get("path").and_then(Value::as_str) {
+        let manifest = Path::new(path).join(".claude-plugin/marketplace.json");
+        if let Some(name) = read_json(&manifest)
+            .ok()
+            .and_then(|m| m.get("name").and_then(Value::as_str).map(String::from))
+        {
+            return Some(name);
+        }
+    }
+    let location = ["repo", "url", "path"]
+        .iter()
+        .find_map(|key| source.get(key).and_then(Value::as_str))?;
+    let last = location.trim_end_matches('/').rsplit(['/', ':']).next()?;
+    let name = last.strip_suffix(".git").unwrap_or(last);
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+/// Records marketplace `name` in `known_marketplaces.json`. `live_dir` is the
+/// Claude Code config directory the marketplace is checked out under.
+pub fn add_marketplace(dir: &Path, name: &str, source: Value, live_dir: &Path) -> Result<()> {
+    let path = dir.join(KNOWN_MARKETPLACES);
+    let mut document = read_json(&path)?;
+    let location = live_dir.join(MARKETPLACES_DIR).join(name);
+    let entry = json!({
+        "source": source,
+        "installLocation": location.display().to_string(),
+        "lastUpdated": chrono::Utc::now().to_rfc3339(),
+    });
+    document
+        .as_object_mut()
+        .ok_or_else(|| Error::Config(format!("{}: not an object", path.display())))?
+        .insert(name.to_string(), entry);
+    write_json(&path, &document)
+}
+
+/// Removes plugin `id` from `enabledPlugins` and `installed_plugins.json`.
+/// Returns false if neither had it.
+pub fn remove_plugin(dir: &Path, id: &str) -> Result<bool> {
+    let mut removed = false;
+
+    let path = dir.join(SETTINGS);
+    let mut settings = read_json(&path)?;
+    if let Some(enabled) = settings
+        .get_mut("enabledPlugins")
+        .and_then(Value::as_object_mut)
+        && enabled.shift_remove(id).is_some()
+    {
+        write_json(&path, &settings)?;
+        removed = true;
+    }
+
+    let path = dir.join(INSTALLED_PLUGINS);
+    let mut document = read_json(&path)?;
+    if let Some(plugins) = document.get_mut("plugins").and_then(Value::as_object_mut)
+        && plugins.shift_remove(id).is_some()
+    {
+        write_json(&path, &document)?;
+        removed = true;
+    }
+
+    Ok(removed)
+}
+
+/// Removes marketplace `name`, its checkout, and its plugins.
+/// Returns false if it wasn't known.
+pub fn remove_marketplace(dir: &Path, name: &str) -> Result<bool> {
+    let path = dir.join(KNOWN_MARKETPLACES);
+    let mut document = read_json(&path)?;
+    let known = document
+        .as_object_mut()
+        .is_some_and(|m| m.shift_remove(name).is_some());
+    if !known {
+        return Ok(false);
+    }
+    write_json(&path, &document)?;
+
+    for plugin in plugins(dir)? {
+        if split_id(&plugin.id).is_ok_and(|(_, marketplace)| marketplace == name) {
+            remove_plugin(dir, &plugin.id)?;
+        }
+    }
+    let checkout = dir.join(MARKETPLACES_DIR).join(name);
+    if checkout.is_dir() {
+        std::fs::remove_dir_all(checkout)?;
+    }
+    Ok(true)
+}
+
+/// Where the files of plugin `id` are inside `dir`: its install path, or its
+/// directory in the marketplace checkout. Absolute paths under `live_dir`
+/// are looked up under `dir` instead.
+pub fn plugin_dir(dir: &Path, id: &str, live_dir: &Path) -> Option<PathBuf> {
+    let (name, marketplace) = split_id(id).ok()?;
+
+    let install_path = installed(dir)
+        .ok()?
+        .into_iter()
+        .find(|(installed_id, _)| installed_id == id)
+        .and_then(|(_, path)| path)
+        .map(|path| match path.strip_prefix(live_dir) {
+            Ok(relative) => dir.join(relative),
+            Err(_) => path,
+        });
+    if let Some(path) = install_path.filter(|p| p.is_dir()) {
+        return Some(path);
+    }
+
+    let checkout = dir.join(MARKETPLACES_DIR).join(marketplace);
+    let manifest = read_json(&checkout.join(".claude-plugin/marketplace.json")).ok()?;
+    manifest
+        .get("plugins")
+        .and_then(Value::as_array)?
+        .iter()
+        .find(|p| p.get("name").and_then(Value::as_str) == Some(name))
+        .and_then(|p| p.get("source").and_then(Value::as_str))
+        .map(|source| checkout.join(source.trim_start_matches("./")))
+        .filter(|p| p.is_dir())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn enable_disable_and_remove_plugins() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path();
+        std::fs::write(dir.join(SETTINGS), r#"{"model": "opus"}"#).unwrap();
+
+        set_enabled(dir, "review@tools", true).unwrap();
+        set_enabled(dir, "lint@tools", false).unwrap();
+        assert!(set_enabled(dir, "lint", true).is_err());
+
+        let entries = plugins(dir).unwrap();
+        assert_eq!(
+            entries,
+            [
+                PluginEntry {
+                    id: "lint@tools".to_string(),
+                    enabled: false,
+                    installed: false,
+                },
+                PluginEntry {
+                    id: "review@tools".to_string(),
+                    enabled: true,
+                    installed: false,
+                },
+            ]
+        );
+        let settings = read_json(&dir.join(SETTINGS)).unwrap();
+        assert_eq!(settings["model"], "opus");
+
+        assert!(remove_plugin(dir, "lint@tools").unwrap());
+        assert!(!remove_plugin(dir, "lint@tools").unwrap());
+        assert_eq!(plugins(dir).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn marketplaces_round_trip_and_take_their_plugins_along() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path();
+        let source = parse_source("acme/claude-tools").unwrap();
+        assert_eq!(marketplace_name(&source).as_deref(), Some("claude-tools"));
+        assert_eq!(
+            marketplace_name(&parse_source("git@github.com:acme/tools.git").unwrap()).as_deref(),
+            Some("tools")
+        );
+        assert!(parse_source("not a source").is_err());
+
+        add_marketplace(dir, "claude-tools", source, Path::new("/home/me/.claude")).unwrap();
+        assert_eq!(
+            marketplaces(dir).unwrap(),
+            [MarketplaceEntry {
+                name: "claude-tools".to_string(),
+                source: "acme/claude-tools".to_string(),
+            }]
+        );
+
+        set_enabled(dir, "review@claude-tools", true).unwrap();
+        set_enabled(dir, "other@elsewhere", true).unwrap();
+        assert!(remove_marketplace(dir, "claude-tools").unwrap());
+        assert!(marketplaces(dir).unwrap().is_empty());
+        let ids: Vec<String> = plugins(dir).unwrap().into_iter().map(|p| p.id).collect();
+        assert_eq!(ids, ["other@elsewhere"]);
+    }
+
+    #[test]
+    fn plugin_dir_follows_install_path_or_marketplace_manifest() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path().join("profile");
+        let live = Path::new("/home/me/.claude");
+
+        let checkout = dir.join(MARKETPLACES_DIR).join("tools");
+        std::fs::create_dir_all(checkout.join(".claude-plugin")).unwrap();
+        std::fs::create_dir_all(checkout.join("plugins/review")).unwrap();
+        std::fs::write(
+            checkout.join(".claude-plugin/marketplace.json"),
+            r#"{"name": "tools", "plugins": [{"name": "review", "source": "./plugins/review"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            plugin_dir(&dir, "review@tools", live),
+            Some(checkout.join("plugins/review"))
+        );
+
+        let cached = dir.join("plugins/cache/tools/review/1.0.0");
+        std::fs::create_dir_all(&cached).unwrap();
+        write_json(
+            &dir.join(INSTALLED_PLUGINS),
+            &json!({"version": 2, "plugins": {"review@tools": [{
+                "scope": "user",
+                "installPath": "/home/me/.claude/plugins/cache/tools/review/1.0.0"
+            }]}}),
+        )
+        .unwrap();
+        assert_eq!(plugin_dir(&dir, "review@tools", live), Some(cached));
+        assert!(plugins(&dir).unwrap()[0].installed);
+        assert_eq!(plugin_dir(&dir, "missing@tools", live), None);
+    }
+}
@@ -0,0 +1,172 @@
+//! Rules files (`CLAUDE.md`, `AGENTS.md`, `.goosehints`, ...) and the shared
+//! snippets composed into them.
+
+use std::path::{Path, PathBuf};
+
+use super::BridleConfig;
+use crate::error::{Error, Result};
+use crate::harness::HarnessConfig;
+
+/// Opens the block of snippets bridle appends to a rules file.
+pub const SNIPPETS_START: &str = "<!-- bridle:snippets -->";
+/// Closes the block of snippets bridle appends to a rules file.
+pub const SNIPPETS_END: &str = "<!-- /bridle:snippets -->";
+
+/// `content` without the snippet block bridle appended to it.
+pub fn strip_snippets(content: &str) -> String {
+    let Some(start) = content.find(SNIPPETS_START) else {
+        return content.to_string();
+    };
+    let rest = &content[start..];
+    let after = rest
+        .find(SNIPPETS_END)
+        .map_or("", |end| &rest[end + SNIPPETS_END.len()..]);
+    let before = content[..start].trim_end();
+    let after = after.trim_start_matches('\n');
+    match (before.is_empty(), after.is_empty()) {
+        (true, _) => after.to_string(),
+        (false, true) => format!("{before}\n"),
+        (false, false) => format!("{before}\n\n{after}"),
+    }
+}
+
+/// `base` followed by a block holding each snippet's text, replacing any earlier block.
+pub fn compose(base: &str, snippets: &[String]) -> String {
+    let base = strip_snippets(base);
+    if snippets.is_empty() {
+        return base;
+    }
+    let mut out = base.trim_end().to_string();
+    if !out.is_empty() {
+        out.push_str("\n\n");
+    }
+    out.push_str(SNIPPETS_START);
+    out.push('\n');
+    let bodies: Vec<&str> = snippets.iter().map(|s| s.trim()).collect();
+    out.push_str(&bodies.join("\n\n"));
+    out.push('\n');
+    out.push_str(SNIPPETS_END);
+    out.push('\n');
+    out
+}
+
+/// Names of the snippets in `~/.config/bridle/snippets/`, sorted.
+pub fn list_snippets() -> Result<Vec<String>> {
+    let dir = BridleConfig::snippets_dir()?;
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut names: Vec<String> = std::fs::read_dir(dir)?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.is_file() && p.extension().is_some_and(|ext| ext == "md"))
+        .filter_map(|p| p.file_stem().and_then(|s| s.to_str()).map(String::from))
+        .collect();
+    names.sort();
+    Ok(names)
+}
+
+/// The text of snippet `name`.
+pub fn load_snippet(name: &str) -> Result<String> {
+    let path = BridleConfig::snippets_dir()?.join(format!("{name}.md"));
+    if !path.is_file() {
+        return Err(Error::Config(format!(
+            "snippet not found: {name} (expected {})",
+            path.display()
+        )));
+    }
+    Ok(std::fs::read_to_string(path)?)
+}
+
+/// Where the rules file is stored inside the profile at `profile_path`.
+pub fn profile_rules_path(harness: &dyn HarnessConfig, profile_path: &Path) -> Option<PathBuf> {
+    harness.rules_file().map(|file| profile_path.join(file))
+}
+
+/// Where the harness reads its rules file from: a project root for project
+/// profiles, otherwise the config directory.
+pub fn live_rules_path(harness: &dyn HarnessConfig) -> Result<Option<PathBuf>> {
+    let Some(file) = harness.rules_file() else {
+        return Ok(None);
+    };
+    if let Some(extra) = harness
+        .extra_files()
+        .into_iter()
+        .find(|p| p.file_name().is_some_and(|n| n == file.as_str()))
+    {
+        return Ok(Some(extra));
+    }
+    Ok(Some(harness.config_dir()?.join(file)))
+}
+
+/// Recomposes the rules file of the profile at `profile_path` with the snippets
+/// named in `names`. A missing rules file is only created when there are snippets.
+///
+/// Snippets that can't be loaded, e.g. deleted from the snippets directory, are
+/// skipped with a warning so they don't block a profile switch.
+///
+/// Returns the path of the rules file if it exists afterwards.
+pub fn apply_snippets(
+    harness: &dyn HarnessConfig,
+    profile_path: &Path,
+    names: &[String],
+) -> Result<Option<PathBuf>> {
+    let Some(path) = profile_rules_path(harness, profile_path) else {
+        return Ok(None);
+    };
+    let current = if path.is_file() {
+        std::fs::read_to_string(&path)?
+    } else if names.is_empty() {
+        return Ok(None);
+    } else {
+        String::new()
+    };
+    let snippets: Vec<String> = names
+        .iter()
+        .filter_map(|name| match load_snippet(name) {
+            Ok(snippet) => Some(snippet),
+            Err(e) => {
+                eprintln!("Warning: skipping snippet {name}: {e}");
+                None
+            }
+        })
+        .collect();
+    let composed = compose(&current, &snippets);
+    if composed != current {
+        std::fs::write(&path, composed)?;
+    }
+    Ok(Some(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compose_appends_one_block_and_replaces_it() {
+        let base = "# Rules\n\nBe terse.\n";
+        let once = compose(base, &["Use tabs.\n".to_string(), "No TODOs.".to_string()]);
+        assert_eq!(
+            once,
+            "# Rules\n\nBe terse.\n\n<!-- bridle:snippets -->\nUse tabs.\n\nNo TODOs.\n<!-- /bridle:snippets -->\n"
+        );
+
+        let again = compose(&once, &["Use spaces.".to_string()]);
+        assert_eq!(again.matches(SNIPPETS_START).count(), 1);
+        assert!(again.contains("Use spaces."));
+        assert!(!again.contains("Use tabs."));
+
+        assert_eq!(compose(&again, &[]), base);
+    }
+
+    #[test]
+    fn strip_keeps_text_after_the_block() {
+        let content =
+            "Top\n\n<!-- bridle:snippets -->\nx\n<!-- /bridle:snippets -->\nEdited below\n";
+        assert_eq!(strip_snippets(content), "Top\n\nEdited below\n");
+        assert_eq!(
+            compose("", &["Only snippet".to_string()]),
+            "<!-- bridle:snippets -->\nOnly snippet\n<!-- /bridle:snippets -->\n"
+        );
+    }
+}
@@ -24,6 +24,78 @@ pub struct ResourceSummary {
     pub directory_exists: bool,
 }
 
+/// Whether a permission rule allows a tool use, asks first, or denies it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PermissionEffect {
+    Allow,
+    Ask,
+    Deny,
+}
+
+impl PermissionEffect {
+    pub const ALL: [PermissionEffect; 3] = [Self::Allow, Self::Ask, Self::Deny];
+
+    /// The key of the rule list in `settings.json`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Allow => "allow",
+            Self::Ask => "ask",
+            Self::Deny => "deny",
+        }
+    }
+}
+
+/// Permission rules from Claude Code's `settings.json`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct PermissionRules {
+    pub allow: Vec<String>,
+    pub ask: Vec<String>,
+    pub deny: Vec<String>,
+    /// `permissions.defaultMode` (e.g. `acceptEdits`).
+    pub default_mode: Option<String>,
+}
+
+impl PermissionRules {
+    pub fn list(&self, effect: PermissionEffect) -> &[String] {
+        match effect {
+            PermissionEffect::Allow => &self.allow,
+            PermissionEffect::Ask => &self.ask,
+            PermissionEffect::Deny => &self.deny,
+        }
+    }
+
+    /// Every rule with its effect, allow rules first.
+    pub fn rules(&self) -> impl Iterator<Item = (PermissionEffect, &str)> {
+        PermissionEffect::ALL
+            .into_iter()
+            .flat_map(|effect| self.list(effect).iter().map(move |r| (effect, r.as_str())))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules().next().is_none() && self.default_mode.is_none()
+    }
+}
+
+/// A command Claude Code runs on a hook event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HookInfo {
+    /// Hook event (e.g. `PreToolUse`).
+    pub event: String,
+    /// Tool name pattern the hook is limited to, if any.
+    pub matcher: Option<String>,
+    pub command: String,
+}
+
+impl std::fmt::Display for HookInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.matcher.as_deref().filter(|m| !m.is_empty()) {
+            Some(matcher) => write!(f, "{} [{matcher}]: {}", self.event, self.command),
+            None => write!(f, "{}: {}", self.event, self.command),
+        }
+    }
+}
+
 /// Information about a profile for display purposes.
 #[derive(Debug, Clone, Default, Serialize)]
 pub struct ProfileInfo {
@@ -53,6 +125,10 @@ pub struct ProfileInfo {
     pub theme: Option<String>,
     /// Model setting.
     pub model: Option<String>,
+    /// Permission rules (Claude Code only).
+    pub permissions: Option<PermissionRules>,
+    /// Hook commands (Claude Code only).
+    pub hooks: Option<Vec<HookInfo>>,
     /// Errors encountered during extraction.
     pub extraction_errors: Vec<String>,
 }
@@ -0,0 +1,329 @@
+//! Comment-preserving edits to block-style YAML files (Goose's config.yaml).
+
+use serde_json::{Map, Value};
+
+/// Rewrites `original` YAML so it parses to `new`, given that it parses to
+/// `old`, touching only the keys that changed.
+///
+/// Comments and formatting elsewhere in the document are kept, and new keys go
+/// at the end of their mapping. Returns `None` if `original` is not a block
+/// mapping, or uses YAML (anchors, multi-line keys) the edit can't follow.
+pub fn patch(original: &str, old: &Value, new: &Value) -> Option<String> {
+    let (Value::Object(old_map), Value::Object(new_map)) = (old, new) else {
+        return None;
+    };
+    let lines: Vec<&str> = original.lines().collect();
+    let root = match mapping(&lines, 0, lines.len())? {
+        Some(block) => block,
+        None => Block {
+            indent: 0,
+            entries: Vec::new(),
+            end: lines.len(),
+        },
+    };
+
+    let mut edits = Vec::new();
+    patch_mapping(&lines, &root, old_map, new_map, &mut edits)?;
+    // Later edits first so earlier line numbers stay valid. At the same line a
+    // mapping's new keys go in before those of its last child, so they end up after.
+    edits.reverse();
+    edits.sort_by_key(|e| std::cmp::Reverse((e.start, e.end)));
+    let mut result: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+    for edit in edits {
+        result.splice(edit.start..edit.end, edit.lines);
+    }
+
+    let mut text = result.join("\n");
+    if original.ends_with('\n') || original.is_empty() {
+        text.push('\n');
+    }
+    let reparsed = serde_json::to_value(serde_yaml::from_str::<serde_yaml::Value>(&text).ok()?);
+    (reparsed.ok()? == *new).then_some(text)
+}
+
+/// A block mapping: its entries and the line after its last one.
+struct Block {
+    indent: usize,
+    entries: Vec<Entry>,
+    end: usize,
+}
+
+/// A `key: value` entry spanning lines `start..end`, children included.
+struct Entry {
+    key: String,
+    start: usize,
+    end: usize,
+    /// The value on the key's own line, without its comment.
+    inline: String,
+    /// The comment after an inline value, with the spacing before it.
+    comment: Option<String>,
+}
+
+/// Replaces lines `start..end` with `lines`.
+struct Edit {
+    start: usize,
+    end: usize,
+    lines: Vec<String>,
+}
+
+fn is_trivia(line: &str) -> bool {
+    let trimmed = line.trim();
+    trimmed.is_empty() || trimmed.starts_with('#') || line == "---"
+}
+
+fn indent_of(line: &str) -> usize {
+    line.len() - line.trim_start_matches(' ').len()
+}
+
+fn is_sequence_item(text: &str) -> bool {
+    text == "-" || text.starts_with("- ")
+}
+
+/// Parses the block mapping in lines `start..end`.
+///
+/// Returns `Some(None)` when the range holds no entries, and `None` when it
+/// holds something other than a block mapping.
+fn mapping(lines: &[&str], start: usize, end: usize) -> Option<Option<Block>> {
+    let mut entries: Vec<Entry> = Vec::new();
+    let mut indent = None;
+    let mut i = start;
+    while i < end {
+        let line = lines[i];
+        if is_trivia(line) {
+            i += 1;
+            continue;
+        }
+        let line_indent = indent_of(line);
+        if *indent.get_or_insert(line_indent) != line_indent {
+            return None;
+        }
+        let (key, rest) = split_key(&line[line_indent..])?;
+        let (inline, comment) = split_comment(rest);
+
+        // Children are indented deeper; a sequence may also sit at the key's indent.
+        let mut last = i;
+        let mut j = i + 1;
+        while j < end {
+            let next = lines[j];
+            if !is_trivia(next) {
+                let next_indent = indent_of(next);
+                let continues = next_indent > line_indent
+                    || (next_indent == line_indent
+                        && inline.is_empty()
+                        && is_sequence_item(&next[next_indent..]));
+                if !continues {
+                    break;
+                }
+                last = j;
+            }
+            j += 1;
+        }
+
+        entries.push(Entry {
+            key,
+            start: i,
+            end: last + 1,
+            inline: inline.to_string(),
+            comment: comment.map(String::from),
+        });
+        i = last + 1;
+    }
+
+    let Some(indent) = indent else {
+        return Some(None);
+    };
+    let end = entries.last().map_or(start, |e| e.end);
+    Some(Some(Block {
+        indent,
+        entries,
+        end,
+    }))
+}
+
+/// Splits `key: rest` into the key and the text after the colon.
+fn split_key(text: &str) -> Option<(String, &str)> {
+    if is_sequence_item(text) || text.starts_with(['{', '[', '?', '&', '*', '!', '|', '>']) {
+        return None;
+    }
+    let (key, rest) = match text.chars().next()? {
+        quote @ ('"' | '\'') => {
+            let close = text[1..].find(quote)? + 1;
+            let key = &text[1..close];
+            if key.contains('\\') || (quote == '\'' && text[close + 1..].starts_with('\'')) {
+                return None;
+            }
+            (key, text[close + 1..].strip_prefix(':')?)
+        }
+        _ => {
+            let colon = text
+                .find(": ")
+                .or_else(|| text.strip_suffix(':').map(str::len))?;
+            (text[..colon].trim_end(), &text[colon + 1..])
+        }
+    };
+    if rest.starts_with(|c: char| !c.is_whitespace()) {
+        return None;
+    }
+    Some((key.to_string(), rest))
+}
+
+/// Splits an inline value from a trailing `# comment`, ignoring `#` in quotes.
+fn split_comment(rest: &str) -> (&str, Option<&str>) {
+    let mut quote = None;
+    let mut previous = ' ';
+    for (i, c) in rest.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == '#' && previous.is_whitespace() => {
+                let value = rest[..i].trim_end();
+                return (value.trim_start(), Some(&rest[value.len()..]));
+            }
+            None => {}
+        }
+        previous = c;
+    }
+    (rest.trim(), None)
+}
+
+fn patch_mapping(
+    lines: &[&str],
+    block: &Block,
+    old: &Map<String, Value>,
+    new: &Map<String, Value>,
+    edits: &mut Vec<Edit>,
+) -> Option<()> {
+    for entry in &block.entries {
+        let Some(value) = new.get(&entry.key) else {
+            edits.push(Edit {
+                start: entry.start,
+                end: entry.end,
+                lines: Vec::new(),
+            });
+            continue;
+        };
+        let previous = old.get(&entry.key);
+        if previous == Some(value) {
+            continue;
+        }
+        if let (Some(Value::Object(old_child)), Value::Object(new_child)) = (previous, value)
+            && entry.inline.is_empty()
+            && let Some(Some(child)) = mapping(lines, entry.start + 1, entry.end)
+        {
+            patch_mapping(lines, &child, old_child, new_child, edits)?;
+            continue;
+        }
+        edits.push(Edit {
+            start: entry.start,
+            end: entry.end,
+            lines: render_entry(&entry.key, value, block.indent, entry.comment.as_deref())?,
+        });
+    }
+
+    let mut added = Vec::new();
+    for (key, value) in new {
+        if !block.entries.iter().any(|e| &e.key == key) {
+            added.extend(render_entry(key, value, block.indent, None)?);
+        }
+    }
+    if !added.is_empty() {
+        edits.push(Edit {
+            start: block.end,
+            end: block.end,
+            lines: added,
+        });
+    }
+    Some(())
+}
+
+/// Renders `key: value` at `indent`, keeping `comment` after a one-line value.
+fn render_entry(
+    key: &str,
+    value: &Value,
+    indent: usize,
+    comment: Option<&str>,
+) -> Option<Vec<String>> {
+    let mut entry = Map::new();
+    entry.insert(key.to_string(), value.clone());
+    let rendered = serde_yaml::to_string(&entry).ok()?;
+    let pad = " ".repeat(indent);
+    let mut lines: Vec<String> = rendered.lines().map(|l| format!("{pad}{l}")).collect();
+    if let (Some(comment), [line]) = (comment, lines.as_mut_slice()) {
+        line.push_str(comment);
+    }
+    Some(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn patched(original: &str, edit: impl FnOnce(&mut Value)) -> Option<String> {
+        let old =
+            serde_json::to_value(serde_yaml::from_str::<serde_yaml::Value>(original).unwrap())
+                .unwrap();
+        let mut new = old.clone();
+        edit(&mut new);
+        patch(original, &old, &new)
+    }
+
+    #[test]
+    fn patch_changes_only_edited_keys() {
+        let original = "# Goose settings\nGOOSE_PROVIDER: openai\nGOOSE_MODEL: gpt-4o # daily\nextensions:\n  # local tools\n  fetch:\n    enabled: true\n    cmd: uvx\n";
+        let result = patched(original, |v| {
+            v["GOOSE_MODEL"] = "gpt-5".into();
+            v["extensions"]["fetch"]["enabled"] = false.into();
+        });
+        assert_eq!(
+            result.as_deref(),
+            Some(
+                "# Goose settings\nGOOSE_PROVIDER: openai\nGOOSE_MODEL: gpt-5 # daily\nextensions:\n  # local tools\n  fetch:\n    enabled: false\n    cmd: uvx\n"
+            )
+        );
+    }
+
+    #[test]
+    fn patch_removes_and_appends_keys() {
+        let original = "a: 1\n# about b\nb:\n- x\n- y\nc: 3 # last\n";
+        let result = patched(original, |v| {
+            let object = v.as_object_mut().unwrap();
+            object.shift_remove("b");
+            object.insert("d".to_string(), serde_json::json!({"e": [1, 2]}));
+        });
+        assert_eq!(
+            result.as_deref(),
+            Some("a: 1\n# about b\nc: 3 # last\nd:\n  e:\n  - 1\n  - 2\n")
+        );
+    }
+
+    #[test]
+    fn patch_appends_nested_and_top_level_keys_in_place() {
+        let original = "model: a\nextensions:\n  fetch:\n    cmd: uvx\n";
+        let result = patched(original, |v| {
+            v["extensions"]["docs"] = serde_json::json!({"cmd": "docs"});
+            v["provider"] = "openai".into();
+        });
+        assert_eq!(
+            result.as_deref(),
+            Some(
+                "model: a\nextensions:\n  fetch:\n    cmd: uvx\n  docs:\n    cmd: docs\nprovider: openai\n"
+            )
+        );
+    }
+
+    #[test]
+    fn patch_keeps_hashes_inside_quotes() {
+        let original = "url: \"http://x/#frag\" # docs\nname: a\n";
+        let result = patched(original, |v| v["name"] = "b".into());
+        assert_eq!(
+            result.as_deref(),
+            Some("url: \"http://x/#frag\" # docs\nname: b\n")
+        );
+    }
+
+    #[test]
+    fn patch_declines_flow_documents() {
+        assert_eq!(patched("{a: 1}\n", |v| v["a"] = 2.into()), None);
+    }
+}
@@ -9,7 +9,9 @@ use ratatui::{
     text::{Line, Span},
 };
 
-use crate::config::{McpServerInfo, ProfileInfo, ResourceSummary};
+use crate::config::{
+    HookInfo, McpServerInfo, PermissionEffect, PermissionRules, ProfileInfo, ResourceSummary,
+};
 
 /// Semantic section types for profile display.
 ///
@@ -31,6 +33,10 @@ pub enum SectionKind {
     ResourceItem,
     /// Rules file reference.
     RulesFile { exists: bool },
+    /// Container listing one entry per line (permission rules, hooks).
+    ListGroup,
+    /// Individual permission rule.
+    PermissionRule { effect: PermissionEffect },
     /// Error or warning message.
     Error,
 }
@@ -169,6 +175,13 @@ pub fn profile_to_nodes(info: &ProfileInfo) -> Vec<ProfileNode> {
         .with_text(rules_text),
     );
 
+    if let Some(permissions) = &info.permissions {
+        nodes.push(build_permissions_node(permissions));
+    }
+    if let Some(hooks) = &info.hooks {
+        nodes.push(build_hooks_node(hooks));
+    }
+
     if !info.extraction_errors.is_empty() {
         let error_children: Vec<ProfileNode> = info
             .extraction_errors
@@ -212,6 +225,43 @@ fn build_mcp_node(info: &ProfileInfo) -> ProfileNode {
         .with_children(children)
 }
 
+fn build_permissions_node(permissions: &PermissionRules) -> ProfileNode {
+    let mut children: Vec<ProfileNode> = permissions
+        .default_mode
+        .iter()
+        .map(|mode| {
+            ProfileNode::new(SectionKind::ResourceItem, "").with_text(format!("mode: {mode}"))
+        })
+        .collect();
+    children.extend(permissions.rules().map(|(effect, rule)| {
+        ProfileNode::new(SectionKind::PermissionRule { effect }, "").with_text(format!(
+            "{} {}",
+            effect.as_str(),
+            rule
+        ))
+    }));
+
+    let count = permissions.rules().count();
+    let node = ProfileNode::new(SectionKind::ListGroup, "Permissions");
+    if children.is_empty() {
+        return node.with_text("(none)");
+    }
+    node.with_text(format!("({count})")).with_children(children)
+}
+
+fn build_hooks_node(hooks: &[HookInfo]) -> ProfileNode {
+    let node = ProfileNode::new(SectionKind::ListGroup, "Hooks");
+    if hooks.is_empty() {
+        return node.with_text("(none)");
+    }
+    let children = hooks
+        .iter()
+        .map(|hook| ProfileNode::new(SectionKind::ResourceItem, "").with_text(hook.to_string()))
+        .collect();
+    node.with_text(format!("({})", hooks.len()))
+        .with_children(children)
+}
+
 fn build_resource_node(
     label: &'static str,
     summary: &ResourceSummary,
@@ -325,7 +375,27 @@ fn render_node_text(out: &mut String, node: &ProfileNode) {
                 let _ = writeln!(out, "  {}", items.join(", "));
             }
         }
-        SectionKind::ResourceItem => {}
+        SectionKind::ResourceItem | SectionKind::PermissionRule { .. } => {}
+        SectionKind::ListGroup => {
+            if node.children.is_empty() {
+                let _ = writeln!(
+                    out,
+                    "{}: {}",
+                    node.label,
+                    node.text.as_deref().unwrap_or("(none)")
+                );
+            } else {
+                let _ = writeln!(
+                    out,
+                    "{} {}:",
+                    node.label,
+                    node.text.as_deref().unwrap_or("")
+                );
+                for child in &node.children {
+                    let _ = writeln!(out, "  {}", child.text.as_deref().unwrap_or(""));
+                }
+            }
+        }
         SectionKind::RulesFile { exists: _ } => {
             let _ = writeln!(
                 out,
@@ -412,6 +482,7 @@ pub fn nodes_to_lines(nodes: &[ProfileNode]) -> Vec<Line<'static>> {
             }
         })
         .filter(|n| !matches!(n.kind, SectionKind::RulesFile { exists: false }))
+        .filter(|n| !matches!(n.kind, SectionKind::ListGroup) || !n.children.is_empty())
         .collect();
 
     let total = display_nodes.len();
@@ -478,18 +549,53 @@ fn render_node_lines(lines: &mut Vec<Line<'static>>, node: &ProfileNode, tree: &
                 ));
             }
         }
-        SectionKind::RulesFile { exists } => {
-            if *exists {
-                lines.push(Line::styled(
-                    format!(
-                        "  {} Rules: {}",
-                        tree.branch,
-                        node.text.as_deref().unwrap_or("")
+        SectionKind::ListGroup => {
+            lines.push(Line::styled(
+                format!(
+                    "  {} {} {}",
+                    tree.branch,
+                    node.label,
+                    node.text.as_deref().unwrap_or("")
+                ),
+                Style::default().fg(Color::Gray),
+            ));
+            let item_count = node.children.len();
+            for (i, child) in node.children.iter().enumerate() {
+                let sub_tree = TreeBranch::for_index(i, item_count);
+                let color = match child.kind {
+                    SectionKind::PermissionRule {
+                        effect: PermissionEffect::Allow,
+                    } => Color::Green,
+                    SectionKind::PermissionRule {
+                        effect: PermissionEffect::Ask,
+                    } => Color::Yellow,
+                    SectionKind::PermissionRule {
+                        effect: PermissionEffect::Deny,
+                    } => Color::Red,
+                    _ => Color::Gray,
+                };
+                lines.push(Line::from(vec![
+                    Span::styled(
+                        format!("  {} {} ", tree.continuation, sub_tree.branch),
+                        Style::default().fg(Color::Gray),
                     ),
-                    Style::default().fg(Color::Gray),
-                ));
+                    Span::styled(
+                        child.text.clone().unwrap_or_default(),
+                        Style::default().fg(color),
+                    ),
+                ]));
             }
         }
+        SectionKind::RulesFile { exists: true } => {
+            lines.push(Line::styled(
+                format!(
+                    "  {} Rules: {}",
+                    tree.branch,
+                    node.text.as_deref().unwrap_or("")
+                ),
+                Style::default().fg(Color::Gray),
+            ));
+        }
         SectionKind::Error => {
             if node.label == "Errors" {
                 for child in &node.children {
@@ -602,6 +708,8 @@ mod tests {
             rules_file: None,
             theme: Some("dark".to_string()),
             model: Some("gpt-4".to_string()),
+            permissions: None,
+            hooks: None,
             extraction_errors: vec![],
         };
 
@@ -627,6 +735,8 @@ mod tests {
             rules_file: None,
             theme: None,
             model: None,
+            permissions: None,
+            hooks: None,
             extraction_errors: vec!["Error 1".to_string(), "Error 2".to_string()],
         };
 
@@ -637,6 +747,39 @@ mod tests {
         assert_eq!(errors_node.unwrap().children.len(), 2);
     }
 
+    #[test]
+    fn test_permissions_and_hooks_render_one_per_line() {
+        let info = ProfileInfo {
+            name: "work".to_string(),
+            harness_id: "claude-code".to_string(),
+            permissions: Some(PermissionRules {
+                allow: vec!["Bash(git:*)".to_string()],
+                deny: vec!["Read(./.env)".to_string()],
+                default_mode: Some("acceptEdits".to_string()),
+                ..PermissionRules::default()
+            }),
+            hooks: Some(vec![HookInfo {
+                event: "PreToolUse".to_string(),
+                matcher: Some("Bash".to_string()),
+                command: "./check.sh".to_string(),
+            }]),
+            ..ProfileInfo::default()
+        };
+
+        let text = nodes_to_text(&profile_to_nodes(&info));
+        assert!(text.contains(
+            "Permissions (2):\n  mode: acceptEdits\n  allow Bash(git:*)\n  deny Read(./.env)\n"
+        ));
+        assert!(text.contains("Hooks (1):\n  PreToolUse [Bash]: ./check.sh\n"));
+
+        let lines = nodes_to_lines(&profile_to_nodes(&info));
+        let deny = lines
+            .iter()
+            .find(|l| l.spans.iter().any(|s| s.content == "deny Read(./.env)"))
+            .unwrap();
+        assert_eq!(deny.spans[1].style.fg, Some(Color::Red));
+    }
+
     #[test]
     fn test_nodes_to_text_renders_header_and_fields() {
         let nodes = vec![
@@ -38,16 +38,20 @@ pub enum Error {
     #[error("invalid profile name: {0}")]
     InvalidProfileName(String),
 
+    /// Project directory passed with `--project` does not exist.
+    #[error("project directory not found: {0}")]
+    ProjectNotFound(String),
+
     /// Unknown harness name.
-    #[error("unknown harness: {0}\nValid options: claude-code, opencode, goose, amp-code")]
-    UnknownHarness(String),
+    #[error("unknown harness: {name}\nValid options: {valid}")]
+    UnknownHarness { name: String, valid: String },
 
     /// Command failed.
     #[error("{0}")]
     Command(String),
 
     /// Unknown configuration setting.
-    #[error("unknown setting: {0}\nValid options: editor, marker_files, default_harness")]
+    #[error("unknown setting: {0}\nValid options: profile_marker, registry, tui.view")]
     UnknownSetting(String),
 
     /// Invalid configuration value.
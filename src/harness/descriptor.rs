@@ -0,0 +1,649 @@
+//! Harnesses described by TOML files in `~/.config/bridle/harnesses/`.
+
+use std::path::{Path, PathBuf};
+
+use harness_locate::{DirectoryResource, DirectoryStructure, FileFormat, InstallationStatus};
+use serde::Deserialize;
+
+use super::HarnessConfig;
+use crate::config::jsonc::{self, strip_jsonc_comments};
+use crate::config::yaml;
+use crate::config::{BridleConfig, ProfileName, expand_home};
+use crate::error::{Error, Result};
+
+/// A setting inside one of the harness's config files.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SettingKey {
+    /// File relative to the config directory (JSON, JSONC, YAML, or TOML).
+    pub file: String,
+
+    /// A JSON pointer (`/mcpServers`) or a dotted path (`extensions`, `mcp.servers`).
+    pub key: String,
+}
+
+/// A harness bridle doesn't know about, described declaratively.
+///
+/// ```toml
+/// id = "cursor"
+/// name = "Cursor"
+/// config_dir = "~/.cursor"
+/// rules_file = "AGENTS.md"
+///
+/// [mcp]
+/// file = "mcp.json"
+/// key = "/mcpServers"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HarnessDescriptor {
+    /// Identifier used on the command line and as the profile directory name.
+    pub id: String,
+
+    /// Display name (defaults to the id).
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// Other names accepted on the command line.
+    #[serde(default)]
+    pub aliases: Vec<String>,
+
+    /// Executable looked up in PATH to detect the harness.
+    #[serde(default)]
+    pub binary: Option<String>,
+
+    /// Global config directory; a leading `~` expands to the home directory.
+    pub config_dir: String,
+
+    /// MCP server definitions.
+    #[serde(default)]
+    pub mcp: Option<SettingKey>,
+
+    /// Skills directory (one `<name>/SKILL.md` per skill), relative to the config directory.
+    #[serde(default)]
+    pub skills_dir: Option<String>,
+
+    /// Agents directory (`*.md`), relative to the config directory.
+    #[serde(default)]
+    pub agents_dir: Option<String>,
+
+    /// Commands directory, relative to the config directory.
+    #[serde(default)]
+    pub commands_dir: Option<String>,
+
+    /// File pattern for commands (defaults to `*.md`).
+    #[serde(default)]
+    pub commands_pattern: Option<String>,
+
+    /// Model setting.
+    #[serde(default)]
+    pub model: Option<SettingKey>,
+
+    /// Rules file name inside the config directory (e.g. `AGENTS.md`).
+    #[serde(default)]
+    pub rules_file: Option<String>,
+
+    /// Entries in the config directory the harness writes while it runs (e.g. `sessions`).
+    #[serde(default)]
+    pub runtime: Vec<String>,
+}
+
+impl HarnessDescriptor {
+    /// Parses a descriptor and checks its id can name a profile directory.
+    pub fn parse(content: &str) -> Result<Self> {
+        let descriptor: Self = toml::from_str(content)?;
+        match ProfileName::new(&descriptor.id) {
+            Ok(name) if name.as_str() == descriptor.id => Ok(descriptor),
+            _ => Err(Error::Config(format!(
+                "invalid harness id '{}': use lowercase letters, digits, and hyphens",
+                descriptor.id
+            ))),
+        }
+    }
+
+    /// Loads every `*.toml` descriptor, sorted by id.
+    pub fn load_all() -> Result<Vec<Self>> {
+        let dir = BridleConfig::harnesses_dir()?;
+        if !dir.is_dir() {
+            return Ok(Vec::new());
+        }
+
+        let mut descriptors = Vec::new();
+        for entry in std::fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if path.extension().is_none_or(|ext| ext != "toml") {
+                continue;
+            }
+            let content = std::fs::read_to_string(&path)?;
+            let descriptor = Self::parse(&content)
+                .map_err(|e| Error::Config(format!("{}: {e}", path.display())))?;
+            descriptors.push(descriptor);
+        }
+        descriptors.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(descriptors)
+    }
+
+    pub fn display_name(&self) -> &str {
+        self.name.as_deref().unwrap_or(&self.id)
+    }
+
+    fn config_path(&self) -> PathBuf {
+        expand_home(&self.config_dir)
+    }
+
+    fn directory(
+        &self,
+        relative: Option<&String>,
+        structure: DirectoryStructure,
+    ) -> Option<DirectoryResource> {
+        let path = self.config_path().join(relative?);
+        let file_format = match structure {
+            DirectoryStructure::Nested { .. } => FileFormat::MarkdownWithFrontmatter,
+            DirectoryStructure::Flat { .. } => FileFormat::Markdown,
+        };
+        Some(DirectoryResource {
+            exists: path.is_dir(),
+            path,
+            structure,
+            file_format,
+        })
+    }
+}
+
+fn markdown_files() -> DirectoryStructure {
+    DirectoryStructure::Flat {
+        file_pattern: "*.md".to_string(),
+    }
+}
+
+impl HarnessConfig for HarnessDescriptor {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn config_dir(&self) -> Result<PathBuf> {
+        Ok(self.config_path())
+    }
+
+    fn installation_status(&self) -> Result<InstallationStatus> {
+        let binary_path = match &self.binary {
+            Some(binary) => harness_locate::find_binary(binary)?,
+            None => None,
+        };
+        let config_path = Some(self.config_path()).filter(|p| p.exists());
+
+        Ok(match (binary_path, config_path) {
+            (Some(binary_path), Some(config_path)) => InstallationStatus::FullyInstalled {
+                binary_path,
+                config_path,
+            },
+            (Some(binary_path), None) => InstallationStatus::BinaryOnly { binary_path },
+            (None, Some(config_path)) => InstallationStatus::ConfigOnly { config_path },
+            (None, None) => InstallationStatus::NotInstalled,
+        })
+    }
+
+    fn mcp_filename(&self) -> Option<String> {
+        self.mcp_config_path()
+            .and_then(|p| p.file_name().map(|n| n.to_string_lossy().into_owned()))
+    }
+
+    fn mcp_config_path(&self) -> Option<PathBuf> {
+        self.mcp
+            .as_ref()
+            .map(|mcp| self.config_path().join(&mcp.file))
+    }
+
+    fn parse_mcp_servers(&self, content: &str, filename: &str) -> Result<Vec<(String, bool)>> {
+        let Some(mcp) = &self.mcp else {
+            return Ok(Vec::new());
+        };
+        let document = parse_document(content, filename)?;
+        let Some(servers) = lookup(&document, &mcp.key).and_then(|v| v.as_object()) else {
+            return Ok(Vec::new());
+        };
+
+        let mut result: Vec<(String, bool)> = servers
+            .iter()
+            .map(|(name, server)| {
+                let disabled = server.get("disabled").and_then(|v| v.as_bool()) == Some(true)
+                    || server.get("enabled").and_then(|v| v.as_bool()) == Some(false);
+                (name.clone(), !disabled)
+            })
+            .collect();
+        result.sort_by(|a, b| a.0.cmp(&b.0));
+        Ok(result)
+    }
+
+    fn runtime_entries(&self) -> Vec<String> {
+        self.runtime.clone()
+    }
+
+    fn skills_dir(&self) -> Result<Option<DirectoryResource>> {
+        Ok(self.directory(
+            self.skills_dir.as_ref(),
+            DirectoryStructure::Nested {
+                subdir_pattern: "*".to_string(),
+                file_name: "SKILL.md".to_string(),
+            },
+        ))
+    }
+
+    fn agents_dir(&self) -> Result<Option<DirectoryResource>> {
+        Ok(self.directory(self.agents_dir.as_ref(), markdown_files()))
+    }
+
+    fn commands_dir(&self) -> Result<Option<DirectoryResource>> {
+        let structure = match &self.commands_pattern {
+            Some(pattern) => DirectoryStructure::Flat {
+                file_pattern: pattern.clone(),
+            },
+            None => markdown_files(),
+        };
+        Ok(self.directory(self.commands_dir.as_ref(), structure))
+    }
+
+    fn rules_dir(&self) -> Result<Option<DirectoryResource>> {
+        Ok(self.rules_file.as_ref().map(|file| DirectoryResource {
+            path: self.config_path(),
+            exists: self.config_path().join(file).is_file(),
+            structure: DirectoryStructure::Flat {
+                file_pattern: file.clone(),
+            },
+            file_format: FileFormat::Markdown,
+        }))
+    }
+
+    fn rules_file(&self) -> Option<String> {
+        self.rules_file.clone()
+    }
+
+    fn profile_subdir(&self, canonical: &str) -> String {
+        let relative = match canonical {
+            "skills" => self.skills_dir.as_ref(),
+            "agents" => self.agents_dir.as_ref(),
+            "commands" => self.commands_dir.as_ref(),
+            _ => None,
+        };
+        relative.cloned().unwrap_or_else(|| canonical.to_string())
+    }
+
+    fn model_setting(&self) -> Option<SettingKey> {
+        self.model.clone()
+    }
+}
+
+/// Parses a config file by extension into a JSON value.
+pub fn parse_document(content: &str, filename: &str) -> Result<serde_json::Value> {
+    let extension = Path::new(filename)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or_default();
+    Ok(match extension {
+        "yaml" | "yml" => {
+            serde_json::to_value(serde_yaml::from_str::<serde_yaml::Value>(content)?)?
+        }
+        "toml" => toml::from_str(content)?,
+        _ => serde_json::from_str(&strip_jsonc_comments(content))?,
+    })
+}
+
+/// Resolves a JSON pointer (`/a/b`) or dotted path (`a.b`) in `document`.
+pub fn lookup<'a>(document: &'a serde_json::Value, key: &str) -> Option<&'a serde_json::Value> {
+    if key.starts_with('/') {
+        return document.pointer(key);
+    }
+    key.split('.')
+        .try_fold(document, |value, segment| value.get(segment))
+}
+
+/// Serializes `document` in the format of `filename`, the inverse of [`parse_document`].
+pub fn render_document(document: &serde_json::Value, filename: &str) -> Result<String> {
+    render_over(document, filename, None)
+}
+
+/// Renders `document` to replace `original`, the file's current content.
+///
+/// Keys keep their order, and only the members that changed are rewritten so
+/// comments survive: in JSON with comments, TOML, and block-style YAML. Other
+/// YAML is rewritten whole.
+fn render_over(
+    document: &serde_json::Value,
+    filename: &str,
+    original: Option<&str>,
+) -> Result<String> {
+    let extension = Path::new(filename)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or_default();
+    match extension {
+        "yaml" | "yml" => {
+            let patched = original.and_then(|original| {
+                let old = parse_document(original, filename).ok()?;
+                yaml::patch(original, &old, document)
+            });
+            match patched {
+                Some(patched) => Ok(patched),
+                None => Ok(serde_yaml::to_string(document)?),
+            }
+        }
+        "toml" => match original {
+            Some(original) => patch_toml(original, document),
+            None => toml::to_string_pretty(document).map_err(|e| Error::Config(e.to_string())),
+        },
+        _ => match original {
+            Some(original) if strip_jsonc_comments(original) != original => {
+                let old = parse_document(original, filename)?;
+                jsonc::patch(original, &old, document).ok_or_else(|| {
+                    Error::Config(format!(
+                        "{filename}: cannot rewrite without losing its comments"
+                    ))
+                })
+            }
+            _ => Ok(serde_json::to_string_pretty(document)?),
+        },
+    }
+}
+
+/// Rewrites `original` TOML so it parses to `document`, editing only the keys
+/// that changed.
+fn patch_toml(original: &str, document: &serde_json::Value) -> Result<String> {
+    let old = toml::from_str::<serde_json::Value>(original)?;
+    let mut edited: toml_edit::DocumentMut = original
+        .parse()
+        .map_err(|e: toml_edit::TomlError| Error::Config(e.to_string()))?;
+    let (Some(old), Some(new)) = (old.as_object(), document.as_object()) else {
+        return Err(Error::Config("TOML documents must be tables".to_string()));
+    };
+    patch_toml_table(edited.as_table_mut(), old, new)?;
+    Ok(edited.to_string())
+}
+
+fn patch_toml_table(
+    table: &mut dyn toml_edit::TableLike,
+    old: &serde_json::Map<String, serde_json::Value>,
+    new: &serde_json::Map<String, serde_json::Value>,
+) -> Result<()> {
+    for key in old.keys().filter(|k| !new.contains_key(*k)) {
+        table.remove(key);
+    }
+    for (key, value) in new {
+        let previous = old.get(key);
+        if previous == Some(value) {
+            continue;
+        }
+        if let (Some(serde_json::Value::Object(old_child)), serde_json::Value::Object(new_child)) =
+            (previous, value)
+            && let Some(child) = table.get_mut(key).and_then(|i| i.as_table_like_mut())
+        {
+            patch_toml_table(child, old_child, new_child)?;
+            continue;
+        }
+
+        let item = toml_item(value)?;
+        match table.get_mut(key) {
+            // Values stay inline and keep their trailing comment.
+            Some(slot) if slot.is_value() => {
+                let mut new_value = item
+                    .into_value()
+                    .map_err(|_| Error::Config(format!("cannot write {key} as TOML")))?;
+                if let Some(old_value) = slot.as_value() {
+                    *new_value.decor_mut() = old_value.decor().clone();
+                }
+                *slot = toml_edit::Item::Value(new_value);
+            }
+            Some(slot) => *slot = item,
+            None => {
+                table.insert(key, item);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Converts `value` to a TOML item, with objects as standard tables.
+fn toml_item(value: &serde_json::Value) -> Result<toml_edit::Item> {
+    let serde_json::Value::Object(map) = value else {
+        return toml_value(value).map(toml_edit::Item::Value);
+    };
+    let mut table = toml_edit::Table::new();
+    // Tables holding only other tables get no header of their own.
+    table.set_implicit(true);
+    table.decor_mut().set_prefix("\n");
+    for (key, value) in map {
+        table.insert(key, toml_item(value)?);
+    }
+    Ok(toml_edit::Item::Table(table))
+}
+
+fn toml_value(value: &serde_json::Value) -> Result<toml_edit::Value> {
+    Ok(match value {
+        serde_json::Value::Null => {
+            return Err(Error::Config("TOML has no null value".to_string()));
+        }
+        serde_json::Value::Bool(b) => (*b).into(),
+        serde_json::Value::Number(n) => match n.as_i64() {
+            Some(i) => i.into(),
+            None => n.as_f64().unwrap_or_default().into(),
+        },
+        serde_json::Value::String(s) => s.as_str().into(),
+        serde_json::Value::Array(items) => items
+            .iter()
+            .map(toml_value)
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .collect::<toml_edit::Array>()
+            .into(),
+        serde_json::Value::Object(map) => {
+            let mut table = toml_edit::InlineTable::new();
+            for (key, value) in map {
+                table.insert(key, toml_value(value)?);
+            }
+            table.into()
+        }
+    })
+}
+
+/// Writes `document` to `path` in the file's format, keeping the comments of
+/// the file it replaces. Creates parent directories.
+pub fn write_document(path: &Path, document: &serde_json::Value) -> Result<()> {
+    let filename = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or_default();
+    let original = match std::fs::read_to_string(path) {
+        Ok(content) => Some(content),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+        Err(e) => return Err(e.into()),
+    };
+    let content = render_over(document, filename, original.as_deref())?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, content)?;
+    Ok(())
+}
+
+/// Sets the value at a JSON pointer or dotted path, creating missing tables on the way.
+pub fn assign(document: &mut serde_json::Value, key: &str, value: serde_json::Value) -> Result<()> {
+    let segments: Vec<String> = match key.strip_prefix('/') {
+        Some(pointer) => pointer
+            .split('/')
+            .map(|s| s.replace("~1", "/").replace("~0", "~"))
+            .collect(),
+        None => key.split('.').map(String::from).collect(),
+    };
+    let Some((last, parents)) = segments.split_last() else {
+        return Err(Error::Config(format!("empty setting key: {key}")));
+    };
+
+    let mut current = document;
+    for segment in parents {
+        current = table(current, key)?
+            .entry(segment.clone())
+            .or_insert_with(|| serde_json::Value::Object(Default::default()));
+    }
+    table(current, key)?.insert(last.clone(), value);
+    Ok(())
+}
+
+fn table<'a>(
+    value: &'a mut serde_json::Value,
+    key: &str,
+) -> Result<&'a mut serde_json::Map<String, serde_json::Value>> {
+    if value.is_null() {
+        *value = serde_json::Value::Object(Default::default());
+    }
+    value
+        .as_object_mut()
+        .ok_or_else(|| Error::Config(format!("cannot set {key}: a parent is not a table")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CURSOR: &str = r#"
+        id = "cursor"
+        name = "Cursor"
+        aliases = ["cur"]
+        config_dir = "/opt/cursor"
+        skills_dir = "skills"
+        commands_dir = "prompts"
+        rules_file = "AGENTS.md"
+
+        [mcp]
+        file = "mcp.json"
+        key = "/mcpServers"
+
+        [model]
+        file = "cli-config.json"
+        key = "model.default"
+    "#;
+
+    #[test]
+    fn parses_descriptor_fields() {
+        let descriptor = HarnessDescriptor::parse(CURSOR).unwrap();
+        assert_eq!(descriptor.id, "cursor");
+        assert_eq!(descriptor.display_name(), "Cursor");
+        assert_eq!(
+            descriptor.mcp_config_path(),
+            Some(PathBuf::from("/opt/cursor/mcp.json"))
+        );
+        assert_eq!(descriptor.mcp_filename().as_deref(), Some("mcp.json"));
+        assert_eq!(descriptor.profile_subdir("commands"), "prompts");
+        assert_eq!(descriptor.profile_subdir("agents"), "agents");
+        assert!(descriptor.plugins_dir().unwrap().is_none());
+    }
+
+    #[test]
+    fn rejects_ids_that_cannot_name_a_directory() {
+        let err = HarnessDescriptor::parse(
+            r#"id = "My Tool"
+config_dir = "~/.tool""#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("invalid harness id"));
+
+        assert!(
+            HarnessDescriptor::parse(
+                r#"id = "tool"
+config_dir = "~/.tool"
+unknown = 1"#
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn parses_mcp_servers_by_pointer() {
+        let descriptor = HarnessDescriptor::parse(CURSOR).unwrap();
+        let servers = descriptor
+            .parse_mcp_servers(
+                r#"{"mcpServers": {"github": {}, "old": {"disabled": true}}}"#,
+                "mcp.json",
+            )
+            .unwrap();
+        assert_eq!(
+            servers,
+            [("github".to_string(), true), ("old".to_string(), false)]
+        );
+    }
+
+    #[test]
+    fn assign_creates_tables_and_round_trips() {
+        let mut document = parse_document("[tui]\ntheme = \"dark\"\n", "config.toml").unwrap();
+        assign(&mut document, "model.name", serde_json::json!("gpt-5")).unwrap();
+        let rendered = render_document(&document, "config.toml").unwrap();
+        let reparsed = parse_document(&rendered, "config.toml").unwrap();
+        assert_eq!(lookup(&reparsed, "model.name").unwrap(), "gpt-5");
+        assert_eq!(lookup(&reparsed, "tui.theme").unwrap(), "dark");
+
+        let mut document = serde_json::json!({"amp.model.default": "old"});
+        assign(
+            &mut document,
+            "/amp.model.default",
+            serde_json::json!("new"),
+        )
+        .unwrap();
+        assert_eq!(document, serde_json::json!({"amp.model.default": "new"}));
+
+        let mut scalar = serde_json::json!({"model": "x"});
+        assert!(assign(&mut scalar, "model.name", serde_json::json!("y")).is_err());
+    }
+
+    #[test]
+    fn toml_writes_keep_comments_and_untouched_keys() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("config.toml");
+        let original = r#"# Codex settings
+model = "gpt-5-codex" # day to day
+approval_policy = "never"
+
+# Servers I trust
+[mcp_servers.docs]
+command = "docs-mcp" # local build
+args = ["--stdio"]
+
+[tui]
+notifications = true
+"#;
+        std::fs::write(&path, original).unwrap();
+
+        let mut document = parse_document(original, "config.toml").unwrap();
+        assign(&mut document, "model", serde_json::json!("o3")).unwrap();
+        assign(
+            &mut document,
+            "mcp_servers.search",
+            serde_json::json!({"url": "https://search.example/mcp"}),
+        )
+        .unwrap();
+        document["tui"]
+            .as_object_mut()
+            .unwrap()
+            .shift_remove("notifications");
+        write_document(&path, &document).unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.starts_with("# Codex settings\nmodel = \"o3\" # day to day\n"));
+        assert!(content.contains("# Servers I trust\n[mcp_servers.docs]\n"));
+        assert!(content.contains("command = \"docs-mcp\" # local build"));
+        assert!(!content.contains("notifications"));
+        assert_eq!(parse_document(&content, "config.toml").unwrap(), document);
+    }
+
+    #[test]
+    fn lookup_supports_dotted_paths_in_yaml_and_toml() {
+        let yaml =
+            parse_document("extensions:\n  fetch:\n    enabled: false\n", "config.yaml").unwrap();
+        assert_eq!(
+            lookup(&yaml, "extensions.fetch.enabled"),
+            Some(&serde_json::Value::Bool(false))
+        );
+
+        let toml =
+            parse_document("[mcp_servers.docs]\ncommand = \"docs\"\n", "config.toml").unwrap();
+        assert!(lookup(&toml, "/mcp_servers/docs").is_some());
+        assert!(lookup(&toml, "mcp_servers.missing").is_none());
+    }
+}
@@ -5,15 +5,23 @@
 #![allow(dead_code)]
 #![allow(unused_imports)]
 
+mod descriptor;
 mod display;
+mod project;
+mod registry;
 
 use std::path::PathBuf;
 
-use harness_locate::{InstallationStatus, McpServer, Scope};
+use harness_locate::{DirectoryResource, InstallationStatus, McpServer, Scope};
 
 use crate::error::Result;
 
+pub use descriptor::{
+    HarnessDescriptor, SettingKey, assign, lookup, parse_document, render_document, write_document,
+};
 pub use display::DisplayInfo;
+pub use project::ProjectHarness;
+pub use registry::{Capabilities, HarnessEntry, HarnessRegistry};
 
 /// Configuration interface for AI coding assistant harnesses.
 ///
@@ -39,6 +47,70 @@ pub trait HarnessConfig {
     ///
     /// Returns a list of (server_name, enabled) pairs.
     fn parse_mcp_servers(&self, content: &str, filename: &str) -> Result<Vec<(String, bool)>>;
+
+    /// Returns the scope the config paths above resolve in.
+    fn scope(&self) -> Scope {
+        Scope::Global
+    }
+
+    /// Returns files outside the config directory, other than the MCP file,
+    /// that belong in a profile (e.g. a project's `AGENTS.md`).
+    fn extra_files(&self) -> Vec<PathBuf> {
+        Vec::new()
+    }
+
+    /// Returns entries of the config directory the harness writes while it runs
+    /// (sessions, logs, caches). They are not configuration, so they never count
+    /// as unsaved changes.
+    fn runtime_entries(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Returns the skills directory and how skills are laid out in it.
+    fn skills_dir(&self) -> Result<Option<DirectoryResource>> {
+        Ok(None)
+    }
+
+    /// Returns the agents directory and how agents are laid out in it.
+    fn agents_dir(&self) -> Result<Option<DirectoryResource>> {
+        Ok(None)
+    }
+
+    /// Returns the commands directory and how commands are laid out in it.
+    fn commands_dir(&self) -> Result<Option<DirectoryResource>> {
+        Ok(None)
+    }
+
+    /// Returns the plugins directory and how plugins are laid out in it.
+    fn plugins_dir(&self) -> Result<Option<DirectoryResource>> {
+        Ok(None)
+    }
+
+    /// Returns the directory holding the rules file, with the file name as its pattern.
+    fn rules_dir(&self) -> Result<Option<DirectoryResource>> {
+        Ok(None)
+    }
+
+    /// Returns the name of the rules file the harness reads (e.g. `CLAUDE.md`).
+    fn rules_file(&self) -> Option<String> {
+        None
+    }
+
+    /// Returns where a resource directory (`skills`, `agents`, `commands`) is kept
+    /// inside a profile. Built-in harnesses store them under the canonical name.
+    fn profile_subdir(&self, canonical: &str) -> String {
+        canonical.to_string()
+    }
+
+    /// Returns the file and key holding the model.
+    fn model_setting(&self) -> Option<SettingKey> {
+        None
+    }
+
+    /// Returns the file and key holding the model provider, if kept apart from the model.
+    fn provider_setting(&self) -> Option<SettingKey> {
+        None
+    }
 }
 
 fn mcp_server_enabled(server: &McpServer) -> bool {
@@ -51,13 +123,7 @@ fn mcp_server_enabled(server: &McpServer) -> bool {
 
 impl HarnessConfig for harness_locate::Harness {
     fn id(&self) -> &'static str {
-        match self.kind() {
-            harness_locate::HarnessKind::ClaudeCode => "claude-code",
-            harness_locate::HarnessKind::OpenCode => "opencode",
-            harness_locate::HarnessKind::Goose => "goose",
-            harness_locate::HarnessKind::AmpCode => "amp-code",
-            _ => "unknown",
-        }
+        HarnessRegistry::id(self.kind())
     }
 
     fn config_dir(&self) -> Result<PathBuf> {
@@ -81,6 +147,45 @@ impl HarnessConfig for harness_locate::Harness {
         self.mcp(&Scope::Global).ok().flatten().map(|r| r.file)
     }
 
+    fn skills_dir(&self) -> Result<Option<DirectoryResource>> {
+        Ok(self.skills(&Scope::Global)?)
+    }
+
+    fn agents_dir(&self) -> Result<Option<DirectoryResource>> {
+        Ok(self.agents(&Scope::Global)?)
+    }
+
+    fn commands_dir(&self) -> Result<Option<DirectoryResource>> {
+        Ok(self.commands(&Scope::Global)?)
+    }
+
+    fn plugins_dir(&self) -> Result<Option<DirectoryResource>> {
+        Ok(self.plugins(&Scope::Global)?)
+    }
+
+    fn rules_dir(&self) -> Result<Option<DirectoryResource>> {
+        Ok(self.rules(&Scope::Global)?)
+    }
+
+    fn rules_file(&self) -> Option<String> {
+        HarnessRegistry::rules_file(self.kind()).map(String::from)
+    }
+
+    fn runtime_entries(&self) -> Vec<String> {
+        HarnessRegistry::runtime_entries(self.kind())
+            .iter()
+            .map(|e| e.to_string())
+            .collect()
+    }
+
+    fn model_setting(&self) -> Option<SettingKey> {
+        HarnessRegistry::model_setting(self.kind())
+    }
+
+    fn provider_setting(&self) -> Option<SettingKey> {
+        HarnessRegistry::provider_setting(self.kind())
+    }
+
     fn parse_mcp_servers(&self, content: &str, filename: &str) -> Result<Vec<(String, bool)>> {
         let is_yaml = filename.ends_with(".yaml") || filename.ends_with(".yml");
         let mut parsed: serde_json::Value = if is_yaml {
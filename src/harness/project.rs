@@ -0,0 +1,110 @@
+//! Project-scoped view of a harness.
+
+use std::path::{Path, PathBuf};
+
+use harness_locate::{DirectoryResource, Harness, InstallationStatus, Scope};
+
+use super::{HarnessConfig, HarnessRegistry, SettingKey};
+use crate::error::Result;
+
+/// A harness whose config lives in a project checkout (`.claude/`, `.mcp.json`, ...)
+/// rather than the user's global config directory.
+pub struct ProjectHarness {
+    harness: Harness,
+    root: PathBuf,
+}
+
+impl ProjectHarness {
+    pub fn new(harness: Harness, root: impl Into<PathBuf>) -> Self {
+        Self {
+            harness,
+            root: root.into(),
+        }
+    }
+
+    pub fn harness(&self) -> &Harness {
+        &self.harness
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// The instruction file the harness reads from the project root.
+    fn instructions_file(&self) -> Option<&'static str> {
+        HarnessRegistry::rules_file(self.harness.kind())
+    }
+}
+
+impl HarnessConfig for ProjectHarness {
+    fn id(&self) -> &str {
+        self.harness.id()
+    }
+
+    fn config_dir(&self) -> Result<PathBuf> {
+        Ok(self.harness.config(&self.scope())?)
+    }
+
+    fn installation_status(&self) -> Result<InstallationStatus> {
+        HarnessConfig::installation_status(&self.harness)
+    }
+
+    fn mcp_filename(&self) -> Option<String> {
+        self.mcp_config_path()
+            .and_then(|p| p.file_name().map(|n| n.to_string_lossy().into_owned()))
+    }
+
+    fn mcp_config_path(&self) -> Option<PathBuf> {
+        self.harness
+            .mcp(&self.scope())
+            .ok()
+            .flatten()
+            .map(|r| r.file)
+    }
+
+    fn parse_mcp_servers(&self, content: &str, filename: &str) -> Result<Vec<(String, bool)>> {
+        self.harness.parse_mcp_servers(content, filename)
+    }
+
+    fn scope(&self) -> Scope {
+        Scope::Project(self.root.clone())
+    }
+
+    fn rules_file(&self) -> Option<String> {
+        self.instructions_file().map(String::from)
+    }
+
+    fn model_setting(&self) -> Option<SettingKey> {
+        HarnessConfig::model_setting(&self.harness)
+    }
+
+    fn provider_setting(&self) -> Option<SettingKey> {
+        HarnessConfig::provider_setting(&self.harness)
+    }
+
+    fn extra_files(&self) -> Vec<PathBuf> {
+        self.instructions_file()
+            .map(|name| vec![self.root.join(name)])
+            .unwrap_or_default()
+    }
+
+    fn skills_dir(&self) -> Result<Option<DirectoryResource>> {
+        Ok(self.harness.skills(&self.scope())?)
+    }
+
+    fn agents_dir(&self) -> Result<Option<DirectoryResource>> {
+        Ok(self.harness.agents(&self.scope())?)
+    }
+
+    fn commands_dir(&self) -> Result<Option<DirectoryResource>> {
+        Ok(self.harness.commands(&self.scope())?)
+    }
+
+    fn plugins_dir(&self) -> Result<Option<DirectoryResource>> {
+        Ok(self.harness.plugins(&self.scope())?)
+    }
+
+    fn rules_dir(&self) -> Result<Option<DirectoryResource>> {
+        Ok(self.harness.rules(&self.scope())?)
+    }
+}
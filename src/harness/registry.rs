@@ -0,0 +1,476 @@
+//! The harnesses bridle can manage: built-ins plus descriptor harnesses.
+
+use harness_locate::{Harness, HarnessKind, InstallationStatus};
+use serde::Serialize;
+
+use super::{HarnessConfig, HarnessDescriptor, SettingKey};
+use crate::error::{Error, Result};
+
+/// What bridle can manage for a harness.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub struct Capabilities {
+    pub skills: bool,
+    pub agents: bool,
+    pub commands: bool,
+    pub mcp: bool,
+    pub rules: bool,
+    pub themes: bool,
+}
+
+impl Capabilities {
+    /// Each capability with its name, in display order.
+    pub fn flags(&self) -> [(&'static str, bool); 6] {
+        [
+            ("skills", self.skills),
+            ("agents", self.agents),
+            ("commands", self.commands),
+            ("mcp", self.mcp),
+            ("rules", self.rules),
+            ("themes", self.themes),
+        ]
+    }
+}
+
+struct Builtin {
+    kind: HarnessKind,
+    id: &'static str,
+    name: &'static str,
+    aliases: &'static [&'static str],
+    /// The instructions file the harness reads, in its config dir or a project root.
+    rules_file: &'static str,
+    /// File and key holding the model, as in a descriptor's `[model]`.
+    model: (&'static str, &'static str),
+    /// File and key holding the model provider, for harnesses that keep it separately.
+    provider: Option<(&'static str, &'static str)>,
+    /// Config-dir entries the harness writes while it runs.
+    runtime: &'static [&'static str],
+    capabilities: Capabilities,
+}
+
+const BUILTINS: [Builtin; 4] = [
+    Builtin {
+        kind: HarnessKind::ClaudeCode,
+        id: "claude-code",
+        name: "Claude Code",
+        aliases: &["claude", "cc"],
+        rules_file: "CLAUDE.md",
+        model: ("settings.json", "model"),
+        provider: None,
+        runtime: &[
+            "debug",
+            "file-history",
+            "history.jsonl",
+            "ide",
+            "projects",
+            "session-env",
+            "shell-snapshots",
+            "statsig",
+            "todos",
+        ],
+        capabilities: Capabilities {
+            skills: true,
+            agents: true,
+            commands: true,
+            mcp: true,
+            rules: true,
+            themes: true,
+        },
+    },
+    Builtin {
+        kind: HarnessKind::OpenCode,
+        id: "opencode",
+        name: "OpenCode",
+        aliases: &["oc"],
+        rules_file: "AGENTS.md",
+        model: ("opencode.jsonc", "model"),
+        provider: None,
+        runtime: &[],
+        capabilities: Capabilities {
+            skills: true,
+            agents: true,
+            commands: true,
+            mcp: true,
+            rules: true,
+            themes: true,
+        },
+    },
+    Builtin {
+        kind: HarnessKind::Goose,
+        id: "goose",
+        name: "Goose",
+        aliases: &[],
+        rules_file: ".goosehints",
+        model: ("config.yaml", "GOOSE_MODEL"),
+        provider: Some(("config.yaml", "GOOSE_PROVIDER")),
+        runtime: &[],
+        capabilities: Capabilities {
+            skills: true,
+            agents: false,
+            commands: false,
+            mcp: true,
+            rules: true,
+            themes: true,
+        },
+    },
+    Builtin {
+        kind: HarnessKind::AmpCode,
+        id: "amp-code",
+        name: "AMP Code",
+        aliases: &["amp", "ampcode"],
+        rules_file: "AGENTS.md",
+        // Amp settings are flat dotted names, so a pointer selects the whole key.
+        model: ("settings.json", "/amp.model.default"),
+        provider: None,
+        runtime: &[],
+        capabilities: Capabilities {
+            skills: true,
+            agents: false,
+            commands: true,
+            mcp: true,
+            rules: true,
+            themes: true,
+        },
+    },
+];
+
+/// Built-in harnesses harness-locate has no kind for, described like custom ones.
+const BUILTIN_DESCRIPTORS: [(&str, Capabilities); 2] = [
+    (
+        r#"
+        id = "codex"
+        name = "Codex CLI"
+        binary = "codex"
+        config_dir = "~/.codex"
+        commands_dir = "prompts"
+        rules_file = "AGENTS.md"
+        runtime = ["history.jsonl", "log", "sessions"]
+
+        [mcp]
+        file = "config.toml"
+        key = "mcp_servers"
+
+        [model]
+        file = "config.toml"
+        key = "model"
+        "#,
+        // Agents are installed as custom prompts.
+        Capabilities {
+            skills: false,
+            agents: true,
+            commands: true,
+            mcp: true,
+            rules: true,
+            themes: false,
+        },
+    ),
+    (
+        r#"
+        id = "gemini"
+        name = "Gemini CLI"
+        aliases = ["gemini-cli"]
+        binary = "gemini"
+        config_dir = "~/.gemini"
+        commands_dir = "commands"
+        commands_pattern = "*.toml"
+        rules_file = "GEMINI.md"
+        runtime = ["tmp"]
+
+        [mcp]
+        file = "settings.json"
+        key = "/mcpServers"
+
+        [model]
+        file = "settings.json"
+        key = "model.name"
+        "#,
+        // Markdown commands are translated to TOML at install time.
+        Capabilities {
+            skills: false,
+            agents: false,
+            commands: true,
+            mcp: true,
+            rules: true,
+            themes: false,
+        },
+    ),
+];
+
+/// A harness known to the registry.
+#[derive(Debug, Clone, Serialize)]
+pub struct HarnessEntry {
+    pub id: String,
+    pub name: String,
+    pub aliases: Vec<String>,
+    /// Whether the harness ships with bridle rather than coming from a descriptor.
+    pub builtin: bool,
+    pub capabilities: Capabilities,
+    #[serde(skip)]
+    pub kind: Option<HarnessKind>,
+    #[serde(skip)]
+    pub descriptor: Option<HarnessDescriptor>,
+}
+
+impl HarnessEntry {
+    fn matches(&self, name: &str) -> bool {
+        self.id == name || self.aliases.iter().any(|a| a == name)
+    }
+
+    /// The harness's global config, from its built-in kind or its descriptor.
+    pub fn config(&self) -> Box<dyn HarnessConfig> {
+        match (self.kind, &self.descriptor) {
+            (Some(kind), _) => Box::new(Harness::new(kind)),
+            (None, Some(descriptor)) => Box::new(descriptor.clone()),
+            (None, None) => unreachable!("registry entries have a kind or a descriptor"),
+        }
+    }
+
+    /// Whether the harness's binary or config directory is present.
+    pub fn is_installed(&self) -> bool {
+        match self.kind {
+            Some(kind) => Harness::locate(kind).is_ok(),
+            None => self
+                .config()
+                .installation_status()
+                .is_ok_and(|status| !matches!(status, InstallationStatus::NotInstalled)),
+        }
+    }
+}
+
+/// Ids, aliases, display names, and capabilities of every manageable harness.
+pub struct HarnessRegistry {
+    entries: Vec<HarnessEntry>,
+}
+
+impl HarnessRegistry {
+    /// The built-in harnesses only. Never touches the filesystem.
+    pub fn builtin() -> Self {
+        let kinds = BUILTINS.iter().map(|b| HarnessEntry {
+            id: b.id.to_string(),
+            name: b.name.to_string(),
+            aliases: b.aliases.iter().map(|a| a.to_string()).collect(),
+            builtin: true,
+            capabilities: b.capabilities,
+            kind: Some(b.kind),
+            descriptor: None,
+        });
+        let descriptors = BUILTIN_DESCRIPTORS.iter().map(|(source, capabilities)| {
+            let descriptor =
+                HarnessDescriptor::parse(source).expect("built-in descriptors are valid");
+            HarnessEntry {
+                id: descriptor.id.clone(),
+                name: descriptor.display_name().to_string(),
+                aliases: descriptor.aliases.clone(),
+                builtin: true,
+                capabilities: *capabilities,
+                kind: None,
+                descriptor: Some(descriptor),
+            }
+        });
+        Self {
+            entries: kinds.chain(descriptors).collect(),
+        }
+    }
+
+    /// The built-in harnesses followed by descriptors from `~/.config/bridle/harnesses/`.
+    ///
+    /// # Errors
+    /// Fails if a descriptor can't be read or reuses a known id or alias.
+    pub fn load() -> Result<Self> {
+        let mut registry = Self::builtin();
+        for descriptor in HarnessDescriptor::load_all()? {
+            registry.add(descriptor)?;
+        }
+        Ok(registry)
+    }
+
+    fn add(&mut self, descriptor: HarnessDescriptor) -> Result<()> {
+        let names = std::iter::once(&descriptor.id).chain(&descriptor.aliases);
+        for name in names {
+            if let Some(existing) = self.find(name) {
+                return Err(Error::Config(format!(
+                    "harness descriptor '{}' reuses '{name}', already taken by {}",
+                    descriptor.id, existing.id
+                )));
+            }
+        }
+        self.entries.push(HarnessEntry {
+            id: descriptor.id.clone(),
+            name: descriptor.display_name().to_string(),
+            aliases: descriptor.aliases.clone(),
+            builtin: false,
+            capabilities: Capabilities {
+                skills: descriptor.skills_dir.is_some(),
+                agents: descriptor.agents_dir.is_some(),
+                commands: descriptor.commands_dir.is_some(),
+                mcp: descriptor.mcp.is_some(),
+                rules: descriptor.rules_file.is_some(),
+                themes: false,
+            },
+            kind: None,
+            descriptor: Some(descriptor),
+        });
+        Ok(())
+    }
+
+    pub fn entries(&self) -> &[HarnessEntry] {
+        &self.entries
+    }
+
+    /// Finds a harness by id or alias.
+    pub fn find(&self, name: &str) -> Option<&HarnessEntry> {
+        self.entries.iter().find(|e| e.matches(name))
+    }
+
+    /// Like [`Self::find`], failing with an error that lists the valid ids.
+    pub fn get(&self, name: &str) -> Result<&HarnessEntry> {
+        self.find(name).ok_or_else(|| Error::UnknownHarness {
+            name: name.to_string(),
+            valid: self
+                .entries
+                .iter()
+                .map(|e| e.id.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+        })
+    }
+
+    /// Resolves a built-in harness id or alias.
+    pub fn kind(name: &str) -> Option<HarnessKind> {
+        BUILTINS
+            .iter()
+            .find(|b| b.id == name || b.aliases.contains(&name))
+            .map(|b| b.kind)
+    }
+
+    /// The id of a built-in harness.
+    pub fn id(kind: HarnessKind) -> &'static str {
+        Self::builtin_entry(kind).map_or("unknown", |b| b.id)
+    }
+
+    /// The display name of a built-in harness.
+    pub fn name(kind: HarnessKind) -> &'static str {
+        Self::builtin_entry(kind).map_or("Unknown", |b| b.name)
+    }
+
+    /// The rules file name of a built-in harness.
+    pub fn rules_file(kind: HarnessKind) -> Option<&'static str> {
+        Self::builtin_entry(kind).map(|b| b.rules_file)
+    }
+
+    /// Config-dir entries a built-in harness writes while it runs.
+    pub fn runtime_entries(kind: HarnessKind) -> &'static [&'static str] {
+        Self::builtin_entry(kind).map_or(&[], |b| b.runtime)
+    }
+
+    /// The file and key holding the model of a built-in harness.
+    pub fn model_setting(kind: HarnessKind) -> Option<SettingKey> {
+        Self::builtin_entry(kind).map(|b| setting(b.model))
+    }
+
+    /// The file and key holding the model provider of a built-in harness, if separate.
+    pub fn provider_setting(kind: HarnessKind) -> Option<SettingKey> {
+        Self::builtin_entry(kind).and_then(|b| b.provider.map(setting))
+    }
+
+    fn builtin_entry(kind: HarnessKind) -> Option<&'static Builtin> {
+        BUILTINS.iter().find(|b| b.kind == kind)
+    }
+}
+
+fn setting((file, key): (&str, &str)) -> SettingKey {
+    SettingKey {
+        file: file.to_string(),
+        key: key.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_ids_and_aliases_resolve() {
+        assert_eq!(HarnessRegistry::kind("cc"), Some(HarnessKind::ClaudeCode));
+        assert_eq!(HarnessRegistry::kind("amp"), Some(HarnessKind::AmpCode));
+        assert_eq!(HarnessRegistry::kind("cursor"), None);
+        assert_eq!(HarnessRegistry::id(HarnessKind::OpenCode), "opencode");
+        assert_eq!(HarnessRegistry::name(HarnessKind::Goose), "Goose");
+
+        let registry = HarnessRegistry::builtin();
+        assert_eq!(registry.find("claude").unwrap().id, "claude-code");
+        assert!(!registry.find("goose").unwrap().capabilities.agents);
+    }
+
+    #[test]
+    fn every_builtin_kind_is_registered() {
+        for kind in HarnessKind::ALL {
+            assert_ne!(HarnessRegistry::id(*kind), "unknown");
+        }
+    }
+
+    #[test]
+    fn codex_and_gemini_are_builtin_descriptors() {
+        let registry = HarnessRegistry::builtin();
+        let codex = registry.find("codex").unwrap();
+        assert!(codex.builtin);
+        assert_eq!(codex.kind, None);
+        assert!(codex.capabilities.agents);
+        assert!(!codex.capabilities.themes);
+
+        let config = codex.config();
+        assert_eq!(config.profile_subdir("commands"), "prompts");
+        assert_eq!(
+            config.mcp_config_path(),
+            Some(crate::config::expand_home("~/.codex/config.toml"))
+        );
+        assert_eq!(HarnessRegistry::kind("codex"), None);
+
+        let gemini = registry.find("gemini-cli").unwrap();
+        assert_eq!(gemini.id, "gemini");
+        let commands = gemini.config().commands_dir().unwrap().unwrap();
+        assert_eq!(
+            commands.structure,
+            harness_locate::DirectoryStructure::Flat {
+                file_pattern: "*.toml".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn unknown_harness_lists_valid_ids() {
+        let err = HarnessRegistry::builtin().get("vim").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "unknown harness: vim\nValid options: claude-code, opencode, goose, amp-code, codex, gemini"
+        );
+    }
+
+    #[test]
+    fn descriptors_cannot_shadow_existing_names() {
+        let mut registry = HarnessRegistry::builtin();
+        let descriptor = HarnessDescriptor::parse(
+            r#"
+            id = "cursor"
+            aliases = ["cc"]
+            config_dir = "~/.cursor"
+            "#,
+        )
+        .unwrap();
+        let err = registry.add(descriptor).unwrap_err();
+        assert!(err.to_string().contains("already taken by claude-code"));
+
+        let descriptor = HarnessDescriptor::parse(
+            r#"
+            id = "cursor"
+            config_dir = "~/.cursor"
+            skills_dir = "skills"
+            "#,
+        )
+        .unwrap();
+        registry.add(descriptor).unwrap();
+        let entry = registry.find("cursor").unwrap();
+        assert!(!entry.builtin);
+        assert!(entry.capabilities.skills);
+        assert!(!entry.capabilities.mcp);
+    }
+}
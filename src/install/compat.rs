@@ -0,0 +1,61 @@
+//! Which harnesses can take which kinds of component.
+
+use std::path::Path;
+
+use harness_locate::{HarnessKind, Severity, validate_agent_for_harness};
+
+use super::types::AgentInfo;
+use crate::harness::{Capabilities, HarnessEntry, HarnessRegistry};
+
+/// Whether the harness is installed and its registry entry has `capability`.
+fn harness_supports(harness_id: &str, capability: fn(&Capabilities) -> bool) -> bool {
+    HarnessRegistry::builtin()
+        .find(harness_id)
+        .filter(|entry| capability(&entry.capabilities))
+        .is_some_and(|entry| entry.is_installed())
+}
+
+pub fn harness_supports_skills(harness_id: &str) -> bool {
+    harness_supports(harness_id, |c| c.skills)
+}
+
+pub fn harness_supports_agents(harness_id: &str) -> bool {
+    harness_supports(harness_id, |c| c.agents)
+}
+
+pub fn harness_supports_commands(harness_id: &str) -> bool {
+    harness_supports(harness_id, |c| c.commands)
+}
+
+pub fn harness_supports_mcp(harness_id: &str) -> bool {
+    harness_supports(harness_id, |c| c.mcp)
+}
+
+/// Agents harness-locate rejects for `kind`. Harnesses without a kind
+/// (Codex) translate agents instead, so none are incompatible.
+pub fn count_incompatible_agents<'a>(
+    agents: impl Iterator<Item = &'a AgentInfo>,
+    kind: Option<HarnessKind>,
+) -> usize {
+    let Some(kind) = kind else {
+        return 0;
+    };
+    agents
+        .filter(|a| {
+            let issues = validate_agent_for_harness(&a.content, kind);
+            issues.iter().any(|i| i.severity == Severity::Error)
+        })
+        .count()
+}
+
+/// Installed built-in harnesses, in registry order. Harnesses without a
+/// built-in kind have no project scope, so they are left out with `--project`.
+pub fn install_harnesses(project: Option<&Path>) -> Vec<HarnessEntry> {
+    HarnessRegistry::builtin()
+        .entries()
+        .iter()
+        .filter(|entry| project.is_none() || entry.kind.is_some())
+        .filter(|entry| entry.is_installed())
+        .cloned()
+        .collect()
+}
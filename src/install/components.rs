@@ -0,0 +1,257 @@
+//! Operations on components already present in a profile.
+//!
+//! Used by the TUI component browser to preview, toggle, and copy items
+//! between profiles of the same harness.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+use super::installer::{
+    InstallError, InstallOutcome, component_dir, install_agent, install_command, install_skill,
+};
+use super::manifest::{InstallManifest, manifest_path};
+use super::mcp_config::{McpConfigError, live_mcp_paths, mcp_store};
+use super::types::{
+    AgentInfo, CommandInfo, ComponentType, Dependency, DependencyKind, InstallOptions,
+    InstallTarget, SkillInfo,
+};
+use crate::config::BridleConfig;
+
+#[derive(Debug, Error)]
+pub enum ComponentError {
+    #[error("Profile directory not found for {harness}/{profile}")]
+    ProfileNotFound { harness: String, profile: String },
+
+    #[error("Component not found: {0}")]
+    NotFound(String),
+
+    #[error("{0} already exists in the target profile")]
+    AlreadyExists(String),
+
+    #[error("Failed to read component: {0}")]
+    Read(#[source] std::io::Error),
+
+    #[error(transparent)]
+    Install(#[from] InstallError),
+
+    #[error(transparent)]
+    McpConfig(#[from] McpConfigError),
+}
+
+/// The file holding an installed component inside `profile_dir`.
+///
+/// Skills resolve to their `SKILL.md`; agents and commands to `<name>.md` (or
+/// another recipe extension for Goose, `<name>.toml` for Gemini, under
+/// `prompts/` for Codex); MCP servers to the config file that lists them.
+pub fn component_file(
+    profile_dir: &Path,
+    harness_id: &str,
+    component: &Dependency,
+) -> Option<PathBuf> {
+    let name = component.name.as_str();
+    let candidates = match component.kind {
+        DependencyKind::Skill => {
+            let dir = profile_dir.join("skills");
+            vec![
+                dir.join(name).join("SKILL.md"),
+                dir.join(format!("{}.md", name)),
+            ]
+        }
+        DependencyKind::Agent => vec![
+            profile_dir
+                .join(component_dir(harness_id, ComponentType::Agent))
+                .join(format!("{}.md", name)),
+        ],
+        DependencyKind::Command => [component_dir(harness_id, ComponentType::Command), "recipes"]
+            .iter()
+            .flat_map(|dir| {
+                ["md", "toml", "yaml", "yml", "json"]
+                    .iter()
+                    .map(move |ext| profile_dir.join(dir).join(format!("{}.{}", name, ext)))
+            })
+            .collect(),
+        DependencyKind::Mcp => vec![profile_dir.join(mcp_store(harness_id).ok()?.file)],
+    };
+    candidates.into_iter().find(|p| p.is_file())
+}
+
+/// Switches an MCP server on or off in a profile, and in the live harness
+/// config when the profile is active.
+pub fn set_mcp_enabled(
+    target: &InstallTarget,
+    server_name: &str,
+    enabled: bool,
+) -> Result<(), ComponentError> {
+    let profile_dir = profile_dir(target)?;
+    let store = mcp_store(&target.harness)?;
+
+    if !store.set_enabled(&profile_dir.join(store.file), server_name, enabled)? {
+        return Err(ComponentError::NotFound(server_name.to_string()));
+    }
+
+    for path in live_mcp_paths(target) {
+        store.set_enabled(&path, server_name, enabled)?;
+    }
+    Ok(())
+}
+
+/// Copies a component from one profile to another of the same harness.
+///
+/// Files go through the installer so the target's live config is updated when
+/// it is active, and manifest provenance travels with the component.
+pub fn copy_component(
+    component: &Dependency,
+    from: &InstallTarget,
+    to: &InstallTarget,
+) -> Result<PathBuf, ComponentError> {
+    let from_dir = profile_dir(from)?;
+    let to_dir = profile_dir(to)?;
+    let not_found = || ComponentError::NotFound(component.to_string());
+
+    let manifest = InstallManifest::load(&manifest_path(&from_dir)).unwrap_or_default();
+    let provenance = manifest.find_component(component.kind, &component.name);
+
+    if component.kind == DependencyKind::Mcp {
+        let store = mcp_store(&to.harness)?;
+        let entry = store
+            .get(&from_dir.join(store.file), &component.name)?
+            .ok_or_else(not_found)?;
+        let target_config = to_dir.join(store.file);
+        if store.get(&target_config, &component.name)?.is_some() {
+            return Err(ComponentError::AlreadyExists(component.to_string()));
+        }
+        store.insert(&target_config, &component.name, entry.clone())?;
+        for path in live_mcp_paths(to) {
+            store.insert(&path, &component.name, entry.clone())?;
+        }
+        if let Some(provenance) = provenance {
+            let manifest_file = manifest_path(&to_dir);
+            let mut target_manifest = InstallManifest::load(&manifest_file).unwrap_or_default();
+            target_manifest.add_entry(provenance.clone());
+            let _ = target_manifest.save(&manifest_file);
+        }
+        return Ok(target_config);
+    }
+
+    let file = component_file(&from_dir, &from.harness, component).ok_or_else(not_found)?;
+    let content = fs::read_to_string(&file).map_err(ComponentError::Read)?;
+    let source = provenance.map(|e| &e.source);
+    let requires = provenance.map(|e| e.requires.clone()).unwrap_or_default();
+
+    let options = InstallOptions::default();
+    let name = component.name.clone();
+    let path = file.to_string_lossy().to_string();
+    let outcome = match component.kind {
+        DependencyKind::Skill => install_skill(
+            &SkillInfo {
+                name,
+                description: None,
+                path,
+                content,
+                requires,
+            },
+            to,
+            &options,
+            source,
+        )?,
+        DependencyKind::Agent => install_agent(
+            &AgentInfo {
+                name,
+                description: None,
+                path,
+                content,
+                requires,
+            },
+            to,
+            &options,
+            source,
+        )?,
+        DependencyKind::Command => install_command(
+            &CommandInfo {
+                name,
+                description: None,
+                path,
+                content,
+                requires,
+            },
+            to,
+            &options,
+            source,
+        )?,
+        DependencyKind::Mcp => unreachable!("handled above"),
+    };
+
+    match outcome {
+        InstallOutcome::Installed(success) => Ok(success.profile_path),
+        InstallOutcome::Skipped(_) => Err(ComponentError::AlreadyExists(component.to_string())),
+    }
+}
+
+fn profile_dir(target: &InstallTarget) -> Result<PathBuf, ComponentError> {
+    let not_found = || ComponentError::ProfileNotFound {
+        harness: target.harness.clone(),
+        profile: target.profile.as_str().to_string(),
+    };
+    let dir = BridleConfig::profiles_dir_for(&target.scope())
+        .map_err(|_| not_found())?
+        .join(&target.harness)
+        .join(target.profile.as_str());
+    if dir.exists() {
+        Ok(dir)
+    } else {
+        Err(not_found())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn component_file_resolves_each_kind() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path();
+        fs::create_dir_all(dir.join("skills/helper")).unwrap();
+        fs::write(dir.join("skills/helper/SKILL.md"), "skill").unwrap();
+        fs::create_dir_all(dir.join("agents")).unwrap();
+        fs::write(dir.join("agents/reviewer.md"), "agent").unwrap();
+        fs::create_dir_all(dir.join("recipes")).unwrap();
+        fs::write(dir.join("recipes/deploy.yaml"), "recipe").unwrap();
+        fs::write(dir.join("opencode.jsonc"), "{}").unwrap();
+
+        let file = |kind, name| component_file(dir, "opencode", &Dependency::new(kind, name));
+        assert_eq!(
+            file(DependencyKind::Skill, "helper"),
+            Some(dir.join("skills/helper/SKILL.md"))
+        );
+        assert_eq!(
+            file(DependencyKind::Agent, "reviewer"),
+            Some(dir.join("agents/reviewer.md"))
+        );
+        assert_eq!(
+            file(DependencyKind::Command, "deploy"),
+            Some(dir.join("recipes/deploy.yaml"))
+        );
+        assert_eq!(
+            file(DependencyKind::Mcp, "fs"),
+            Some(dir.join("opencode.jsonc"))
+        );
+        assert_eq!(file(DependencyKind::Agent, "missing"), None);
+
+        fs::create_dir_all(dir.join("commands")).unwrap();
+        fs::write(dir.join("commands/review.toml"), "prompt = \"Review\"").unwrap();
+        assert_eq!(
+            component_file(dir, "gemini", &Dependency::parse("command:review").unwrap()),
+            Some(dir.join("commands/review.toml"))
+        );
+        fs::create_dir_all(dir.join("prompts")).unwrap();
+        fs::write(dir.join("prompts/ship.md"), "Ship $1").unwrap();
+        assert_eq!(
+            component_file(dir, "codex", &Dependency::parse("command:ship").unwrap()),
+            Some(dir.join("prompts/ship.md"))
+        );
+    }
+}
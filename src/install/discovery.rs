@@ -2,10 +2,16 @@
 //!
 //! Wraps the `skills-locate` crate to discover installable skills.
 
+use std::path::Path;
+
 use skills_locate::{GitHubRef, extract_file, fetch_bytes, list_files, parse_skill_descriptor};
 use thiserror::Error;
 
-use super::types::{AgentInfo, CommandInfo, DiscoveryResult, McpInfo, SkillInfo, SourceInfo};
+use super::types::{
+    AgentInfo, CommandInfo, Dependency, DiscoveryResult, McpInfo, SkillInfo, SourceInfo,
+};
+
+const GITHUB_URL: &str = "https://github.com";
 
 #[derive(Debug, Error)]
 pub enum DiscoveryError {
@@ -19,20 +25,74 @@ pub enum DiscoveryError {
     NoSkillsFound,
 }
 
+/// Parses an install source into a repository reference and optional subpath.
+///
+/// Accepts full GitHub URLs (including `/tree/<ref>`) and `owner/repo`
+/// shorthand, either of which may be followed by `//<subpath>` to limit
+/// discovery to a directory and `@<ref>` to pick a branch or tag, e.g.
+/// `owner/repo//skills/foo@v1.2`.
+pub fn parse_source(source: &str) -> Result<(GitHubRef, Option<String>), DiscoveryError> {
+    let source = source.trim();
+    let path = match source
+        .strip_prefix("https://github.com/")
+        .or_else(|| source.strip_prefix("http://github.com/"))
+    {
+        Some(path) => path,
+        None if source.contains("://") => {
+            return Err(DiscoveryError::InvalidUrl(format!(
+                "not a GitHub URL: {source}"
+            )));
+        }
+        None => source,
+    };
+
+    let (path, ref_override) = match path.rsplit_once('@') {
+        Some((path, git_ref)) if !git_ref.is_empty() => (path, Some(git_ref)),
+        _ => (path, None),
+    };
+
+    let (repo_path, subpath) = match path.split_once("//") {
+        Some((repo_path, subpath)) => {
+            let subpath = subpath.trim_matches('/');
+            (
+                repo_path,
+                (!subpath.is_empty()).then(|| subpath.to_string()),
+            )
+        }
+        None => (path, None),
+    };
+
+    let mut github_ref = GitHubRef::parse(&format!("https://github.com/{repo_path}"))
+        .map_err(|e| DiscoveryError::InvalidUrl(e.to_string()))?;
+    if let Some(git_ref) = ref_override {
+        github_ref.git_ref = git_ref.to_string();
+    }
+
+    Ok((github_ref, subpath))
+}
+
 pub fn discover_skills(url: &str) -> Result<DiscoveryResult, DiscoveryError> {
-    let github_ref =
-        GitHubRef::parse(url).map_err(|e| DiscoveryError::InvalidUrl(e.to_string()))?;
+    let (github_ref, subpath) = parse_source(url)?;
 
     let source = SourceInfo {
         owner: github_ref.owner.clone(),
         repo: github_ref.repo.clone(),
         git_ref: Some(github_ref.git_ref.clone()),
+        subpath: subpath.clone(),
     };
 
-    let archive_url = github_ref.archive_url();
-    let zip_bytes = fetch_bytes(&archive_url).map_err(DiscoveryError::FetchError)?;
+    let zip_bytes = fetch_bytes(&archive_url(&github_ref)).map_err(DiscoveryError::FetchError)?;
+
+    // Restricts every listing below to the requested subdirectory, if any.
+    let list = |suffix: &str| -> Result<Vec<String>, DiscoveryError> {
+        let paths = list_files(&zip_bytes, suffix).map_err(DiscoveryError::FetchError)?;
+        Ok(paths
+            .into_iter()
+            .filter(|p| within_subpath(&normalize_archive_path(p, &github_ref), subpath.as_deref()))
+            .collect())
+    };
 
-    let skill_paths = list_files(&zip_bytes, "SKILL.md").map_err(DiscoveryError::FetchError)?;
+    let skill_paths = list("SKILL.md")?;
 
     let mut skills = Vec::new();
     for path in skill_paths {
@@ -46,15 +106,20 @@ pub fn discover_skills(url: &str) -> Result<DiscoveryResult, DiscoveryError> {
             Err(_) => continue,
         };
 
+        let requires = parse_yaml_frontmatter(&content, Some(&descriptor.name))
+            .map(|meta| meta.requires)
+            .unwrap_or_default();
+
         skills.push(SkillInfo {
             name: descriptor.name,
             description: descriptor.description,
             path: normalize_archive_path(&path, &github_ref),
             content,
+            requires,
         });
     }
 
-    let mcp_paths = list_files(&zip_bytes, ".mcp.json").map_err(DiscoveryError::FetchError)?;
+    let mcp_paths = list(".mcp.json")?;
 
     let mut mcp_servers = Vec::new();
     for path in mcp_paths {
@@ -67,7 +132,7 @@ pub fn discover_skills(url: &str) -> Result<DiscoveryResult, DiscoveryError> {
     }
 
     // Discover agents from AGENT.md files (legacy format)
-    let agent_paths = list_files(&zip_bytes, "AGENT.md").map_err(DiscoveryError::FetchError)?;
+    let agent_paths = list("AGENT.md")?;
 
     let mut agents = Vec::new();
     for path in agent_paths {
@@ -78,8 +143,9 @@ pub fn discover_skills(url: &str) -> Result<DiscoveryResult, DiscoveryError> {
 
         if let Some(agent) = parse_agent_frontmatter(&content, &path) {
             agents.push(AgentInfo {
-                name: agent.0,
-                description: agent.1,
+                name: agent.name,
+                description: agent.description,
+                requires: agent.requires,
                 path: normalize_archive_path(&path, &github_ref),
                 content,
             });
@@ -87,7 +153,7 @@ pub fn discover_skills(url: &str) -> Result<DiscoveryResult, DiscoveryError> {
     }
 
     // Discover agents from */agents/*.md directories (claude-code format)
-    let all_md_paths = list_files(&zip_bytes, ".md").map_err(DiscoveryError::FetchError)?;
+    let all_md_paths = list(".md")?;
     for path in &all_md_paths {
         if !is_in_agents_dir(path) {
             continue;
@@ -99,8 +165,9 @@ pub fn discover_skills(url: &str) -> Result<DiscoveryResult, DiscoveryError> {
 
         if let Some(agent) = parse_agent_frontmatter(&content, path) {
             agents.push(AgentInfo {
-                name: agent.0,
-                description: agent.1,
+                name: agent.name,
+                description: agent.description,
+                requires: agent.requires,
                 path: normalize_archive_path(path, &github_ref),
                 content,
             });
@@ -108,7 +175,7 @@ pub fn discover_skills(url: &str) -> Result<DiscoveryResult, DiscoveryError> {
     }
 
     // Discover commands from COMMAND.md files (legacy format)
-    let command_paths = list_files(&zip_bytes, "COMMAND.md").map_err(DiscoveryError::FetchError)?;
+    let command_paths = list("COMMAND.md")?;
 
     let mut commands = Vec::new();
     for path in command_paths {
@@ -119,8 +186,9 @@ pub fn discover_skills(url: &str) -> Result<DiscoveryResult, DiscoveryError> {
 
         if let Some(cmd) = parse_command_frontmatter(&content, &path) {
             commands.push(CommandInfo {
-                name: cmd.0,
-                description: cmd.1,
+                name: cmd.name,
+                description: cmd.description,
+                requires: cmd.requires,
                 path: normalize_archive_path(&path, &github_ref),
                 content,
             });
@@ -139,8 +207,9 @@ pub fn discover_skills(url: &str) -> Result<DiscoveryResult, DiscoveryError> {
 
         if let Some(cmd) = parse_command_frontmatter(&content, path) {
             commands.push(CommandInfo {
-                name: cmd.0,
-                description: cmd.1,
+                name: cmd.name,
+                description: cmd.description,
+                requires: cmd.requires,
                 path: normalize_archive_path(path, &github_ref),
                 content,
             });
@@ -160,6 +229,55 @@ pub fn discover_skills(url: &str) -> Result<DiscoveryResult, DiscoveryError> {
     })
 }
 
+/// Agents and commands in a local directory laid out like a Claude Code
+/// plugin: `agents/*.md` and `commands/*.md`. Unreadable files are skipped.
+pub fn discover_local_components(dir: &Path) -> (Vec<AgentInfo>, Vec<CommandInfo>) {
+    let markdown = |subdir: &str| -> Vec<(String, String)> {
+        let Ok(entries) = std::fs::read_dir(dir.join(subdir)) else {
+            return Vec::new();
+        };
+        let mut files: Vec<(String, String)> = entries
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.is_file() && p.extension().is_some_and(|ext| ext == "md"))
+            .filter_map(|p| {
+                let content = std::fs::read_to_string(&p).ok()?;
+                Some((p.display().to_string(), content))
+            })
+            .collect();
+        files.sort();
+        files
+    };
+
+    let agents = markdown("agents")
+        .into_iter()
+        .filter_map(|(path, content)| {
+            let meta = parse_agent_frontmatter(&content, &path)?;
+            Some(AgentInfo {
+                name: meta.name,
+                description: meta.description,
+                requires: meta.requires,
+                path,
+                content,
+            })
+        })
+        .collect();
+    let commands = markdown("commands")
+        .into_iter()
+        .filter_map(|(path, content)| {
+            let meta = parse_command_frontmatter(&content, &path)?;
+            Some(CommandInfo {
+                name: meta.name,
+                description: meta.description,
+                requires: meta.requires,
+                path,
+                content,
+            })
+        })
+        .collect();
+    (agents, commands)
+}
+
 fn parse_mcp_json(content: &str) -> Vec<McpInfo> {
     use serde::Deserialize;
     use std::collections::HashMap;
@@ -230,49 +348,115 @@ fn parse_mcp_json(content: &str) -> Vec<McpInfo> {
     }
 }
 
-fn parse_agent_frontmatter(content: &str, path: &str) -> Option<(String, Option<String>)> {
+/// Metadata read from a component's YAML frontmatter.
+struct ComponentMeta {
+    name: String,
+    description: Option<String>,
+    requires: Vec<Dependency>,
+}
+
+fn parse_agent_frontmatter(content: &str, path: &str) -> Option<ComponentMeta> {
     parse_yaml_frontmatter(content, filename_stem(path))
 }
 
-fn parse_command_frontmatter(content: &str, path: &str) -> Option<(String, Option<String>)> {
+fn parse_command_frontmatter(content: &str, path: &str) -> Option<ComponentMeta> {
     parse_yaml_frontmatter(content, filename_stem(path))
 }
 
-fn parse_yaml_frontmatter(
-    content: &str,
-    fallback_name: Option<&str>,
-) -> Option<(String, Option<String>)> {
+fn parse_yaml_frontmatter(content: &str, fallback_name: Option<&str>) -> Option<ComponentMeta> {
     let content = content.trim();
     if !content.starts_with("---") {
-        return fallback_name.map(|n| (n.to_string(), None));
+        return fallback_name.map(|n| ComponentMeta {
+            name: n.to_string(),
+            description: None,
+            requires: Vec::new(),
+        });
     }
 
     let end = content[3..].find("---")?;
     let yaml_content = &content[3..3 + end];
 
+    /// `requires:` may be a single entry or a list.
+    #[derive(serde::Deserialize)]
+    #[serde(untagged)]
+    enum Requires {
+        One(String),
+        Many(Vec<String>),
+    }
+
     #[derive(serde::Deserialize)]
     struct Frontmatter {
         name: Option<String>,
         description: Option<String>,
+        requires: Option<Requires>,
     }
 
     let fm: Frontmatter = serde_yaml::from_str(yaml_content).ok()?;
     let name = fm.name.or_else(|| fallback_name.map(String::from))?;
-    Some((name, fm.description))
+    let requires = match fm.requires {
+        Some(Requires::One(entry)) => vec![entry],
+        Some(Requires::Many(entries)) => entries,
+        None => Vec::new(),
+    };
+    Some(ComponentMeta {
+        name,
+        description: fm.description,
+        requires: requires
+            .iter()
+            .filter_map(|r| Dependency::parse(r))
+            .collect(),
+    })
 }
 
 fn filename_stem(path: &str) -> Option<&str> {
     path.rsplit('/').next()?.strip_suffix(".md")
 }
 
+/// Expands `owner/repo` shorthand to a GitHub URL; URLs are returned unchanged.
+pub fn normalize_source(source: &str) -> String {
+    if source.starts_with("http://") || source.starts_with("https://") {
+        source.to_string()
+    } else if source.contains('/') && !source.contains(':') {
+        format!("{GITHUB_URL}/{source}")
+    } else {
+        source.to_string()
+    }
+}
+
+/// Archive URL that resolves branches, tags, and commit SHAs alike.
+///
+/// `BRIDLE_GITHUB_URL` replaces `https://github.com`, e.g. to fetch through a mirror.
+fn archive_url(github_ref: &GitHubRef) -> String {
+    let base = std::env::var("BRIDLE_GITHUB_URL").unwrap_or_else(|_| GITHUB_URL.to_string());
+    format!(
+        "{}/{}/{}/archive/{}.zip",
+        base.trim_end_matches('/'),
+        github_ref.owner,
+        github_ref.repo,
+        github_ref.git_ref
+    )
+}
+
 fn normalize_archive_path(archive_path: &str, github_ref: &GitHubRef) -> String {
-    let prefix = format!("{}-{}/", github_ref.repo, github_ref.git_ref);
-    archive_path
-        .strip_prefix(&prefix)
+    // GitHub names the archive root `<repo>-<ref>`, with `/` in the ref
+    // replaced by `-` and a leading `v` dropped from version tags.
+    let git_ref = github_ref.git_ref.replace('/', "-");
+    [git_ref.as_str(), git_ref.trim_start_matches('v')]
+        .iter()
+        .find_map(|r| archive_path.strip_prefix(&format!("{}-{}/", github_ref.repo, r)))
         .unwrap_or(archive_path)
         .to_string()
 }
 
+fn within_subpath(path: &str, subpath: Option<&str>) -> bool {
+    match subpath {
+        None => true,
+        Some(dir) => path
+            .strip_prefix(dir)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('/')),
+    }
+}
+
 fn is_in_agents_dir(path: &str) -> bool {
     path.contains("/agents/") && path.ends_with(".md") && !path.ends_with("AGENT.md")
 }
@@ -284,6 +468,7 @@ fn is_in_commands_dir(path: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::install::types::DependencyKind;
 
     #[test]
     fn discover_skills_invalid_url() {
@@ -318,6 +503,113 @@ mod tests {
         );
     }
 
+    #[test]
+    fn normalize_path_handles_version_tag() {
+        let (github_ref, _) = parse_source("owner/repo@v1.2").unwrap();
+        assert_eq!(
+            normalize_archive_path("repo-1.2/skills/foo/SKILL.md", &github_ref),
+            "skills/foo/SKILL.md"
+        );
+    }
+
+    #[test]
+    fn parse_source_shorthand() {
+        let (github_ref, subpath) = parse_source("owner/repo").unwrap();
+        assert_eq!(github_ref.owner, "owner");
+        assert_eq!(github_ref.repo, "repo");
+        assert_eq!(github_ref.git_ref, "main");
+        assert!(subpath.is_none());
+    }
+
+    #[test]
+    fn parse_source_subpath_and_ref() {
+        let (github_ref, subpath) = parse_source("owner/repo//skills/foo@v1.2").unwrap();
+        assert_eq!(github_ref.repo, "repo");
+        assert_eq!(github_ref.git_ref, "v1.2");
+        assert_eq!(subpath.as_deref(), Some("skills/foo"));
+    }
+
+    #[test]
+    fn parse_source_full_url_with_subpath() {
+        let (github_ref, subpath) =
+            parse_source("https://github.com/owner/repo/tree/dev//plugins/x/").unwrap();
+        assert_eq!(github_ref.git_ref, "dev");
+        assert_eq!(subpath.as_deref(), Some("plugins/x"));
+    }
+
+    #[test]
+    fn parse_source_rejects_other_hosts() {
+        assert!(matches!(
+            parse_source("https://gitlab.com/owner/repo//skills"),
+            Err(DiscoveryError::InvalidUrl(_))
+        ));
+        assert!(parse_source("owner").is_err());
+    }
+
+    #[test]
+    fn within_subpath_matches_whole_components() {
+        assert!(within_subpath("skills/foo/SKILL.md", None));
+        assert!(within_subpath("skills/foo/SKILL.md", Some("skills/foo")));
+        assert!(!within_subpath(
+            "skills/foobar/SKILL.md",
+            Some("skills/foo")
+        ));
+        assert!(!within_subpath("agents/foo.md", Some("skills")));
+    }
+
+    #[test]
+    fn frontmatter_parses_requires_list() {
+        let content = "---\nname: reviewer\nrequires: [mcp:github, skill:memory-safety]\n---\nBody";
+        let meta = parse_agent_frontmatter(content, "agents/reviewer.md").unwrap();
+        assert_eq!(meta.name, "reviewer");
+        assert_eq!(
+            meta.requires,
+            vec![
+                Dependency::new(DependencyKind::Mcp, "github"),
+                Dependency::new(DependencyKind::Skill, "memory-safety"),
+            ]
+        );
+    }
+
+    #[test]
+    fn frontmatter_parses_single_requires() {
+        let content = "---\ndescription: Deploys\nrequires: command:build\n---\n";
+        let meta = parse_command_frontmatter(content, "commands/deploy.md").unwrap();
+        assert_eq!(meta.name, "deploy");
+        assert_eq!(
+            meta.requires,
+            vec![Dependency::new(DependencyKind::Command, "build")]
+        );
+    }
+
+    #[test]
+    fn frontmatter_without_requires() {
+        let meta = parse_yaml_frontmatter("# No frontmatter", Some("plain")).unwrap();
+        assert!(meta.requires.is_empty());
+    }
+
+    #[test]
+    fn local_components_come_from_agents_and_commands_dirs() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let dir = temp.path();
+        std::fs::create_dir_all(dir.join("agents")).unwrap();
+        std::fs::create_dir_all(dir.join("commands")).unwrap();
+        std::fs::write(
+            dir.join("agents/reviewer.md"),
+            "---\nname: code-reviewer\ndescription: Reviews\n---\nBody\n",
+        )
+        .unwrap();
+        std::fs::write(dir.join("agents/notes.txt"), "ignored").unwrap();
+        std::fs::write(dir.join("commands/check.md"), "Run checks.\n").unwrap();
+
+        let (agents, commands) = discover_local_components(dir);
+        assert_eq!(agents.len(), 1);
+        assert_eq!(agents[0].name, "code-reviewer");
+        assert_eq!(agents[0].description.as_deref(), Some("Reviews"));
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].name, "check");
+    }
+
     #[test]
     fn parse_mcp_wrapper_format() {
         let content = r#"{
@@ -368,4 +660,24 @@ mod tests {
             Err(e) => panic!("Unexpected error: {e}"),
         }
     }
+
+    #[test]
+    fn normalize_source_handles_shorthand() {
+        assert_eq!(
+            normalize_source("owner/repo"),
+            "https://github.com/owner/repo"
+        );
+    }
+
+    #[test]
+    fn normalize_source_preserves_full_url() {
+        let url = "https://github.com/owner/repo";
+        assert_eq!(normalize_source(url), url);
+    }
+
+    #[test]
+    fn normalize_source_preserves_http() {
+        let url = "http://example.com/repo";
+        assert_eq!(normalize_source(url), url);
+    }
 }
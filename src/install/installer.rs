@@ -3,17 +3,20 @@
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use serde::Serialize;
 use thiserror::Error;
 
 use harness_locate::{Harness, HarnessKind, Scope};
 
 use super::manifest::{InstallManifest, ManifestEntry, manifest_path};
+use super::mcp_config::{McpConfigError, live_mcp_paths, mcp_store};
 use super::types::{
-    AgentInfo, CommandInfo, ComponentType, InstallFailure, InstallOptions, InstallReport,
-    InstallSkip, InstallSuccess, InstallTarget, SkillInfo, SkipReason, SourceInfo,
+    AgentInfo, CommandInfo, ComponentType, Dependency, DependencyKind, InstallFailure,
+    InstallOptions, InstallReport, InstallSkip, InstallSuccess, InstallTarget, McpInfo, SkillInfo,
+    SkipReason, SourceInfo,
 };
 use crate::config::BridleConfig;
-use crate::harness::HarnessConfig;
+use crate::harness::{HarnessConfig, HarnessRegistry};
 
 #[derive(Debug, Error)]
 pub enum InstallError {
@@ -31,6 +34,9 @@ pub enum InstallError {
 
     #[error("Invalid component name: {0}")]
     InvalidComponentName(String),
+
+    #[error(transparent)]
+    McpConfig(#[from] McpConfigError),
 }
 
 fn validate_component_name(name: &str) -> Result<(), InstallError> {
@@ -46,16 +52,6 @@ fn validate_component_name(name: &str) -> Result<(), InstallError> {
     Ok(())
 }
 
-fn parse_harness_kind(id: &str) -> Option<HarnessKind> {
-    match id {
-        "claude-code" | "claude" | "cc" => Some(HarnessKind::ClaudeCode),
-        "opencode" | "oc" => Some(HarnessKind::OpenCode),
-        "goose" => Some(HarnessKind::Goose),
-        "amp-code" | "amp" | "ampcode" => Some(HarnessKind::AmpCode),
-        _ => None,
-    }
-}
-
 pub fn sanitize_name_for_opencode(name: &str) -> String {
     name.to_lowercase()
         .chars()
@@ -190,6 +186,136 @@ fn transform_agent_for_opencode(content: &str) -> String {
     format!("---\n{}\n---{}", new_frontmatter.trim_end(), body)
 }
 
+/// Front matter keys Codex custom prompts understand.
+const CODEX_PROMPT_KEYS: [&str; 2] = ["description", "argument-hint"];
+
+/// Drops front matter keys Codex doesn't read (`allowed-tools`, `model`, ...),
+/// along with any indented lines that continue them.
+fn filter_frontmatter_for_codex(frontmatter: &str) -> String {
+    let mut kept = String::new();
+    let mut keep = false;
+    for line in frontmatter.lines() {
+        if !line.starts_with([' ', '\t', '-']) {
+            let key = line.split_once(':').map(|(k, _)| k.trim()).unwrap_or("");
+            keep = CODEX_PROMPT_KEYS.contains(&key);
+        }
+        if keep {
+            kept.push_str(line);
+            kept.push('\n');
+        }
+    }
+    kept
+}
+
+/// Turns a command into a Codex custom prompt.
+///
+/// Codex expands `$ARGUMENTS` and `$1`..`$9` like the other harnesses, so only
+/// the front matter needs trimming.
+pub fn transform_command_for_codex(content: &str) -> String {
+    let parts: Vec<&str> = content.splitn(3, "---").collect();
+    if parts.len() < 3 || !parts[0].trim().is_empty() {
+        return content.to_string();
+    }
+
+    let frontmatter = filter_frontmatter_for_codex(parts[1]);
+    if frontmatter.is_empty() {
+        return parts[2].trim_start().to_string();
+    }
+    format!("---\n{}---{}", frontmatter, parts[2])
+}
+
+/// Turns an agent into a Codex custom prompt, since Codex has no agents.
+///
+/// The agent's instructions become the prompt, followed by `$ARGUMENTS` so
+/// `/prompts:<agent> <task>` hands the task over.
+pub fn transform_agent_for_codex(content: &str) -> String {
+    let prompt = transform_command_for_codex(content);
+    if prompt.contains("$ARGUMENTS") {
+        return prompt;
+    }
+    format!("{}\n\n$ARGUMENTS\n", prompt.trim_end())
+}
+
+/// Codex keeps both commands and agents as custom prompts in this directory.
+const CODEX_PROMPTS_DIR: &str = "prompts";
+
+fn is_codex(target: &InstallTarget) -> bool {
+    target.harness == "codex"
+}
+
+/// Directory holding components of `component_type` in a profile of `harness_id`.
+pub fn component_dir(harness_id: &str, component_type: ComponentType) -> &'static str {
+    match component_type {
+        ComponentType::Agent | ComponentType::Command if harness_id == "codex" => CODEX_PROMPTS_DIR,
+        _ => component_type.dir_name(),
+    }
+}
+
+/// A Gemini CLI custom command (`commands/<name>.toml`).
+#[derive(Serialize)]
+struct GeminiCommand {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    prompt: String,
+}
+
+/// Rewrites Claude-style ``!`cmd` `` shell injections as Gemini's `!{cmd}`.
+fn translate_shell_injections(body: &str) -> String {
+    let mut translated = String::new();
+    let mut rest = body;
+    while let Some(start) = rest.find("!`") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find('`') else {
+            break;
+        };
+        translated.push_str(&rest[..start]);
+        translated.push_str(&format!("!{{{}}}", &after[..end]));
+        rest = &after[end + 1..];
+    }
+    translated.push_str(rest);
+    translated
+}
+
+/// Converts a markdown command into Gemini's TOML command format.
+///
+/// The front matter `description` is kept, `$ARGUMENTS` becomes `{{args}}`,
+/// and shell injections are rewritten. Other front matter is dropped.
+pub fn transform_command_for_gemini(content: &str) -> String {
+    let parts: Vec<&str> = content.splitn(3, "---").collect();
+    let (frontmatter, body) = if parts.len() == 3 && parts[0].trim().is_empty() {
+        (parts[1], parts[2])
+    } else {
+        ("", content)
+    };
+
+    let description = frontmatter.lines().find_map(|line| {
+        let value = line.strip_prefix("description:")?.trim();
+        let value = value.trim_matches('"').trim_matches('\'');
+        (!value.is_empty()).then(|| value.to_string())
+    });
+    let prompt = translate_shell_injections(body.trim_start()).replace("$ARGUMENTS", "{{args}}");
+
+    toml::to_string(&GeminiCommand {
+        description,
+        prompt,
+    })
+    .expect("strings always serialize to TOML")
+}
+
+fn is_gemini(target: &InstallTarget) -> bool {
+    target.harness == "gemini"
+}
+
+/// The command as a Gemini TOML command; `.toml` files, such as ones copied
+/// from another Gemini profile, already are and are kept verbatim.
+fn gemini_command_content(command: &CommandInfo) -> String {
+    if command.path.ends_with(".toml") {
+        command.content.clone()
+    } else {
+        transform_command_for_gemini(&command.content)
+    }
+}
+
 /// Canonical directory name for agents in profile storage.
 const CANONICAL_AGENTS_DIR: &str = "agents";
 
@@ -200,13 +326,16 @@ pub fn install_skill(
     skill: &SkillInfo,
     target: &InstallTarget,
     options: &InstallOptions,
+    source: Option<&SourceInfo>,
 ) -> InstallResult {
-    let profiles_dir = BridleConfig::profiles_dir().map_err(|_| InstallError::ProfileNotFound {
-        harness: target.harness.clone(),
-        profile: target.profile.as_str().to_string(),
+    let profiles_dir = BridleConfig::profiles_dir_for(&target.scope()).map_err(|_| {
+        InstallError::ProfileNotFound {
+            harness: target.harness.clone(),
+            profile: target.profile.as_str().to_string(),
+        }
     })?;
 
-    install_skill_to_dir(skill, target, options, &profiles_dir)
+    install_skill_to_dir_with_source(skill, target, options, &profiles_dir, source)
 }
 
 fn install_skill_to_dir(
@@ -240,7 +369,7 @@ fn install_skill_to_dir_with_source(
 
     // For OpenCode, sanitize skill name and content before writing to profile
     // This ensures consistency between profile and harness (both use sanitized names)
-    let kind = parse_harness_kind(&target.harness);
+    let kind = HarnessRegistry::kind(&target.harness);
     let (skill_name, skill_content) = if matches!(kind, Some(HarnessKind::OpenCode)) {
         let sanitized = sanitize_name_for_opencode(&skill.name);
         let transformed = transform_skill_for_opencode(&skill.content, &sanitized);
@@ -264,7 +393,13 @@ fn install_skill_to_dir_with_source(
     fs::write(&skill_path, &skill_content).map_err(InstallError::WriteFile)?;
 
     if let Some(source_info) = source {
-        update_manifest(&profile_dir, ComponentType::Skill, &skill_name, source_info);
+        update_manifest(
+            &profile_dir,
+            DependencyKind::Skill,
+            &skill_name,
+            source_info,
+            &skill.requires,
+        );
     }
 
     let skill_for_harness = SkillInfo {
@@ -272,6 +407,7 @@ fn install_skill_to_dir_with_source(
         description: skill.description.clone(),
         path: skill.path.clone(),
         content: skill_content,
+        requires: skill.requires.clone(),
     };
     let harness_path = write_to_harness_if_active(target, &skill_for_harness)?;
 
@@ -290,7 +426,7 @@ fn write_to_harness_if_active(
     let config = BridleConfig::load().ok();
     let is_active = config
         .as_ref()
-        .and_then(|c| c.active_profile_for(&target.harness))
+        .and_then(|c| c.active_profile_in(&target.scope(), &target.harness))
         .map(|active| active == target.profile.as_str())
         .unwrap_or(false);
 
@@ -298,19 +434,20 @@ fn write_to_harness_if_active(
         return Ok(None);
     }
 
-    let kind = parse_harness_kind(&target.harness)
+    let kind = HarnessRegistry::kind(&target.harness)
         .ok_or_else(|| InstallError::HarnessNotFound(target.harness.clone()))?;
     let harness =
         Harness::locate(kind).map_err(|_| InstallError::HarnessNotFound(target.harness.clone()))?;
 
+    let scope = target.scope();
     let skills_dir = harness
-        .skills(&Scope::Global)
+        .skills(&scope)
         .ok()
         .flatten()
         .map(|r| r.path)
         .unwrap_or_else(|| {
             harness
-                .config_dir()
+                .config(&scope)
                 .map(|d| d.join("skills"))
                 .unwrap_or_default()
         });
@@ -337,7 +474,7 @@ fn write_agent_to_harness_if_active(
     let config = BridleConfig::load().ok();
     let is_active = config
         .as_ref()
-        .and_then(|c| c.active_profile_for(&target.harness))
+        .and_then(|c| c.active_profile_in(&target.scope(), &target.harness))
         .map(|active| active == target.profile.as_str())
         .unwrap_or(false);
 
@@ -345,13 +482,18 @@ fn write_agent_to_harness_if_active(
         return Ok(None);
     }
 
-    let kind = parse_harness_kind(&target.harness)
+    if is_codex(target) {
+        let content = transform_agent_for_codex(&agent.content);
+        return write_descriptor_command(target, &format!("{}.md", agent.name), &content).map(Some);
+    }
+
+    let kind = HarnessRegistry::kind(&target.harness)
         .ok_or_else(|| InstallError::HarnessNotFound(target.harness.clone()))?;
     let harness =
         Harness::locate(kind).map_err(|_| InstallError::HarnessNotFound(target.harness.clone()))?;
 
     // Check if harness supports agents - skip harness write if not
-    let Some(agents_resource) = harness.agents(&Scope::Global).ok().flatten() else {
+    let Some(agents_resource) = harness.agents(&target.scope()).ok().flatten() else {
         return Ok(None);
     };
     let harness_agent_path = agents_resource.path.join(format!("{}.md", &agent.name));
@@ -377,7 +519,7 @@ fn write_command_to_harness_if_active(
     let config = BridleConfig::load().ok();
     let is_active = config
         .as_ref()
-        .and_then(|c| c.active_profile_for(&target.harness))
+        .and_then(|c| c.active_profile_in(&target.scope(), &target.harness))
         .map(|active| active == target.profile.as_str())
         .unwrap_or(false);
 
@@ -385,12 +527,23 @@ fn write_command_to_harness_if_active(
         return Ok(None);
     }
 
-    let kind = parse_harness_kind(&target.harness)
+    if is_codex(target) {
+        let content = transform_command_for_codex(&command.content);
+        return write_descriptor_command(target, &format!("{}.md", command.name), &content)
+            .map(Some);
+    }
+    if is_gemini(target) {
+        let content = gemini_command_content(command);
+        return write_descriptor_command(target, &format!("{}.toml", command.name), &content)
+            .map(Some);
+    }
+
+    let kind = HarnessRegistry::kind(&target.harness)
         .ok_or_else(|| InstallError::HarnessNotFound(target.harness.clone()))?;
     let harness =
         Harness::locate(kind).map_err(|_| InstallError::HarnessNotFound(target.harness.clone()))?;
 
-    let Some(commands_resource) = harness.commands(&Scope::Global).ok().flatten() else {
+    let Some(commands_resource) = harness.commands(&target.scope()).ok().flatten() else {
         return Ok(None);
     };
     let harness_command_path = commands_resource.path.join(format!("{}.md", &command.name));
@@ -403,11 +556,33 @@ fn write_command_to_harness_if_active(
     Ok(Some(harness_command_path))
 }
 
+/// Writes into the commands directory of a harness bridle describes itself
+/// (Codex prompts, Gemini commands).
+fn write_descriptor_command(
+    target: &InstallTarget,
+    file_name: &str,
+    content: &str,
+) -> Result<PathBuf, InstallError> {
+    let registry = HarnessRegistry::builtin();
+    let commands_dir = registry
+        .find(&target.harness)
+        .and_then(|entry| entry.config().commands_dir().ok().flatten())
+        .ok_or_else(|| InstallError::HarnessNotFound(target.harness.clone()))?
+        .path;
+    let command_path = commands_dir.join(file_name);
+
+    fs::create_dir_all(&commands_dir).map_err(InstallError::CreateDir)?;
+    fs::write(&command_path, content).map_err(InstallError::WriteFile)?;
+
+    Ok(command_path)
+}
+
 fn update_manifest(
     profile_dir: &std::path::Path,
-    component_type: ComponentType,
+    component_type: DependencyKind,
     name: &str,
     source: &SourceInfo,
+    requires: &[Dependency],
 ) {
     let manifest_file = manifest_path(profile_dir);
     let mut manifest = InstallManifest::load(&manifest_file).unwrap_or_default();
@@ -416,6 +591,7 @@ fn update_manifest(
         component_type,
         name: name.to_string(),
         source: source.clone(),
+        requires: requires.to_vec(),
         installed_at: chrono::Utc::now().to_rfc3339(),
     });
 
@@ -433,12 +609,15 @@ pub fn install_agent(
     agent: &AgentInfo,
     target: &InstallTarget,
     options: &InstallOptions,
+    source: Option<&SourceInfo>,
 ) -> InstallResult {
-    let profiles_dir = BridleConfig::profiles_dir().map_err(|_| InstallError::ProfileNotFound {
-        harness: target.harness.clone(),
-        profile: target.profile.as_str().to_string(),
+    let profiles_dir = BridleConfig::profiles_dir_for(&target.scope()).map_err(|_| {
+        InstallError::ProfileNotFound {
+            harness: target.harness.clone(),
+            profile: target.profile.as_str().to_string(),
+        }
     })?;
-    install_agent_to_dir(agent, target, options, &profiles_dir)
+    install_agent_to_dir_with_source(agent, target, options, &profiles_dir, source)
 }
 
 pub fn install_agent_to_dir(
@@ -450,19 +629,6 @@ pub fn install_agent_to_dir(
     install_agent_to_dir_with_source(agent, target, options, profiles_dir, None)
 }
 
-fn install_agent_with_source(
-    agent: &AgentInfo,
-    target: &InstallTarget,
-    options: &InstallOptions,
-    source: Option<&SourceInfo>,
-) -> InstallResult {
-    let profiles_dir = BridleConfig::profiles_dir().map_err(|_| InstallError::ProfileNotFound {
-        harness: target.harness.clone(),
-        profile: target.profile.as_str().to_string(),
-    })?;
-    install_agent_to_dir_with_source(agent, target, options, &profiles_dir, source)
-}
-
 fn install_agent_to_dir_with_source(
     agent: &AgentInfo,
     target: &InstallTarget,
@@ -483,7 +649,17 @@ fn install_agent_to_dir_with_source(
         });
     }
 
-    let agents_dir = profile_dir.join(CANONICAL_AGENTS_DIR);
+    let (agents_dir, content) = if is_codex(target) {
+        (
+            profile_dir.join(CODEX_PROMPTS_DIR),
+            transform_agent_for_codex(&agent.content),
+        )
+    } else {
+        (
+            profile_dir.join(CANONICAL_AGENTS_DIR),
+            agent.content.clone(),
+        )
+    };
     let agent_path = agents_dir.join(format!("{}.md", &agent.name));
 
     if agent_path.exists() && !options.force {
@@ -495,10 +671,16 @@ fn install_agent_to_dir_with_source(
     }
 
     fs::create_dir_all(&agents_dir).map_err(InstallError::CreateDir)?;
-    fs::write(&agent_path, &agent.content).map_err(InstallError::WriteFile)?;
+    fs::write(&agent_path, &content).map_err(InstallError::WriteFile)?;
 
     if let Some(source_info) = source {
-        update_manifest(&profile_dir, ComponentType::Agent, &agent.name, source_info);
+        update_manifest(
+            &profile_dir,
+            DependencyKind::Agent,
+            &agent.name,
+            source_info,
+            &agent.requires,
+        );
     }
 
     let harness_path = write_agent_to_harness_if_active(target, agent)?;
@@ -515,12 +697,15 @@ pub fn install_command(
     command: &CommandInfo,
     target: &InstallTarget,
     options: &InstallOptions,
+    source: Option<&SourceInfo>,
 ) -> InstallResult {
-    let profiles_dir = BridleConfig::profiles_dir().map_err(|_| InstallError::ProfileNotFound {
-        harness: target.harness.clone(),
-        profile: target.profile.as_str().to_string(),
+    let profiles_dir = BridleConfig::profiles_dir_for(&target.scope()).map_err(|_| {
+        InstallError::ProfileNotFound {
+            harness: target.harness.clone(),
+            profile: target.profile.as_str().to_string(),
+        }
     })?;
-    install_command_to_dir(command, target, options, &profiles_dir)
+    install_command_to_dir_with_source(command, target, options, &profiles_dir, source)
 }
 
 pub fn install_command_to_dir(
@@ -532,19 +717,6 @@ pub fn install_command_to_dir(
     install_command_to_dir_with_source(command, target, options, profiles_dir, None)
 }
 
-fn install_command_with_source(
-    command: &CommandInfo,
-    target: &InstallTarget,
-    options: &InstallOptions,
-    source: Option<&SourceInfo>,
-) -> InstallResult {
-    let profiles_dir = BridleConfig::profiles_dir().map_err(|_| InstallError::ProfileNotFound {
-        harness: target.harness.clone(),
-        profile: target.profile.as_str().to_string(),
-    })?;
-    install_command_to_dir_with_source(command, target, options, &profiles_dir, source)
-}
-
 fn install_command_to_dir_with_source(
     command: &CommandInfo,
     target: &InstallTarget,
@@ -565,8 +737,26 @@ fn install_command_to_dir_with_source(
         });
     }
 
-    let commands_dir = profile_dir.join(CANONICAL_COMMANDS_DIR);
-    let command_path = commands_dir.join(format!("{}.md", &command.name));
+    let (commands_dir, file_name, content) = if is_codex(target) {
+        (
+            profile_dir.join(CODEX_PROMPTS_DIR),
+            format!("{}.md", &command.name),
+            transform_command_for_codex(&command.content),
+        )
+    } else if is_gemini(target) {
+        (
+            profile_dir.join(CANONICAL_COMMANDS_DIR),
+            format!("{}.toml", &command.name),
+            gemini_command_content(command),
+        )
+    } else {
+        (
+            profile_dir.join(CANONICAL_COMMANDS_DIR),
+            format!("{}.md", &command.name),
+            command.content.clone(),
+        )
+    };
+    let command_path = commands_dir.join(file_name);
 
     if command_path.exists() && !options.force {
         return Ok(InstallOutcome::Skipped(InstallSkip {
@@ -577,14 +767,15 @@ fn install_command_to_dir_with_source(
     }
 
     fs::create_dir_all(&commands_dir).map_err(InstallError::CreateDir)?;
-    fs::write(&command_path, &command.content).map_err(InstallError::WriteFile)?;
+    fs::write(&command_path, &content).map_err(InstallError::WriteFile)?;
 
     if let Some(source_info) = source {
         update_manifest(
             &profile_dir,
-            ComponentType::Command,
+            DependencyKind::Command,
             &command.name,
             source_info,
+            &command.requires,
         );
     }
 
@@ -598,17 +789,124 @@ fn install_command_to_dir_with_source(
     }))
 }
 
+/// The server entry a harness expects for a stdio MCP server.
+pub fn mcp_entry_for_harness(harness_id: &str, server: &McpInfo) -> Option<serde_json::Value> {
+    let entry = match harness_id {
+        "opencode" => {
+            let command: Vec<&str> = std::iter::once(server.command.as_str())
+                .chain(server.args.iter().map(String::as_str))
+                .collect();
+            serde_json::json!({
+                "type": "local",
+                "command": command,
+                "environment": server.env,
+                "enabled": true,
+            })
+        }
+        "goose" => serde_json::json!({
+            "name": server.name,
+            "type": "stdio",
+            "cmd": server.command,
+            "args": server.args,
+            "envs": server.env,
+            "enabled": true,
+        }),
+        "claude-code" | "amp-code" | "codex" | "gemini" => serde_json::json!({
+            "command": server.command,
+            "args": server.args,
+            "env": server.env,
+        }),
+        _ => return None,
+    };
+    Some(entry)
+}
+
+pub fn install_mcp_server(
+    server: &McpInfo,
+    target: &InstallTarget,
+    options: &InstallOptions,
+    source: Option<&SourceInfo>,
+) -> InstallResult {
+    let profiles_dir = BridleConfig::profiles_dir_for(&target.scope()).map_err(|_| {
+        InstallError::ProfileNotFound {
+            harness: target.harness.clone(),
+            profile: target.profile.as_str().to_string(),
+        }
+    })?;
+    install_mcp_server_to_dir(server, target, options, &profiles_dir, source)
+}
+
+fn install_mcp_server_to_dir(
+    server: &McpInfo,
+    target: &InstallTarget,
+    options: &InstallOptions,
+    profiles_dir: &Path,
+    source: Option<&SourceInfo>,
+) -> InstallResult {
+    validate_component_name(&server.name)?;
+
+    let profile_dir = profiles_dir
+        .join(&target.harness)
+        .join(target.profile.as_str());
+
+    if !profile_dir.exists() {
+        return Err(InstallError::ProfileNotFound {
+            harness: target.harness.clone(),
+            profile: target.profile.as_str().to_string(),
+        });
+    }
+
+    let store = mcp_store(&target.harness)?;
+    let entry = mcp_entry_for_harness(&target.harness, server)
+        .ok_or_else(|| McpConfigError::Unsupported(target.harness.clone()))?;
+    let profile_config = profile_dir.join(store.file);
+
+    if store.get(&profile_config, &server.name)?.is_some() && !options.force {
+        return Ok(InstallOutcome::Skipped(InstallSkip {
+            skill: server.name.clone(),
+            target: target.clone(),
+            reason: SkipReason::AlreadyExists,
+        }));
+    }
+
+    store.insert(&profile_config, &server.name, entry.clone())?;
+
+    if let Some(source_info) = source {
+        update_manifest(
+            &profile_dir,
+            DependencyKind::Mcp,
+            &server.name,
+            source_info,
+            &[],
+        );
+    }
+
+    let mut harness_path = None;
+    for path in live_mcp_paths(target) {
+        store.insert(&path, &server.name, entry.clone())?;
+        harness_path.get_or_insert(path);
+    }
+
+    Ok(InstallOutcome::Installed(InstallSuccess {
+        skill: server.name.clone(),
+        target: target.clone(),
+        profile_path: profile_config,
+        harness_path,
+    }))
+}
+
 pub fn install_skills(
     skills: &[SkillInfo],
     target: &InstallTarget,
     options: &InstallOptions,
+    source: Option<&SourceInfo>,
 ) -> InstallReport {
     let mut installed = Vec::new();
     let mut skipped = Vec::new();
     let mut errors = Vec::new();
 
     for skill in skills {
-        match install_skill(skill, target, options) {
+        match install_skill(skill, target, options, source) {
             Ok(InstallOutcome::Installed(success)) => installed.push(success),
             Ok(InstallOutcome::Skipped(skip)) => skipped.push(skip),
             Err(e) => errors.push(InstallFailure {
@@ -641,6 +939,7 @@ mod tests {
         let target = InstallTarget {
             harness: "opencode".to_string(),
             profile: ProfileName::new("test").unwrap(),
+            project: None,
         };
 
         (temp, target, profiles_dir)
@@ -655,6 +954,7 @@ mod tests {
             description: Some("A test skill".to_string()),
             path: "skills/my-skill/SKILL.md".to_string(),
             content: "# My Skill\n\nContent here".to_string(),
+            requires: Vec::new(),
         };
 
         let result =
@@ -688,6 +988,7 @@ mod tests {
             description: None,
             path: "skills/existing/SKILL.md".to_string(),
             content: "new content".to_string(),
+            requires: Vec::new(),
         };
 
         let result =
@@ -708,6 +1009,7 @@ mod tests {
             description: None,
             path: "skills/existing/SKILL.md".to_string(),
             content: "new content".to_string(),
+            requires: Vec::new(),
         };
 
         let result = install_skill_to_dir(
@@ -740,6 +1042,7 @@ mod tests {
                 description: None,
                 path: String::new(),
                 content: "content".to_string(),
+                requires: Vec::new(),
             };
             let result =
                 install_skill_to_dir(&skill, &target, &InstallOptions::default(), &profiles_dir);
@@ -759,6 +1062,7 @@ mod tests {
         let target = InstallTarget {
             harness: "opencode".to_string(),
             profile: ProfileName::new("nonexistent").unwrap(),
+            project: None,
         };
 
         let skill = SkillInfo {
@@ -766,6 +1070,7 @@ mod tests {
             description: None,
             path: "skills/skill/SKILL.md".to_string(),
             content: "content".to_string(),
+            requires: Vec::new(),
         };
 
         let result =
@@ -782,6 +1087,7 @@ mod tests {
             description: None,
             path: "agents/test-agent.md".to_string(),
             content: "# Test Agent".to_string(),
+            requires: Vec::new(),
         };
 
         let result =
@@ -806,6 +1112,7 @@ mod tests {
             description: None,
             path: "commands/test-command.md".to_string(),
             content: "# Test Command".to_string(),
+            requires: Vec::new(),
         };
 
         let result =
@@ -833,6 +1140,7 @@ mod tests {
             description: Some("A skill with spaces".to_string()),
             path: "skills/Hook Development/SKILL.md".to_string(),
             content: "---\nname: Hook Development\ndescription: Test\n---\n# Content".to_string(),
+            requires: Vec::new(),
         };
 
         let result =
@@ -857,4 +1165,175 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn codex_prompts_keep_only_supported_frontmatter() {
+        let command = "---\ndescription: Review a PR\nallowed-tools:\n  - Bash\nmodel: opus\nargument-hint: <pr>\n---\nReview $ARGUMENTS\n";
+        assert_eq!(
+            transform_command_for_codex(command),
+            "---\ndescription: Review a PR\nargument-hint: <pr>\n---\nReview $ARGUMENTS\n"
+        );
+
+        let agent = "---\nname: reviewer\ntools: Read, Grep\n---\nYou review code.\n";
+        assert_eq!(
+            transform_agent_for_codex(agent),
+            "You review code.\n\n$ARGUMENTS\n"
+        );
+        assert_eq!(
+            transform_agent_for_codex(&transform_agent_for_codex(agent)),
+            transform_agent_for_codex(agent)
+        );
+
+        // A `---` rule in a body without frontmatter is not a frontmatter fence.
+        let ruled = "Intro\n---\nfoo\n---\nbar";
+        assert_eq!(transform_command_for_codex(ruled), ruled);
+    }
+
+    #[test]
+    fn codex_agents_and_commands_install_as_prompts() {
+        let temp = TempDir::new().unwrap();
+        let profiles_dir = temp.path().join("profiles");
+        fs::create_dir_all(profiles_dir.join("codex").join("test")).unwrap();
+        let target = InstallTarget {
+            harness: "codex".to_string(),
+            profile: ProfileName::new("test").unwrap(),
+            project: None,
+        };
+
+        let agent = AgentInfo {
+            name: "reviewer".to_string(),
+            description: None,
+            path: "agents/reviewer.md".to_string(),
+            content: "---\ndescription: Reviews code\ncolor: red\n---\nYou review code."
+                .to_string(),
+            requires: Vec::new(),
+        };
+        let Ok(InstallOutcome::Installed(success)) =
+            install_agent_to_dir(&agent, &target, &InstallOptions::default(), &profiles_dir)
+        else {
+            panic!("agent should install");
+        };
+        assert!(
+            success
+                .profile_path
+                .ends_with("codex/test/prompts/reviewer.md")
+        );
+        assert_eq!(
+            fs::read_to_string(&success.profile_path).unwrap(),
+            "---\ndescription: Reviews code\n---\nYou review code.\n\n$ARGUMENTS\n"
+        );
+
+        let command = CommandInfo {
+            name: "ship".to_string(),
+            description: None,
+            path: "commands/ship.md".to_string(),
+            content: "Ship $1".to_string(),
+            requires: Vec::new(),
+        };
+        let Ok(InstallOutcome::Installed(success)) =
+            install_command_to_dir(&command, &target, &InstallOptions::default(), &profiles_dir)
+        else {
+            panic!("command should install");
+        };
+        assert!(success.profile_path.ends_with("codex/test/prompts/ship.md"));
+        assert_eq!(
+            fs::read_to_string(&success.profile_path).unwrap(),
+            "Ship $1"
+        );
+    }
+
+    #[test]
+    fn gemini_commands_become_toml() {
+        let command = "---\ndescription: \"Summarize changes\"\nallowed-tools: Bash\n---\nDiff: !`git diff`\n\nFocus on $ARGUMENTS\n";
+        let converted = transform_command_for_gemini(command);
+        let parsed: toml::Table = toml::from_str(&converted).unwrap();
+        assert_eq!(parsed["description"].as_str(), Some("Summarize changes"));
+        assert_eq!(
+            parsed["prompt"].as_str(),
+            Some("Diff: !{git diff}\n\nFocus on {{args}}\n")
+        );
+
+        let parsed: toml::Table =
+            toml::from_str(&transform_command_for_gemini("Just do it")).unwrap();
+        assert!(parsed.get("description").is_none());
+        assert_eq!(parsed["prompt"].as_str(), Some("Just do it"));
+    }
+
+    #[test]
+    fn gemini_toml_commands_install_verbatim() {
+        let (temp, _target, profiles_dir) = setup_test_env();
+        fs::create_dir_all(temp.path().join("profiles/gemini/test")).unwrap();
+        let target = InstallTarget {
+            harness: "gemini".to_string(),
+            profile: ProfileName::new("test").unwrap(),
+            project: None,
+        };
+        let stored = "# Reviews the diff\nprompt = \"Review {{args}}\"\n";
+        let command = CommandInfo {
+            name: "review".to_string(),
+            description: None,
+            path: "commands/review.toml".to_string(),
+            content: stored.to_string(),
+            requires: Vec::new(),
+        };
+
+        let Ok(InstallOutcome::Installed(success)) =
+            install_command_to_dir(&command, &target, &InstallOptions::default(), &profiles_dir)
+        else {
+            panic!("command should install");
+        };
+        assert!(
+            success
+                .profile_path
+                .ends_with("gemini/test/commands/review.toml")
+        );
+        assert_eq!(fs::read_to_string(&success.profile_path).unwrap(), stored);
+    }
+
+    #[test]
+    fn install_mcp_server_writes_harness_entry() {
+        let (_temp, target, profiles_dir) = setup_test_env();
+        let server = McpInfo {
+            name: "docs".to_string(),
+            description: None,
+            command: "npx".to_string(),
+            args: vec!["docs-mcp".to_string()],
+            env: Default::default(),
+        };
+
+        let Ok(InstallOutcome::Installed(success)) = install_mcp_server_to_dir(
+            &server,
+            &target,
+            &InstallOptions::default(),
+            &profiles_dir,
+            None,
+        ) else {
+            panic!("server should install");
+        };
+        assert!(
+            success
+                .profile_path
+                .ends_with("opencode/test/opencode.jsonc")
+        );
+        let entry = mcp_store("opencode")
+            .unwrap()
+            .get(&success.profile_path, "docs")
+            .unwrap()
+            .unwrap();
+        assert_eq!(entry["command"], serde_json::json!(["npx", "docs-mcp"]));
+
+        let again = install_mcp_server_to_dir(
+            &server,
+            &target,
+            &InstallOptions::default(),
+            &profiles_dir,
+            None,
+        );
+        assert!(matches!(again, Ok(InstallOutcome::Skipped(_))));
+
+        assert_eq!(
+            mcp_entry_for_harness("gemini", &server),
+            Some(serde_json::json!({"command": "npx", "args": ["docs-mcp"], "env": {}}))
+        );
+    }
 }
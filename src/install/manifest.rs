@@ -4,7 +4,7 @@ use std::path::{Path, PathBuf};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use super::types::{ComponentType, SourceInfo};
+use super::types::{Dependency, DependencyKind, SourceInfo};
 
 #[derive(Debug, Error)]
 pub enum ManifestError {
@@ -23,9 +23,13 @@ pub enum ManifestError {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ManifestEntry {
-    pub component_type: ComponentType,
+    /// Skill, agent, command, or MCP server.
+    pub component_type: DependencyKind,
     pub name: String,
     pub source: SourceInfo,
+    /// Components this entry declared in its `requires:` frontmatter.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub requires: Vec<Dependency>,
     pub installed_at: String,
 }
 
@@ -58,19 +62,27 @@ impl InstallManifest {
         self.entries.push(entry);
     }
 
-    pub fn remove_component(&mut self, component_type: ComponentType, name: &str) {
+    pub fn remove_component(&mut self, component_type: DependencyKind, name: &str) {
         self.entries
-            .retain(|e| !(e.component_type as u8 == component_type as u8 && e.name == name));
+            .retain(|e| !(e.component_type == component_type && e.name == name));
     }
 
     pub fn find_component(
         &self,
-        component_type: ComponentType,
+        component_type: DependencyKind,
         name: &str,
     ) -> Option<&ManifestEntry> {
         self.entries
             .iter()
-            .find(|e| e.component_type as u8 == component_type as u8 && e.name == name)
+            .find(|e| e.component_type == component_type && e.name == name)
+    }
+
+    /// Entries whose `requires` list includes the given dependency.
+    pub fn dependents(&self, dep: &Dependency) -> Vec<&ManifestEntry> {
+        self.entries
+            .iter()
+            .filter(|e| e.requires.contains(dep))
+            .collect()
     }
 }
 
@@ -99,13 +111,15 @@ mod tests {
 
         let mut manifest = InstallManifest::default();
         manifest.add_entry(ManifestEntry {
-            component_type: ComponentType::Skill,
+            component_type: DependencyKind::Skill,
             name: "test-skill".to_string(),
             source: SourceInfo {
                 owner: "test".to_string(),
                 repo: "repo".to_string(),
                 git_ref: Some("main".to_string()),
+                subpath: None,
             },
+            requires: Vec::new(),
             installed_at: "2025-01-02T12:00:00Z".to_string(),
         });
 
@@ -121,24 +135,28 @@ mod tests {
         let mut manifest = InstallManifest::default();
 
         manifest.add_entry(ManifestEntry {
-            component_type: ComponentType::Skill,
+            component_type: DependencyKind::Skill,
             name: "skill".to_string(),
             source: SourceInfo {
                 owner: "old".to_string(),
                 repo: "repo".to_string(),
                 git_ref: None,
+                subpath: None,
             },
+            requires: Vec::new(),
             installed_at: "2025-01-01T00:00:00Z".to_string(),
         });
 
         manifest.add_entry(ManifestEntry {
-            component_type: ComponentType::Skill,
+            component_type: DependencyKind::Skill,
             name: "skill".to_string(),
             source: SourceInfo {
                 owner: "new".to_string(),
                 repo: "repo".to_string(),
                 git_ref: None,
+                subpath: None,
             },
+            requires: Vec::new(),
             installed_at: "2025-01-02T00:00:00Z".to_string(),
         });
 
@@ -151,28 +169,32 @@ mod tests {
         let mut manifest = InstallManifest::default();
 
         manifest.add_entry(ManifestEntry {
-            component_type: ComponentType::Skill,
+            component_type: DependencyKind::Skill,
             name: "skill1".to_string(),
             source: SourceInfo {
                 owner: "test".to_string(),
                 repo: "repo".to_string(),
                 git_ref: None,
+                subpath: None,
             },
+            requires: Vec::new(),
             installed_at: "2025-01-02T00:00:00Z".to_string(),
         });
 
         manifest.add_entry(ManifestEntry {
-            component_type: ComponentType::Agent,
+            component_type: DependencyKind::Agent,
             name: "agent1".to_string(),
             source: SourceInfo {
                 owner: "test".to_string(),
                 repo: "repo".to_string(),
                 git_ref: None,
+                subpath: None,
             },
+            requires: Vec::new(),
             installed_at: "2025-01-02T00:00:00Z".to_string(),
         });
 
-        manifest.remove_component(ComponentType::Skill, "skill1");
+        manifest.remove_component(DependencyKind::Skill, "skill1");
         assert_eq!(manifest.entries.len(), 1);
         assert_eq!(manifest.entries[0].name, "agent1");
     }
@@ -182,21 +204,57 @@ mod tests {
         let mut manifest = InstallManifest::default();
 
         manifest.add_entry(ManifestEntry {
-            component_type: ComponentType::Skill,
+            component_type: DependencyKind::Skill,
             name: "skill1".to_string(),
             source: SourceInfo {
                 owner: "test".to_string(),
                 repo: "repo".to_string(),
                 git_ref: None,
+                subpath: None,
             },
+            requires: Vec::new(),
             installed_at: "2025-01-02T00:00:00Z".to_string(),
         });
 
-        let found = manifest.find_component(ComponentType::Skill, "skill1");
+        let found = manifest.find_component(DependencyKind::Skill, "skill1");
         assert!(found.is_some());
         assert_eq!(found.unwrap().name, "skill1");
 
-        let not_found = manifest.find_component(ComponentType::Agent, "skill1");
+        let not_found = manifest.find_component(DependencyKind::Agent, "skill1");
         assert!(not_found.is_none());
     }
+
+    #[test]
+    fn test_manifest_dependents() {
+        let mut manifest = InstallManifest::default();
+
+        manifest.add_entry(ManifestEntry {
+            component_type: DependencyKind::Agent,
+            name: "reviewer".to_string(),
+            source: SourceInfo {
+                owner: "test".to_string(),
+                repo: "repo".to_string(),
+                git_ref: None,
+                subpath: None,
+            },
+            requires: vec![Dependency::parse("mcp:github").unwrap()],
+            installed_at: "2025-01-02T00:00:00Z".to_string(),
+        });
+
+        let github = Dependency::parse("mcp:github").unwrap();
+        assert_eq!(manifest.dependents(&github).len(), 1);
+        assert!(
+            manifest
+                .dependents(&Dependency::parse("skill:github").unwrap())
+                .is_empty()
+        );
+
+        let temp = TempDir::new().unwrap();
+        let path = manifest_path(temp.path());
+        manifest.save(&path).unwrap();
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.contains("\"mcp:github\""));
+        let loaded = InstallManifest::load(&path).unwrap();
+        assert_eq!(loaded.entries[0].requires, vec![github]);
+    }
 }
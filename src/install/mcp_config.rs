@@ -0,0 +1,369 @@
+//! Editing MCP server tables in harness config files.
+//!
+//! Each harness keeps its servers under a different file and key; JSON, JSONC,
+//! YAML, and TOML files are all edited through a common `serde_json::Value` view.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use harness_locate::Harness;
+use thiserror::Error;
+
+use super::types::InstallTarget;
+use crate::config::BridleConfig;
+use crate::harness::{
+    HarnessConfig, HarnessRegistry, ProjectHarness, parse_document, write_document,
+};
+
+#[derive(Debug, Error)]
+pub enum McpConfigError {
+    #[error("Failed to read {}: {source}", path.display())]
+    Read {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("Failed to write {}: {source}", path.display())]
+    Write {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("Failed to parse {}: {message}", path.display())]
+    Parse { path: PathBuf, message: String },
+
+    #[error("Harness does not store MCP servers in its config: {0}")]
+    Unsupported(String),
+}
+
+/// How a harness marks a server as switched off.
+#[derive(Debug, Clone, Copy)]
+enum Toggle {
+    /// `enabled: false`
+    Enabled,
+    /// `disabled: true`
+    Disabled,
+}
+
+/// Where a harness keeps MCP servers inside its config directory.
+#[derive(Debug, Clone, Copy)]
+pub struct McpStore {
+    pub file: &'static str,
+    key: &'static str,
+    toggle: Toggle,
+}
+
+pub fn mcp_store(harness_id: &str) -> Result<McpStore, McpConfigError> {
+    let store = match harness_id {
+        "opencode" => McpStore {
+            file: "opencode.jsonc",
+            key: "mcp",
+            toggle: Toggle::Enabled,
+        },
+        "claude-code" => McpStore {
+            file: ".mcp.json",
+            key: "mcpServers",
+            toggle: Toggle::Disabled,
+        },
+        "amp-code" => McpStore {
+            file: "settings.json",
+            key: "amp.mcpServers",
+            toggle: Toggle::Disabled,
+        },
+        "goose" => McpStore {
+            file: "config.yaml",
+            key: "extensions",
+            toggle: Toggle::Enabled,
+        },
+        "codex" => McpStore {
+            file: "config.toml",
+            key: "mcp_servers",
+            toggle: Toggle::Enabled,
+        },
+        "gemini" => McpStore {
+            file: "settings.json",
+            key: "mcpServers",
+            toggle: Toggle::Disabled,
+        },
+        _ => return Err(McpConfigError::Unsupported(harness_id.to_string())),
+    };
+    Ok(store)
+}
+
+/// Live harness config files that hold MCP servers, if `target` is the active profile.
+pub fn live_mcp_paths(target: &InstallTarget) -> Vec<PathBuf> {
+    let is_active = BridleConfig::load()
+        .ok()
+        .as_ref()
+        .and_then(|c| c.active_profile_in(&target.scope(), &target.harness))
+        .is_some_and(|active| active == target.profile.as_str());
+    if !is_active {
+        return Vec::new();
+    }
+
+    let registry = HarnessRegistry::builtin();
+    let Some(entry) = registry.find(&target.harness) else {
+        return Vec::new();
+    };
+    let harness: Box<dyn HarnessConfig> = match (&target.project, entry.kind) {
+        (Some(root), Some(kind)) => Box::new(ProjectHarness::new(Harness::new(kind), root)),
+        _ => entry.config(),
+    };
+    let Ok(store) = mcp_store(&target.harness) else {
+        return Vec::new();
+    };
+
+    let mut paths: Vec<PathBuf> = harness
+        .config_dir()
+        .map(|d| vec![d.join(store.file)])
+        .unwrap_or_default();
+    if let Some(mcp_path) = harness.mcp_config_path()
+        && !paths.contains(&mcp_path)
+    {
+        paths.push(mcp_path);
+    }
+    paths.retain(|p| p.exists());
+    paths
+}
+
+impl McpStore {
+    /// The server entry called `name` in `path`, if present.
+    pub fn get(
+        &self,
+        path: &Path,
+        name: &str,
+    ) -> Result<Option<serde_json::Value>, McpConfigError> {
+        Ok(self
+            .load(path)?
+            .and_then(|config| config.get(self.key)?.get(name).cloned()))
+    }
+
+    /// Removes `name` from the server table in `path`.
+    ///
+    /// Returns `Ok(false)` if the file or entry does not exist.
+    pub fn remove(&self, path: &Path, name: &str) -> Result<bool, McpConfigError> {
+        self.edit(path, |servers| servers.shift_remove(name).is_some())
+    }
+
+    /// Adds or replaces the entry called `name`, creating the file if needed.
+    pub fn insert(
+        &self,
+        path: &Path,
+        name: &str,
+        entry: serde_json::Value,
+    ) -> Result<(), McpConfigError> {
+        if !path.exists() {
+            self.save(path, &serde_json::json!({}))?;
+        }
+        self.edit(path, |servers| {
+            servers.insert(name.to_string(), entry);
+            true
+        })?;
+        Ok(())
+    }
+
+    /// Switches the server called `name` on or off.
+    ///
+    /// Returns `Ok(false)` if the file or entry does not exist.
+    pub fn set_enabled(
+        &self,
+        path: &Path,
+        name: &str,
+        enabled: bool,
+    ) -> Result<bool, McpConfigError> {
+        let toggle = self.toggle;
+        self.edit(path, |servers| {
+            let Some(server) = servers.get_mut(name).and_then(|s| s.as_object_mut()) else {
+                return false;
+            };
+            match toggle {
+                Toggle::Enabled => {
+                    server.insert("enabled".to_string(), enabled.into());
+                }
+                Toggle::Disabled if enabled => {
+                    server.shift_remove("disabled");
+                }
+                Toggle::Disabled => {
+                    server.insert("disabled".to_string(), true.into());
+                }
+            }
+            true
+        })
+    }
+
+    /// Applies `f` to the server table and writes the file back if it reports a change.
+    fn edit(
+        &self,
+        path: &Path,
+        f: impl FnOnce(&mut serde_json::Map<String, serde_json::Value>) -> bool,
+    ) -> Result<bool, McpConfigError> {
+        let Some(mut config) = self.load(path)? else {
+            return Ok(false);
+        };
+        let Some(root) = config.as_object_mut() else {
+            return Err(McpConfigError::Parse {
+                path: path.to_path_buf(),
+                message: "top level is not a table".to_string(),
+            });
+        };
+        let servers = root
+            .entry(self.key)
+            .or_insert_with(|| serde_json::Value::Object(Default::default()));
+        let Some(servers) = servers.as_object_mut() else {
+            return Err(McpConfigError::Parse {
+                path: path.to_path_buf(),
+                message: format!("'{}' is not a table", self.key),
+            });
+        };
+
+        if !f(servers) {
+            return Ok(false);
+        }
+        self.save(path, &config)?;
+        Ok(true)
+    }
+
+    fn load(&self, path: &Path) -> Result<Option<serde_json::Value>, McpConfigError> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(path).map_err(|source| McpConfigError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        parse_document(&content, self.file)
+            .map(Some)
+            .map_err(|e| McpConfigError::Parse {
+                path: path.to_path_buf(),
+                message: e.to_string(),
+            })
+    }
+
+    /// Writes `config` back through the shared writer, keeping key order and JSONC comments.
+    fn save(&self, path: &Path, config: &serde_json::Value) -> Result<(), McpConfigError> {
+        write_document(path, config).map_err(|e| match e {
+            crate::error::Error::Io(source) => McpConfigError::Write {
+                path: path.to_path_buf(),
+                source,
+            },
+            e => McpConfigError::Parse {
+                path: path.to_path_buf(),
+                message: e.to_string(),
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn toggles_opencode_server() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("opencode.jsonc");
+        fs::write(
+            &path,
+            "{\n  // servers\n  \"mcp\": { \"fs\": { \"type\": \"local\" } }\n}",
+        )
+        .unwrap();
+        let store = mcp_store("opencode").unwrap();
+
+        assert!(store.set_enabled(&path, "fs", false).unwrap());
+        let entry = store.get(&path, "fs").unwrap().unwrap();
+        assert_eq!(entry["enabled"], false);
+        assert!(!store.set_enabled(&path, "missing", false).unwrap());
+    }
+
+    #[test]
+    fn edits_keep_comments_and_key_order() {
+        let temp = TempDir::new().unwrap();
+        let jsonc = temp.path().join("opencode.jsonc");
+        fs::write(
+            &jsonc,
+            "{\n  // my setup\n  \"theme\": \"dark\",\n  \"mcp\": {},\n  \"agent\": {}\n}\n",
+        )
+        .unwrap();
+        let store = mcp_store("opencode").unwrap();
+        store
+            .insert(&jsonc, "fs", serde_json::json!({"type": "local"}))
+            .unwrap();
+        let content = fs::read_to_string(&jsonc).unwrap();
+        assert!(content.contains("// my setup"));
+        assert!(content.find("\"theme\"") < content.find("\"mcp\""));
+        assert!(content.find("\"mcp\"") < content.find("\"agent\""));
+
+        let yaml = temp.path().join("config.yaml");
+        fs::write(
+            &yaml,
+            "# my setup\nprovider: openai\nextensions:\n  # search\n  web:\n    cmd: web\nmodel: gpt-5 # daily\n",
+        )
+        .unwrap();
+        let store = mcp_store("goose").unwrap();
+        store
+            .insert(&yaml, "fs", serde_json::json!({"cmd": "fs"}))
+            .unwrap();
+        assert_eq!(
+            fs::read_to_string(&yaml).unwrap(),
+            "# my setup\nprovider: openai\nextensions:\n  # search\n  web:\n    cmd: web\n  fs:\n    cmd: fs\nmodel: gpt-5 # daily\n"
+        );
+    }
+
+    #[test]
+    fn toggles_claude_server_with_disabled_flag() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(".mcp.json");
+        fs::write(&path, r#"{"mcpServers": {"gh": {"command": "gh"}}}"#).unwrap();
+        let store = mcp_store("claude-code").unwrap();
+
+        store.set_enabled(&path, "gh", false).unwrap();
+        assert_eq!(store.get(&path, "gh").unwrap().unwrap()["disabled"], true);
+        store.set_enabled(&path, "gh", true).unwrap();
+        assert!(
+            store
+                .get(&path, "gh")
+                .unwrap()
+                .unwrap()
+                .get("disabled")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn inserts_into_new_yaml_file() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("config.yaml");
+        let store = mcp_store("goose").unwrap();
+
+        store
+            .insert(
+                &path,
+                "fs",
+                serde_json::json!({"type": "stdio", "cmd": "fs"}),
+            )
+            .unwrap();
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.contains("extensions:"));
+        assert_eq!(store.get(&path, "fs").unwrap().unwrap()["cmd"], "fs");
+        assert!(store.remove(&path, "fs").unwrap());
+        assert!(store.get(&path, "fs").unwrap().is_none());
+    }
+
+    #[test]
+    fn edits_codex_toml_servers() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("config.toml");
+        fs::write(&path, "model = \"gpt-5\"\n").unwrap();
+        let store = mcp_store("codex").unwrap();
+
+        store
+            .insert(&path, "docs", serde_json::json!({"command": "docs-mcp"}))
+            .unwrap();
+        store.set_enabled(&path, "docs", false).unwrap();
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.contains("model = \"gpt-5\""));
+        assert!(content.contains("[mcp_servers.docs]"));
+        assert!(content.contains("enabled = false"));
+    }
+}
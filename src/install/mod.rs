@@ -3,9 +3,14 @@
 #![allow(dead_code)]
 #![allow(unused_imports)]
 
+pub mod compat;
+pub mod components;
 pub mod discovery;
 pub mod installer;
 pub mod manifest;
+pub mod mcp_config;
+pub mod registry;
+pub mod scan;
 pub mod types;
 pub mod uninstaller;
 
@@ -0,0 +1,210 @@
+//! Skill registry index for discovering installable sources by name.
+//!
+//! A registry is a JSON document, served over HTTP or read from a local path,
+//! that lists installable sources along with the components they provide.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::types::ComponentType;
+use crate::config::BridleConfig;
+
+#[derive(Debug, Error)]
+pub enum RegistryError {
+    #[error("No registry configured. Set one with: bridle config set registry <url-or-path>")]
+    NotConfigured,
+
+    #[error("Failed to fetch registry: {0}")]
+    Fetch(#[source] skills_locate::Error),
+
+    #[error("Failed to read registry: {0}")]
+    Read(#[source] std::io::Error),
+
+    #[error("Failed to parse registry: {0}")]
+    Parse(#[source] serde_json::Error),
+
+    #[error("Unknown source '{0}'. Use owner/repo or configure a registry.")]
+    UnknownSource(String),
+
+    #[error("No registry entry named '{0}'")]
+    EntryNotFound(String),
+}
+
+/// Resolves a bare registry name (e.g. `rust-tools`) to its source.
+///
+/// Anything that already looks like a URL or `owner/repo` is returned unchanged.
+pub fn resolve_source(source: &str) -> Result<String, RegistryError> {
+    if source.contains('/') || source.contains(':') {
+        return Ok(source.to_string());
+    }
+
+    let config = BridleConfig::load().unwrap_or_default();
+    let location = config
+        .registry()
+        .ok_or_else(|| RegistryError::UnknownSource(source.to_string()))?;
+    let index = RegistryIndex::load(location)?;
+    let entry = index
+        .find(source)
+        .ok_or_else(|| RegistryError::EntryNotFound(source.to_string()))?;
+    Ok(entry.source.clone())
+}
+
+/// A single installable source listed in the registry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegistryEntry {
+    /// Short name used with `bridle install <name>`.
+    pub name: String,
+    /// GitHub URL or `owner/repo` shorthand.
+    pub source: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub skills: Vec<String>,
+    #[serde(default)]
+    pub agents: Vec<String>,
+    #[serde(default)]
+    pub commands: Vec<String>,
+    #[serde(default)]
+    pub mcp_servers: Vec<String>,
+}
+
+impl RegistryEntry {
+    /// Iterates over all component names with their type.
+    ///
+    /// MCP servers are reported with `None` since they have no [`ComponentType`].
+    pub fn components(&self) -> impl Iterator<Item = (Option<ComponentType>, &str)> {
+        let skills = self
+            .skills
+            .iter()
+            .map(|n| (Some(ComponentType::Skill), n.as_str()));
+        let agents = self
+            .agents
+            .iter()
+            .map(|n| (Some(ComponentType::Agent), n.as_str()));
+        let commands = self
+            .commands
+            .iter()
+            .map(|n| (Some(ComponentType::Command), n.as_str()));
+        let mcp = self.mcp_servers.iter().map(|n| (None, n.as_str()));
+        skills.chain(agents).chain(commands).chain(mcp)
+    }
+
+    fn matches(&self, query: &str) -> bool {
+        let contains = |s: &str| s.to_lowercase().contains(query);
+        contains(&self.name)
+            || contains(&self.source)
+            || self.description.as_deref().is_some_and(contains)
+            || self.components().any(|(_, name)| contains(name))
+    }
+}
+
+/// Parsed registry index.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct RegistryIndex {
+    #[serde(default)]
+    pub sources: Vec<RegistryEntry>,
+}
+
+impl RegistryIndex {
+    /// Loads the index from an HTTP(S) URL or a local file path.
+    pub fn load(location: &str) -> Result<Self, RegistryError> {
+        let content = if location.starts_with("http://") || location.starts_with("https://") {
+            let bytes = skills_locate::fetch_bytes(location).map_err(RegistryError::Fetch)?;
+            String::from_utf8_lossy(&bytes).into_owned()
+        } else {
+            std::fs::read_to_string(Path::new(location)).map_err(RegistryError::Read)?
+        };
+        Self::parse(&content)
+    }
+
+    pub fn parse(content: &str) -> Result<Self, RegistryError> {
+        serde_json::from_str(content).map_err(RegistryError::Parse)
+    }
+
+    /// Returns entries whose name, source, description, or component names
+    /// contain `query` (case-insensitive). An empty query matches everything.
+    pub fn search(&self, query: &str) -> Vec<&RegistryEntry> {
+        let query = query.trim().to_lowercase();
+        self.sources.iter().filter(|e| e.matches(&query)).collect()
+    }
+
+    /// Finds an entry by its exact name (case-insensitive).
+    pub fn find(&self, name: &str) -> Option<&RegistryEntry> {
+        self.sources
+            .iter()
+            .find(|e| e.name.eq_ignore_ascii_case(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const INDEX: &str = r#"{
+        "sources": [
+            {
+                "name": "rust-tools",
+                "source": "acme/rust-skills",
+                "description": "Skills for Rust development",
+                "skills": ["memory-safety", "cargo-helper"],
+                "agents": ["reviewer"]
+            },
+            {
+                "name": "web",
+                "source": "https://github.com/acme/web-kit",
+                "commands": ["deploy"],
+                "mcp_servers": ["browser"]
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn load_from_local_file() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("index.json");
+        std::fs::write(&path, INDEX).unwrap();
+
+        let index = RegistryIndex::load(path.to_str().unwrap()).unwrap();
+        assert_eq!(index.sources.len(), 2);
+        assert!(index.sources[1].skills.is_empty());
+    }
+
+    #[test]
+    fn load_missing_file_fails() {
+        let result = RegistryIndex::load("/nonexistent/index.json");
+        assert!(matches!(result, Err(RegistryError::Read(_))));
+    }
+
+    #[test]
+    fn search_matches_component_names() {
+        let index = RegistryIndex::parse(INDEX).unwrap();
+        let results = index.search("MEMORY");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].name, "rust-tools");
+
+        let results = index.search("browser");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].name, "web");
+    }
+
+    #[test]
+    fn search_matches_description_and_empty_query() {
+        let index = RegistryIndex::parse(INDEX).unwrap();
+        assert_eq!(index.search("rust development").len(), 1);
+        assert_eq!(index.search("").len(), 2);
+        assert!(index.search("nothing-here").is_empty());
+    }
+
+    #[test]
+    fn find_by_name() {
+        let index = RegistryIndex::parse(INDEX).unwrap();
+        assert_eq!(
+            index.find("Web").unwrap().source,
+            "https://github.com/acme/web-kit"
+        );
+        assert!(index.find("acme").is_none());
+    }
+}
@@ -2,6 +2,7 @@
 
 use std::path::PathBuf;
 
+use harness_locate::Scope;
 use serde::Serialize;
 
 use crate::config::ProfileName;
@@ -17,6 +18,8 @@ pub struct SkillInfo {
     pub path: String,
     /// Actual SKILL.md file content
     pub content: String,
+    /// Components this one needs, from `requires:` frontmatter
+    pub requires: Vec<Dependency>,
 }
 
 /// Information about a discovered MCP server
@@ -36,6 +39,7 @@ pub struct AgentInfo {
     pub description: Option<String>,
     pub path: String,
     pub content: String,
+    pub requires: Vec<Dependency>,
 }
 
 /// Information about a discovered command
@@ -45,6 +49,107 @@ pub struct CommandInfo {
     pub description: Option<String>,
     pub path: String,
     pub content: String,
+    pub requires: Vec<Dependency>,
+}
+
+/// Kind of component a [`Dependency`] refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DependencyKind {
+    Skill,
+    Agent,
+    Command,
+    Mcp,
+}
+
+impl DependencyKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DependencyKind::Skill => "skill",
+            DependencyKind::Agent => "agent",
+            DependencyKind::Command => "command",
+            DependencyKind::Mcp => "mcp",
+        }
+    }
+
+    /// The installable component type, or `None` for MCP servers.
+    pub fn component_type(&self) -> Option<ComponentType> {
+        match self {
+            DependencyKind::Skill => Some(ComponentType::Skill),
+            DependencyKind::Agent => Some(ComponentType::Agent),
+            DependencyKind::Command => Some(ComponentType::Command),
+            DependencyKind::Mcp => None,
+        }
+    }
+}
+
+impl std::fmt::Display for DependencyKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<ComponentType> for DependencyKind {
+    fn from(component_type: ComponentType) -> Self {
+        match component_type {
+            ComponentType::Skill => DependencyKind::Skill,
+            ComponentType::Agent => DependencyKind::Agent,
+            ComponentType::Command => DependencyKind::Command,
+        }
+    }
+}
+
+/// A component required by another, written as `kind:name` (e.g. `mcp:github`)
+///
+/// A bare name without a kind prefix refers to a skill.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, serde::Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Dependency {
+    pub kind: DependencyKind,
+    pub name: String,
+}
+
+impl Dependency {
+    pub fn new(kind: DependencyKind, name: impl Into<String>) -> Self {
+        Self {
+            kind,
+            name: name.into(),
+        }
+    }
+
+    pub fn parse(spec: &str) -> Option<Self> {
+        let spec = spec.trim();
+        let (kind, name) = match spec.split_once(':') {
+            Some(("skill", name)) => (DependencyKind::Skill, name),
+            Some(("agent", name)) => (DependencyKind::Agent, name),
+            Some(("command", name)) => (DependencyKind::Command, name),
+            Some(("mcp", name)) => (DependencyKind::Mcp, name),
+            Some(_) => return None,
+            None => (DependencyKind::Skill, spec),
+        };
+        let name = name.trim();
+        (!name.is_empty()).then(|| Self::new(kind, name))
+    }
+}
+
+impl std::fmt::Display for Dependency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.kind.as_str(), self.name)
+    }
+}
+
+impl TryFrom<String> for Dependency {
+    type Error = String;
+
+    fn try_from(spec: String) -> Result<Self, Self::Error> {
+        Self::parse(&spec).ok_or_else(|| format!("invalid dependency: {spec}"))
+    }
+}
+
+impl From<Dependency> for String {
+    fn from(dep: Dependency) -> Self {
+        dep.to_string()
+    }
 }
 
 /// Target harness + profile for installation
@@ -53,6 +158,19 @@ pub struct InstallTarget {
     /// Harness identifier (e.g., "opencode", "claude-code")
     pub harness: String,
     pub profile: ProfileName,
+    /// Project checkout for a project-scope profile; `None` for a global one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<PathBuf>,
+}
+
+impl InstallTarget {
+    /// The scope the target profile's harness paths resolve in.
+    pub fn scope(&self) -> Scope {
+        match &self.project {
+            Some(root) => Scope::Project(root.clone()),
+            None => Scope::Global,
+        }
+    }
 }
 
 /// Options controlling installation behavior
@@ -62,8 +180,8 @@ pub struct InstallOptions {
     pub force: bool,
 }
 
-/// Result of discovery operation
-#[derive(Debug)]
+/// Result of discovery operation, or a selection of the components of one
+#[derive(Debug, Clone)]
 pub struct DiscoveryResult {
     /// Discovered skills
     pub skills: Vec<SkillInfo>,
@@ -77,12 +195,103 @@ pub struct DiscoveryResult {
     pub source: SourceInfo,
 }
 
+impl DiscoveryResult {
+    /// An empty selection from `source`.
+    pub fn empty(source: &SourceInfo) -> Self {
+        Self {
+            skills: Vec::new(),
+            mcp_servers: Vec::new(),
+            agents: Vec::new(),
+            commands: Vec::new(),
+            source: source.clone(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.skills.is_empty()
+            && self.mcp_servers.is_empty()
+            && self.agents.is_empty()
+            && self.commands.is_empty()
+    }
+
+    /// Whether this source contains the given component.
+    pub fn provides(&self, dep: &Dependency) -> bool {
+        match dep.kind {
+            DependencyKind::Skill => self.skills.iter().any(|s| s.name == dep.name),
+            DependencyKind::Agent => self.agents.iter().any(|a| a.name == dep.name),
+            DependencyKind::Command => self.commands.iter().any(|c| c.name == dep.name),
+            DependencyKind::Mcp => self.mcp_servers.iter().any(|m| m.name == dep.name),
+        }
+    }
+
+    /// Dependencies declared by these components that are not among them.
+    pub fn missing_dependencies(&self) -> Vec<Dependency> {
+        let mut missing: Vec<Dependency> = Vec::new();
+        let declared = self
+            .skills
+            .iter()
+            .flat_map(|s| &s.requires)
+            .chain(self.agents.iter().flat_map(|a| &a.requires))
+            .chain(self.commands.iter().flat_map(|c| &c.requires));
+        for dep in declared {
+            if !self.provides(dep) && !missing.contains(dep) {
+                missing.push(dep.clone());
+            }
+        }
+        missing
+    }
+
+    /// Adds the named component from `discovery`, if present.
+    pub fn add(&mut self, discovery: &DiscoveryResult, dep: &Dependency) {
+        let name = dep.name.as_str();
+        match dep.kind {
+            DependencyKind::Skill => self
+                .skills
+                .extend(discovery.skills.iter().filter(|s| s.name == name).cloned()),
+            DependencyKind::Agent => self
+                .agents
+                .extend(discovery.agents.iter().filter(|a| a.name == name).cloned()),
+            DependencyKind::Command => self.commands.extend(
+                discovery
+                    .commands
+                    .iter()
+                    .filter(|c| c.name == name)
+                    .cloned(),
+            ),
+            DependencyKind::Mcp => self.mcp_servers.extend(
+                discovery
+                    .mcp_servers
+                    .iter()
+                    .filter(|m| m.name == name)
+                    .cloned(),
+            ),
+        }
+    }
+}
+
 /// Metadata about the source repository
 #[derive(Debug, Clone, Serialize, serde::Deserialize)]
 pub struct SourceInfo {
     pub owner: String,
     pub repo: String,
     pub git_ref: Option<String>,
+    /// Directory within the repository that discovery was limited to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subpath: Option<String>,
+}
+
+impl std::fmt::Display for SourceInfo {
+    /// Formats as `owner/repo[//subpath][@ref]`, the same syntax `bridle install` accepts.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.owner, self.repo)?;
+        if let Some(subpath) = &self.subpath {
+            write!(f, "//{}", subpath)?;
+        }
+        if let Some(git_ref) = &self.git_ref {
+            write!(f, "@{}", git_ref)?;
+        }
+        Ok(())
+    }
 }
 
 /// Result of installation operation
@@ -93,6 +302,15 @@ pub struct InstallReport {
     pub errors: Vec<InstallFailure>,
 }
 
+impl InstallReport {
+    /// Appends another report's results to this one.
+    pub fn merge(&mut self, other: InstallReport) {
+        self.installed.extend(other.installed);
+        self.skipped.extend(other.skipped);
+        self.errors.extend(other.errors);
+    }
+}
+
 #[derive(Debug, Serialize)]
 pub struct InstallSuccess {
     /// Component name
@@ -135,6 +353,10 @@ pub enum ComponentType {
 }
 
 impl ComponentType {
+    pub fn as_str(&self) -> &'static str {
+        DependencyKind::from(*self).as_str()
+    }
+
     pub fn dir_name(&self) -> &'static str {
         match self {
             ComponentType::Skill => "skills",
@@ -144,6 +366,12 @@ impl ComponentType {
     }
 }
 
+impl std::fmt::Display for ComponentType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Result of uninstallation operation
 #[derive(Debug, Default, Serialize)]
 pub struct UninstallReport {
@@ -1,18 +1,21 @@
 //! Component uninstallation executor.
 
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use thiserror::Error;
 
 use harness_locate::{Harness, HarnessKind, Scope};
 
-use super::manifest::{InstallManifest, manifest_path};
+use super::installer::component_dir;
+use super::manifest::{InstallManifest, ManifestEntry, ManifestError, manifest_path};
+use super::mcp_config::{McpConfigError, live_mcp_paths, mcp_store};
 use super::types::{
-    ComponentType, InstallTarget, UninstallFailure, UninstallReport, UninstallSuccess,
+    ComponentType, Dependency, DependencyKind, InstallTarget, UninstallFailure, UninstallReport,
+    UninstallSuccess,
 };
 use crate::config::BridleConfig;
-use crate::harness::HarnessConfig;
+use crate::harness::{HarnessConfig, HarnessRegistry};
 
 #[derive(Debug, Error)]
 pub enum UninstallError {
@@ -27,16 +30,9 @@ pub enum UninstallError {
 
     #[error("Harness not found: {0}")]
     HarnessNotFound(String),
-}
 
-fn parse_harness_kind(id: &str) -> Option<HarnessKind> {
-    match id {
-        "claude-code" | "claude" | "cc" => Some(HarnessKind::ClaudeCode),
-        "opencode" | "oc" => Some(HarnessKind::OpenCode),
-        "goose" => Some(HarnessKind::Goose),
-        "amp-code" | "amp" | "ampcode" => Some(HarnessKind::AmpCode),
-        _ => None,
-    }
+    #[error(transparent)]
+    McpConfig(#[from] McpConfigError),
 }
 
 pub fn uninstall_component(
@@ -44,11 +40,12 @@ pub fn uninstall_component(
     component_type: ComponentType,
     target: &InstallTarget,
 ) -> Result<UninstallSuccess, UninstallError> {
-    let profiles_dir =
-        BridleConfig::profiles_dir().map_err(|_| UninstallError::ProfileNotFound {
+    let profiles_dir = BridleConfig::profiles_dir_for(&target.scope()).map_err(|_| {
+        UninstallError::ProfileNotFound {
             harness: target.harness.clone(),
             profile: target.profile.as_str().to_string(),
-        })?;
+        }
+    })?;
 
     uninstall_component_from_dir(component_name, component_type, target, &profiles_dir)
 }
@@ -59,32 +56,24 @@ fn uninstall_component_from_dir(
     target: &InstallTarget,
     profiles_dir: &std::path::Path,
 ) -> Result<UninstallSuccess, UninstallError> {
-    let profile_dir = profiles_dir
-        .join(&target.harness)
-        .join(target.profile.as_str());
-
-    if !profile_dir.exists() {
-        return Err(UninstallError::ProfileNotFound {
-            harness: target.harness.clone(),
-            profile: target.profile.as_str().to_string(),
-        });
-    }
-
-    let component_dir = profile_dir
-        .join(component_type.dir_name())
-        .join(component_name);
-
-    if !component_dir.exists() {
+    let profile_dir = profile_dir(profiles_dir, target)?;
+
+    let Some(component_path) = component_path(
+        &profile_dir,
+        &target.harness,
+        component_type,
+        component_name,
+    ) else {
         return Err(UninstallError::ComponentNotFound(
             component_name.to_string(),
         ));
-    }
+    };
 
-    fs::remove_dir_all(&component_dir).map_err(UninstallError::RemoveDir)?;
+    remove_path(&component_path)?;
 
     let manifest_file = manifest_path(&profile_dir);
     if let Ok(mut manifest) = InstallManifest::load(&manifest_file) {
-        manifest.remove_component(component_type, component_name);
+        manifest.remove_component(component_type.into(), component_name);
         let _ = manifest.save(&manifest_file);
     }
 
@@ -92,72 +81,244 @@ fn uninstall_component_from_dir(
 
     Ok(UninstallSuccess {
         component: component_name.to_string(),
-        component_type: format!("{:?}", component_type).to_lowercase(),
+        component_type: component_type.to_string(),
         target: target.clone(),
-        profile_path: component_dir,
+        profile_path: component_path,
         harness_path,
     })
 }
 
+fn profile_dir(profiles_dir: &Path, target: &InstallTarget) -> Result<PathBuf, UninstallError> {
+    let profile_dir = profiles_dir
+        .join(&target.harness)
+        .join(target.profile.as_str());
+
+    if !profile_dir.exists() {
+        return Err(UninstallError::ProfileNotFound {
+            harness: target.harness.clone(),
+            profile: target.profile.as_str().to_string(),
+        });
+    }
+    Ok(profile_dir)
+}
+
+/// Locates an installed component inside `base`, laid out as `harness_id` keeps it.
+///
+/// Skills are directories; agents and commands are `<name>.md` files (Gemini
+/// commands `<name>.toml`), with a directory of the same name accepted for
+/// older installs.
+fn component_path(
+    base: &Path,
+    harness_id: &str,
+    component_type: ComponentType,
+    name: &str,
+) -> Option<PathBuf> {
+    let dir = base.join(component_dir(harness_id, component_type));
+    let candidates = match component_type {
+        ComponentType::Skill => vec![dir.join(name)],
+        ComponentType::Agent | ComponentType::Command => {
+            vec![
+                dir.join(format!("{}.md", name)),
+                dir.join(format!("{}.toml", name)),
+                dir.join(name),
+            ]
+        }
+    };
+    candidates.into_iter().find(|p| p.exists())
+}
+
+fn remove_path(path: &Path) -> Result<(), UninstallError> {
+    if path.is_dir() {
+        fs::remove_dir_all(path).map_err(UninstallError::RemoveDir)
+    } else {
+        fs::remove_file(path).map_err(UninstallError::RemoveDir)
+    }
+}
+
+fn is_active_target(target: &InstallTarget) -> bool {
+    BridleConfig::load()
+        .ok()
+        .as_ref()
+        .and_then(|c| c.active_profile_in(&target.scope(), &target.harness))
+        .is_some_and(|active| active == target.profile.as_str())
+}
+
+fn locate_harness(target: &InstallTarget) -> Result<Harness, UninstallError> {
+    let kind = HarnessRegistry::kind(&target.harness)
+        .ok_or_else(|| UninstallError::HarnessNotFound(target.harness.clone()))?;
+    Harness::locate(kind).map_err(|_| UninstallError::HarnessNotFound(target.harness.clone()))
+}
+
 fn remove_from_harness_if_active(
     target: &InstallTarget,
     component_name: &str,
     component_type: ComponentType,
 ) -> Result<Option<PathBuf>, UninstallError> {
-    let config = BridleConfig::load().ok();
-    let is_active = config
-        .as_ref()
-        .and_then(|c| c.active_profile_for(&target.harness))
-        .map(|active| active == target.profile.as_str())
-        .unwrap_or(false);
-
-    if !is_active {
+    if !is_active_target(target) {
         return Ok(None);
     }
 
-    let kind = parse_harness_kind(&target.harness)
-        .ok_or_else(|| UninstallError::HarnessNotFound(target.harness.clone()))?;
-    let harness = Harness::locate(kind)
-        .map_err(|_| UninstallError::HarnessNotFound(target.harness.clone()))?;
+    // Harnesses bridle describes itself mirror the profile layout in their config dir.
+    if HarnessRegistry::kind(&target.harness).is_none() {
+        let found = HarnessRegistry::builtin()
+            .find(&target.harness)
+            .and_then(|entry| entry.config().config_dir().ok())
+            .and_then(|dir| component_path(&dir, &target.harness, component_type, component_name));
+        return found
+            .map(|path| remove_path(&path).map(|()| path))
+            .transpose();
+    }
 
+    let harness = locate_harness(target)?;
+
+    let scope = target.scope();
     let component_dir_result = match component_type {
-        ComponentType::Skill => harness.skills(&Scope::Global),
-        ComponentType::Agent => harness.agents(&Scope::Global),
-        ComponentType::Command => harness.commands(&Scope::Global),
+        ComponentType::Skill => harness.skills(&scope),
+        ComponentType::Agent => harness.agents(&scope),
+        ComponentType::Command => harness.commands(&scope),
     };
 
-    let harness_component_dir = component_dir_result
-        .ok()
-        .flatten()
-        .map(|r| r.path.join(component_name))
-        .unwrap_or_else(|| {
-            harness
-                .config_dir()
-                .map(|d| d.join(component_type.dir_name()).join(component_name))
-                .unwrap_or_default()
-        });
+    // Resource paths already point at the harness's skills/agents/commands dir.
+    let found = match component_dir_result.ok().flatten() {
+        Some(resource) => {
+            let file = resource.path.join(format!("{}.md", component_name));
+            let dir = resource.path.join(component_name);
+            [file, dir].into_iter().find(|p| p.exists())
+        }
+        None => harness
+            .config(&scope)
+            .ok()
+            .and_then(|d| component_path(&d, &target.harness, component_type, component_name)),
+    };
 
-    if harness_component_dir.exists() {
-        fs::remove_dir_all(&harness_component_dir).map_err(UninstallError::RemoveDir)?;
-        Ok(Some(harness_component_dir))
-    } else {
-        Ok(None)
+    match found {
+        Some(path) => {
+            remove_path(&path)?;
+            Ok(Some(path))
+        }
+        None => Ok(None),
     }
 }
 
-pub fn uninstall_components(
-    components: &[(String, ComponentType)],
+/// Removes an MCP server from a profile's config, and from the live harness
+/// config when the profile is active.
+pub fn uninstall_mcp_server(
+    server_name: &str,
     target: &InstallTarget,
-) -> UninstallReport {
+) -> Result<UninstallSuccess, UninstallError> {
+    let profiles_dir = BridleConfig::profiles_dir_for(&target.scope()).map_err(|_| {
+        UninstallError::ProfileNotFound {
+            harness: target.harness.clone(),
+            profile: target.profile.as_str().to_string(),
+        }
+    })?;
+
+    uninstall_mcp_server_from_dir(server_name, target, &profiles_dir)
+}
+
+fn uninstall_mcp_server_from_dir(
+    server_name: &str,
+    target: &InstallTarget,
+    profiles_dir: &Path,
+) -> Result<UninstallSuccess, UninstallError> {
+    let profile_dir = profile_dir(profiles_dir, target)?;
+    let store = mcp_store(&target.harness)?;
+
+    let profile_config = profile_dir.join(store.file);
+    if !store.remove(&profile_config, server_name)? {
+        return Err(UninstallError::ComponentNotFound(server_name.to_string()));
+    }
+
+    let manifest_file = manifest_path(&profile_dir);
+    if let Ok(mut manifest) = InstallManifest::load(&manifest_file) {
+        manifest.remove_component(DependencyKind::Mcp, server_name);
+        let _ = manifest.save(&manifest_file);
+    }
+
+    let mut harness_path = None;
+    for path in live_mcp_paths(target) {
+        if store.remove(&path, server_name)? {
+            harness_path = Some(path);
+        }
+    }
+
+    Ok(UninstallSuccess {
+        component: server_name.to_string(),
+        component_type: "mcp".to_string(),
+        target: target.clone(),
+        profile_path: profile_config,
+        harness_path,
+    })
+}
+
+/// Installed components recorded in the manifest as coming from `owner/repo`.
+///
+/// When `subpath` is given, only components installed from that subdirectory match.
+pub fn components_from_source(
+    profile_dir: &Path,
+    owner: &str,
+    repo: &str,
+    subpath: Option<&str>,
+) -> Result<Vec<Dependency>, ManifestError> {
+    let manifest = InstallManifest::load(&manifest_path(profile_dir))?;
+    Ok(manifest
+        .entries
+        .iter()
+        .filter(|e| {
+            e.source.owner.eq_ignore_ascii_case(owner)
+                && e.source.repo.eq_ignore_ascii_case(repo)
+                && subpath.is_none_or(|sub| e.source.subpath.as_deref() == Some(sub))
+        })
+        .map(|e| Dependency::new(e.component_type, e.name.as_str()))
+        .collect())
+}
+
+/// Drops manifest entries whose component files, or MCP config entries, no
+/// longer exist in the profile.
+///
+/// Returns the pruned entries.
+pub fn prune_manifest(
+    profile_dir: &Path,
+    harness_id: &str,
+) -> Result<Vec<ManifestEntry>, ManifestError> {
+    let manifest_file = manifest_path(profile_dir);
+    let mut manifest = InstallManifest::load(&manifest_file)?;
+
+    let store = mcp_store(harness_id).ok();
+    let installed = |e: &ManifestEntry| match e.component_type.component_type() {
+        Some(component_type) => {
+            component_path(profile_dir, harness_id, component_type, &e.name).is_some()
+        }
+        None => store.as_ref().is_some_and(|store| {
+            matches!(
+                store.get(&profile_dir.join(store.file), &e.name),
+                Ok(Some(_))
+            )
+        }),
+    };
+    let (kept, pruned): (Vec<_>, Vec<_>) = manifest.entries.drain(..).partition(installed);
+    manifest.entries = kept;
+
+    if !pruned.is_empty() {
+        manifest.save(&manifest_file)?;
+    }
+    Ok(pruned)
+}
+
+pub fn uninstall_components(components: &[Dependency], target: &InstallTarget) -> UninstallReport {
     let mut removed = Vec::new();
     let mut errors = Vec::new();
 
-    for (name, comp_type) in components {
-        match uninstall_component(name, *comp_type, target) {
+    for component in components {
+        let result = match component.kind.component_type() {
+            Some(comp_type) => uninstall_component(&component.name, comp_type, target),
+            None => uninstall_mcp_server(&component.name, target),
+        };
+        match result {
             Ok(success) => removed.push(success),
             Err(e) => errors.push(UninstallFailure {
-                component: name.clone(),
-                component_type: format!("{:?}", comp_type).to_lowercase(),
+                component: component.name.clone(),
+                component_type: component.kind.as_str().to_string(),
                 target: target.clone(),
                 error: e.to_string(),
             }),
@@ -171,6 +332,8 @@ pub fn uninstall_components(
 mod tests {
     use super::*;
     use crate::config::ProfileName;
+    use crate::config::jsonc::strip_jsonc_comments;
+    use crate::install::types::SourceInfo;
     use tempfile::TempDir;
 
     fn setup_test_env() -> (TempDir, InstallTarget, PathBuf) {
@@ -182,6 +345,7 @@ mod tests {
         let target = InstallTarget {
             harness: "opencode".to_string(),
             profile: ProfileName::new("test").unwrap(),
+            project: None,
         };
 
         (temp, target, profiles_dir)
@@ -229,6 +393,7 @@ mod tests {
         let target = InstallTarget {
             harness: "opencode".to_string(),
             profile: ProfileName::new("nonexistent").unwrap(),
+            project: None,
         };
 
         let result = uninstall_component_from_dir(
@@ -242,4 +407,97 @@ mod tests {
             Err(UninstallError::ProfileNotFound { .. })
         ));
     }
+
+    #[test]
+    fn uninstall_removes_agent_file() {
+        let (temp, target, profiles_dir) = setup_test_env();
+
+        let agents_dir = temp.path().join("profiles/opencode/test/agents");
+        fs::create_dir_all(&agents_dir).unwrap();
+        fs::write(agents_dir.join("reviewer.md"), "# Reviewer").unwrap();
+
+        let result =
+            uninstall_component_from_dir("reviewer", ComponentType::Agent, &target, &profiles_dir);
+        assert!(result.is_ok());
+        assert!(!agents_dir.join("reviewer.md").exists());
+    }
+
+    #[test]
+    fn uninstall_mcp_removes_server_entry() {
+        let (temp, target, profiles_dir) = setup_test_env();
+
+        let config = temp.path().join("profiles/opencode/test/opencode.jsonc");
+        fs::write(
+            &config,
+            r#"{
+                // comment
+                "mcp": {"github": {"type": "local"}, "web": {"type": "remote"}}
+            }"#,
+        )
+        .unwrap();
+
+        let result = uninstall_mcp_server_from_dir("github", &target, &profiles_dir).unwrap();
+        assert_eq!(result.component_type, "mcp");
+
+        let content = fs::read_to_string(&config).unwrap();
+        assert!(content.contains("// comment"));
+        let value: serde_json::Value =
+            serde_json::from_str(&strip_jsonc_comments(&content)).unwrap();
+        assert!(value["mcp"].get("github").is_none());
+        assert!(value["mcp"].get("web").is_some());
+
+        let missing = uninstall_mcp_server_from_dir("github", &target, &profiles_dir);
+        assert!(matches!(missing, Err(UninstallError::ComponentNotFound(_))));
+    }
+
+    fn manifest_entry(component_type: DependencyKind, name: &str, owner: &str) -> ManifestEntry {
+        ManifestEntry {
+            component_type,
+            name: name.to_string(),
+            source: SourceInfo {
+                owner: owner.to_string(),
+                repo: "repo".to_string(),
+                git_ref: None,
+                subpath: None,
+            },
+            requires: Vec::new(),
+            installed_at: "2025-01-02T00:00:00Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn prune_manifest_drops_missing_components() {
+        let (temp, _target, _profiles_dir) = setup_test_env();
+        let profile_dir = temp.path().join("profiles/opencode/test");
+        fs::create_dir_all(profile_dir.join("skills/present")).unwrap();
+
+        let mut manifest = InstallManifest::default();
+        manifest.add_entry(manifest_entry(DependencyKind::Skill, "present", "acme"));
+        manifest.add_entry(manifest_entry(DependencyKind::Skill, "gone", "acme"));
+        manifest.save(&manifest_path(&profile_dir)).unwrap();
+
+        let pruned = prune_manifest(&profile_dir, "opencode").unwrap();
+        assert_eq!(pruned.len(), 1);
+        assert_eq!(pruned[0].name, "gone");
+
+        let loaded = InstallManifest::load(&manifest_path(&profile_dir)).unwrap();
+        assert_eq!(loaded.entries.len(), 1);
+        assert_eq!(loaded.entries[0].name, "present");
+    }
+
+    #[test]
+    fn components_from_source_matches_owner_and_repo() {
+        let (temp, _target, _profiles_dir) = setup_test_env();
+        let profile_dir = temp.path().join("profiles/opencode/test");
+
+        let mut manifest = InstallManifest::default();
+        manifest.add_entry(manifest_entry(DependencyKind::Skill, "a", "acme"));
+        manifest.add_entry(manifest_entry(DependencyKind::Agent, "b", "Acme"));
+        manifest.add_entry(manifest_entry(DependencyKind::Skill, "c", "other"));
+        manifest.save(&manifest_path(&profile_dir)).unwrap();
+
+        let components = components_from_source(&profile_dir, "acme", "repo", None).unwrap();
+        let names: Vec<&str> = components.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["a", "b"]);
+    }
 }
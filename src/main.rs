@@ -6,9 +6,15 @@ mod harness;
 mod install;
 mod tui;
 
+use std::path::PathBuf;
+
 use clap::Parser;
 use cli::output::OutputFormat;
-use cli::{Commands, ConfigCommands, ProfileCommands};
+use cli::{
+    Commands, ConfigCommands, HooksCommands, ModelCommands, PermissionsCommands, PluginCommands,
+    ProfileCommands, RulesCommands, TrashCommands,
+};
+use config::PermissionEffect;
 
 #[derive(Parser)]
 #[command(name = "bridle")]
@@ -17,6 +23,10 @@ struct Cli {
     #[arg(long, short = 'o', default_value = "auto", global = true)]
     output: OutputFormat,
 
+    /// Manage the project-scope config of this checkout instead of the global one.
+    #[arg(long, value_name = "PATH", global = true)]
+    project: Option<PathBuf>,
+
     #[command(subcommand)]
     command: Option<Commands>,
 }
@@ -26,15 +36,19 @@ fn main() -> color_eyre::Result<()> {
 
     let cli = Cli::parse();
     let format = cli.output.resolve();
+    let project = cli.project.as_deref();
 
     match cli.command {
         None | Some(Commands::Tui) => cli::tui::run_tui()?,
         Some(Commands::Status) => cli::status::display_status(format),
         Some(Commands::Init) => cli::init::run_init()?,
+        Some(Commands::Harnesses) => cli::harnesses::list(format)?,
         Some(Commands::Profile(profile_cmd)) => match profile_cmd {
-            ProfileCommands::List { harness } => cli::profile::list_profiles(&harness, format)?,
+            ProfileCommands::List { harness } => {
+                cli::profile::list_profiles(&harness, project, format)?
+            }
             ProfileCommands::Show { harness, name } => {
-                cli::profile::show_profile(&harness, &name, format)?
+                cli::profile::show_profile(&harness, &name, project, format)?
             }
             ProfileCommands::Create {
                 harness,
@@ -42,30 +56,206 @@ fn main() -> color_eyre::Result<()> {
                 from_current,
             } => {
                 if from_current {
-                    cli::profile::create_profile_from_current(&harness, &name)?
+                    cli::profile::create_profile_from_current(&harness, &name, project)?
                 } else {
-                    cli::profile::create_profile(&harness, &name)?
+                    cli::profile::create_profile(&harness, &name, project)?
                 }
             }
             ProfileCommands::Delete { harness, name } => {
-                cli::profile::delete_profile(&harness, &name)?
+                cli::profile::delete_profile(&harness, &name, project)?
             }
             ProfileCommands::Switch { harness, name } => {
-                cli::profile::switch_profile(&harness, &name)?
+                cli::profile::switch_profile(&harness, &name, project)?
+            }
+            ProfileCommands::Edit { harness, name } => {
+                cli::profile::edit_profile(&harness, &name, project)?
             }
-            ProfileCommands::Edit { harness, name } => cli::profile::edit_profile(&harness, &name)?,
             ProfileCommands::Diff {
                 harness,
                 name,
                 other,
-            } => cli::profile::diff_profiles(&harness, &name, other.as_deref())?,
+            } => cli::profile::diff_profiles(&harness, &name, other.as_deref(), project)?,
+            ProfileCommands::Status { harness } => {
+                cli::profile::profile_status(&harness, project, format)?
+            }
+            ProfileCommands::Save { harness } => cli::profile::save_profile(&harness, project)?,
+            ProfileCommands::Reset { harness } => cli::profile::reset_profile(&harness, project)?,
         },
         Some(Commands::Config(config_cmd)) => match config_cmd {
             ConfigCommands::Set { key, value } => cli::config_cmd::set_config(&key, &value)?,
             ConfigCommands::Get { key } => cli::config_cmd::get_config(&key)?,
         },
-        Some(Commands::Install { source, force }) => cli::install::run(&source, force)?,
-        Some(Commands::Uninstall { harness, profile }) => cli::uninstall::run(&harness, &profile)?,
+        Some(Commands::Trash(trash_cmd)) => match trash_cmd {
+            TrashCommands::List { harness } => {
+                cli::trash::list(harness.as_deref(), project, format)?
+            }
+            TrashCommands::Restore { harness, name } => {
+                cli::trash::restore(&harness, &name, project)?
+            }
+            TrashCommands::Empty { harness, yes } => {
+                cli::trash::empty(harness.as_deref(), project, yes)?
+            }
+        },
+        Some(Commands::Rules(rules_cmd)) => match rules_cmd {
+            RulesCommands::Show { harness, profile } => {
+                cli::rules::show(&harness, profile.as_deref(), project, format)?
+            }
+            RulesCommands::Edit { harness, profile } => {
+                cli::rules::edit(&harness, profile.as_deref(), project)?
+            }
+            RulesCommands::Snippets => cli::rules::list_snippets(format)?,
+            RulesCommands::AddSnippet {
+                harness,
+                profile,
+                snippet,
+            } => cli::rules::add_snippet(&harness, &profile, &snippet, project)?,
+            RulesCommands::RemoveSnippet {
+                harness,
+                profile,
+                snippet,
+            } => cli::rules::remove_snippet(&harness, &profile, &snippet, project)?,
+            RulesCommands::Sync { file, profile } => {
+                cli::rules::sync(&file, profile.as_deref(), project, format)?
+            }
+        },
+        Some(Commands::Model(model_cmd)) => match model_cmd {
+            ModelCommands::Get { harness, profile } => {
+                cli::model::get(&harness, profile.as_deref(), project, format)?
+            }
+            ModelCommands::Set { harness, args } => {
+                cli::model::set(&harness, &args, project, format)?
+            }
+            ModelCommands::Aliases => cli::model::list_aliases(format)?,
+        },
+        Some(Commands::Plugin(plugin_cmd)) => match plugin_cmd {
+            PluginCommands::List { profile } => {
+                cli::plugin::list(profile.as_deref(), project, format)?
+            }
+            PluginCommands::Enable { plugin, profile } => {
+                cli::plugin::set_enabled(&plugin, profile.as_deref(), true, project)?
+            }
+            PluginCommands::Disable { plugin, profile } => {
+                cli::plugin::set_enabled(&plugin, profile.as_deref(), false, project)?
+            }
+            PluginCommands::AddMarketplace {
+                source,
+                profile,
+                name,
+            } => cli::plugin::add_marketplace(
+                &source,
+                profile.as_deref(),
+                name.as_deref(),
+                project,
+            )?,
+            PluginCommands::Remove { name, profile } => {
+                cli::plugin::remove(&name, profile.as_deref(), project)?
+            }
+            PluginCommands::Port {
+                plugin,
+                profile,
+                to,
+                to_profile,
+                force,
+            } => cli::plugin::port(
+                &plugin,
+                profile.as_deref(),
+                &to,
+                to_profile.as_deref(),
+                force,
+                project,
+                format,
+            )?,
+        },
+        Some(Commands::Permissions(permissions_cmd)) => match permissions_cmd {
+            PermissionsCommands::List { profile } => {
+                cli::permissions::list(profile.as_deref(), project, format)?
+            }
+            PermissionsCommands::Allow { rule, profile } => cli::permissions::add(
+                PermissionEffect::Allow,
+                &rule,
+                profile.as_deref(),
+                project,
+            )?,
+            PermissionsCommands::Ask { rule, profile } => cli::permissions::add(
+                PermissionEffect::Ask,
+                &rule,
+                profile.as_deref(),
+                project,
+            )?,
+            PermissionsCommands::Deny { rule, profile } => cli::permissions::add(
+                PermissionEffect::Deny,
+                &rule,
+                profile.as_deref(),
+                project,
+            )?,
+            PermissionsCommands::Remove { rule, profile } => {
+                cli::permissions::remove(&rule, profile.as_deref(), project)?
+            }
+            PermissionsCommands::Diff { profile, other } => {
+                cli::permissions::diff(&profile, other.as_deref(), project, format)?
+            }
+        },
+        Some(Commands::Hooks(hooks_cmd)) => match hooks_cmd {
+            HooksCommands::List { profile } => {
+                cli::hooks::list(profile.as_deref(), project, format)?
+            }
+            HooksCommands::Add {
+                event,
+                command,
+                profile,
+                matcher,
+            } => cli::hooks::add(
+                &event,
+                &command,
+                matcher.as_deref(),
+                profile.as_deref(),
+                project,
+            )?,
+            HooksCommands::Remove {
+                event,
+                command,
+                profile,
+                matcher,
+            } => cli::hooks::remove(
+                &event,
+                &command,
+                matcher.as_deref(),
+                profile.as_deref(),
+                project,
+            )?,
+        },
+        Some(Commands::Hook { dir }) => cli::auto::hook(dir.as_deref())?,
+        Some(Commands::ShellInit { shell }) => cli::auto::shell_init(shell),
+        Some(Commands::Which { dir }) => cli::auto::which(dir.as_deref(), format)?,
+        Some(Commands::Install {
+            sources,
+            force,
+            yes,
+            allow_risky,
+        }) => cli::install::run(cli::install::InstallArgs {
+            sources: &sources,
+            force,
+            yes,
+            allow_risky,
+            project,
+        })?,
+        Some(Commands::Search { query, registry }) => {
+            cli::search::run(&query, registry.as_deref(), format)?
+        }
+        Some(Commands::Uninstall {
+            harness,
+            profile,
+            components,
+            all_from,
+            yes,
+        }) => cli::uninstall::run(cli::uninstall::UninstallArgs {
+            harness: &harness,
+            profile: &profile,
+            components: &components,
+            all_from: all_from.as_deref(),
+            yes,
+            project,
+        })?,
     }
 
     Ok(())
@@ -0,0 +1,152 @@
+//! Component browser for the detail pane.
+//!
+//! Turns a profile's skills, agents, commands, and MCP servers into a flat
+//! selectable list annotated with manifest provenance, and renders previews.
+
+use std::path::Path;
+
+use ratatui::{
+    style::Modifier,
+    text::{Line, Span},
+};
+
+use crate::config::ProfileInfo;
+use crate::install::manifest::{InstallManifest, ManifestEntry, manifest_path};
+use crate::install::{Dependency, DependencyKind};
+use crate::tui::theme::Theme;
+
+/// One selectable component in the detail pane.
+#[derive(Debug, Clone)]
+pub struct ComponentEntry {
+    pub component: Dependency,
+    /// Enabled flag for MCP servers; `None` for file components.
+    pub enabled: Option<bool>,
+    /// Where the component was installed from, if bridle installed it.
+    pub provenance: Option<ManifestEntry>,
+}
+
+impl ComponentEntry {
+    /// Short `owner/repo · date` description of the provenance.
+    pub fn provenance_text(&self) -> Option<String> {
+        self.provenance
+            .as_ref()
+            .map(|entry| format!("{} · {}", entry.source, install_date(&entry.installed_at)))
+    }
+}
+
+/// Components in display order: MCP servers, skills, commands, agents.
+pub fn profile_components(profile: &ProfileInfo) -> Vec<ComponentEntry> {
+    let manifest = InstallManifest::load(&manifest_path(&profile.path)).unwrap_or_default();
+    let provenance = |component: &Dependency| {
+        manifest
+            .find_component(component.kind, &component.name)
+            .cloned()
+    };
+
+    let mut entries: Vec<ComponentEntry> = profile
+        .mcp_servers
+        .iter()
+        .map(|server| ComponentEntry {
+            component: Dependency::new(DependencyKind::Mcp, server.name.as_str()),
+            enabled: Some(server.enabled),
+            provenance: None,
+        })
+        .collect();
+
+    let groups = [
+        (DependencyKind::Skill, Some(&profile.skills)),
+        (DependencyKind::Command, Some(&profile.commands)),
+        (DependencyKind::Agent, profile.agents.as_ref()),
+    ];
+    for (kind, summary) in groups {
+        for name in summary.into_iter().flat_map(|s| &s.items) {
+            let component = Dependency::new(kind, name.as_str());
+            entries.push(ComponentEntry {
+                provenance: provenance(&component),
+                component,
+                enabled: None,
+            });
+        }
+    }
+
+    entries
+}
+
+fn install_date(installed_at: &str) -> String {
+    chrono::DateTime::parse_from_rfc3339(installed_at)
+        .map(|d| d.format("%Y-%m-%d").to_string())
+        .unwrap_or_else(|_| installed_at.to_string())
+}
+
+/// Scrollable preview of a component, shown in a popup.
+#[derive(Debug)]
+pub struct Preview {
+    pub title: String,
+    pub lines: Vec<Line<'static>>,
+    pub scroll: u16,
+}
+
+/// Lines for the preview popup: provenance, then the file rendered as markdown.
+pub fn preview_lines(entry: &ComponentEntry, path: &Path, content: &str) -> Vec<Line<'static>> {
+    let muted = Theme::muted_color();
+    let mut lines = vec![Line::styled(path.display().to_string(), muted)];
+    if let Some(provenance) = &entry.provenance {
+        lines.push(Line::from(vec![
+            Span::styled("Source: ", muted),
+            Span::raw(provenance.source.to_string()),
+            Span::styled("  Installed: ", muted),
+            Span::raw(install_date(&provenance.installed_at)),
+        ]));
+    }
+    lines.push(Line::from(""));
+
+    let is_markdown = path.extension().is_some_and(|e| e == "md");
+    if is_markdown {
+        lines.extend(markdown_lines(content));
+    } else {
+        lines.extend(content.lines().map(|l| Line::raw(l.to_string())));
+    }
+    lines
+}
+
+/// Lightweight markdown rendering: frontmatter as fields, headings, and code blocks.
+fn markdown_lines(content: &str) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    let mut body = content;
+
+    if let Some(rest) = content.trim_start().strip_prefix("---")
+        && let Some(end) = rest.find("\n---")
+    {
+        for field in rest[..end].lines().filter(|l| !l.trim().is_empty()) {
+            match field.split_once(':') {
+                Some((key, value)) if !key.starts_with(' ') => {
+                    lines.push(Line::from(vec![
+                        Span::styled(format!("{}:", key), Theme::accent()),
+                        Span::raw(value.to_string()),
+                    ]));
+                }
+                _ => lines.push(Line::raw(field.to_string())),
+            }
+        }
+        lines.push(Line::styled("─".repeat(40), Theme::muted_color()));
+        body = rest[end + 4..].trim_start_matches(['\n', '\r']);
+    }
+
+    let mut in_code = false;
+    for line in body.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+            lines.push(Line::styled(line.to_string(), Theme::muted_color()));
+        } else if in_code {
+            lines.push(Line::styled(line.to_string(), Theme::added()));
+        } else if line.starts_with('#') {
+            lines.push(Line::styled(
+                line.trim_start_matches('#').trim().to_string(),
+                Theme::accent().add_modifier(Modifier::BOLD),
+            ));
+        } else {
+            lines.push(Line::raw(line.to_string()));
+        }
+    }
+    lines
+}
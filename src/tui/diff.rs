@@ -0,0 +1,312 @@
+//! Side-by-side comparison of two profiles, or a profile and the live config.
+//!
+//! The top half shows a semantic summary per side; the bottom half lists
+//! changed files, each of which can be opened as a unified text diff.
+
+use crossterm::event::KeyCode;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+use crate::config::ProfileInfo;
+use crate::config::diff::{
+    FileChange, FileStatus, ProfileDiff, SectionDiff, changed_files, unified_file_diff,
+};
+use crate::tui::install::popup_area;
+use crate::tui::theme::Theme;
+
+/// Which side of the comparison a summary column shows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+struct FileView {
+    title: String,
+    lines: Vec<Line<'static>>,
+    scroll: u16,
+}
+
+#[derive(Debug)]
+pub struct DiffScreen {
+    left: ProfileInfo,
+    right: ProfileInfo,
+    diff: ProfileDiff,
+    files: Vec<FileChange>,
+    file_state: ListState,
+    file_view: Option<FileView>,
+    message: Option<String>,
+    open: bool,
+}
+
+impl DiffScreen {
+    pub fn new(left: ProfileInfo, right: ProfileInfo) -> Self {
+        let diff = ProfileDiff::new(&left, &right);
+        let (files, message) = match changed_files(&left.path, &right.path) {
+            Ok(files) => (files, None),
+            Err(e) => (Vec::new(), Some(format!("Failed to compare files: {}", e))),
+        };
+        let mut file_state = ListState::default();
+        if !files.is_empty() {
+            file_state.select(Some(0));
+        }
+
+        Self {
+            left,
+            right,
+            diff,
+            files,
+            file_state,
+            file_view: None,
+            message,
+            open: true,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn handle_key(&mut self, key: KeyCode) {
+        if let Some(view) = &mut self.file_view {
+            match key {
+                KeyCode::Up | KeyCode::Char('k') => view.scroll = view.scroll.saturating_sub(1),
+                KeyCode::Down | KeyCode::Char('j') => {
+                    let max = (view.lines.len() as u16).saturating_sub(1);
+                    view.scroll = (view.scroll + 1).min(max);
+                }
+                KeyCode::PageUp => view.scroll = view.scroll.saturating_sub(10),
+                KeyCode::PageDown => {
+                    let max = (view.lines.len() as u16).saturating_sub(1);
+                    view.scroll = (view.scroll + 10).min(max);
+                }
+                KeyCode::Esc | KeyCode::Char('q') | KeyCode::Backspace => self.file_view = None,
+                _ => {}
+            }
+            return;
+        }
+
+        match key {
+            KeyCode::Up | KeyCode::Char('k') => self.move_file(-1),
+            KeyCode::Down | KeyCode::Char('j') => self.move_file(1),
+            KeyCode::Enter => self.open_file(),
+            KeyCode::Esc | KeyCode::Char('q') => self.open = false,
+            _ => {}
+        }
+    }
+
+    fn move_file(&mut self, delta: isize) {
+        if self.files.is_empty() {
+            return;
+        }
+        let current = self.file_state.selected().unwrap_or(0) as isize;
+        let next = (current + delta).clamp(0, self.files.len() as isize - 1);
+        self.file_state.select(Some(next as usize));
+    }
+
+    fn open_file(&mut self) {
+        let Some(change) = self.file_state.selected().and_then(|i| self.files.get(i)) else {
+            return;
+        };
+        let text = unified_file_diff(&self.left.path, &self.right.path, &change.path);
+        self.file_view = Some(FileView {
+            title: change.path.display().to_string(),
+            lines: text.lines().map(diff_line).collect(),
+            scroll: 0,
+        });
+    }
+
+    pub fn render(&mut self, frame: &mut Frame) {
+        let area = popup_area(frame.area());
+        frame.render_widget(Clear, area);
+
+        let block = Block::default()
+            .title(format!(" Diff: {} → {} ", self.left.name, self.right.name))
+            .borders(Borders::ALL)
+            .border_style(Theme::border_active());
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(0),
+                Constraint::Length(1),
+                Constraint::Length(1),
+            ])
+            .split(inner);
+
+        if let Some(view) = &self.file_view {
+            let block = Block::default()
+                .title(format!(" {} ", view.title))
+                .borders(Borders::ALL);
+            frame.render_widget(
+                Paragraph::new(view.lines.clone())
+                    .block(block)
+                    .scroll((view.scroll, 0)),
+                chunks[0],
+            );
+        } else {
+            let halves = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+                .split(chunks[0]);
+            let columns = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(halves[0]);
+            self.render_summary(frame, columns[0], Side::Left);
+            self.render_summary(frame, columns[1], Side::Right);
+            self.render_files(frame, halves[1]);
+        }
+
+        let status = match &self.message {
+            Some(message) => message.clone(),
+            None if self.diff.is_empty() && self.files.is_empty() => {
+                "Profiles are identical".to_string()
+            }
+            None => format!("{} file(s) differ", self.files.len()),
+        };
+        frame.render_widget(
+            Paragraph::new(status).style(Theme::text_warning()),
+            chunks[1],
+        );
+        frame.render_widget(
+            Paragraph::new(self.hints()).style(Theme::text_muted()),
+            chunks[2],
+        );
+    }
+
+    fn hints(&self) -> &'static str {
+        if self.file_view.is_some() {
+            "↑/↓:scroll  PgUp/PgDn:page  Esc:back"
+        } else {
+            "↑/↓:file  Enter:show diff  Esc:close"
+        }
+    }
+
+    fn render_summary(&self, frame: &mut Frame, area: Rect, side: Side) {
+        let profile = match side {
+            Side::Left => &self.left,
+            Side::Right => &self.right,
+        };
+        let muted = Theme::muted_color();
+        let mut lines = Vec::new();
+
+        for field in &self.diff.fields {
+            let value = match side {
+                Side::Left => &field.left,
+                Side::Right => &field.right,
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("{}: ", field.label), muted),
+                Span::styled(
+                    value.clone().unwrap_or_else(|| "(not set)".to_string()),
+                    Theme::changed(),
+                ),
+            ]));
+        }
+        if !self.diff.fields.is_empty() {
+            lines.push(Line::from(""));
+        }
+
+        for section in &self.diff.sections {
+            lines.extend(section_lines(section, side));
+        }
+
+        let title = if profile.is_active {
+            format!(" {} (active) ", profile.name)
+        } else {
+            format!(" {} ", profile.name)
+        };
+        frame.render_widget(
+            Paragraph::new(lines).block(Block::default().title(title).borders(Borders::ALL)),
+            area,
+        );
+    }
+
+    fn render_files(&mut self, frame: &mut Frame, area: Rect) {
+        let items: Vec<ListItem> = self
+            .files
+            .iter()
+            .map(|change| {
+                let (marker, style) = match change.status {
+                    FileStatus::Added => ("+ ", Theme::added()),
+                    FileStatus::Removed => ("- ", Theme::removed()),
+                    FileStatus::Modified => ("~ ", Theme::changed()),
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(marker, style),
+                    Span::raw(change.path.display().to_string()),
+                ]))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .title(" Changed files ")
+                    .borders(Borders::ALL),
+            )
+            .highlight_style(Theme::selection())
+            .highlight_symbol("> ");
+        frame.render_stateful_widget(list, area, &mut self.file_state);
+    }
+}
+
+/// A section's items from one side: unique items highlighted, shared items muted.
+fn section_lines(section: &SectionDiff, side: Side) -> Vec<Line<'static>> {
+    let (unique, marker, style) = match side {
+        Side::Left => (&section.removed, "- ", Theme::removed()),
+        Side::Right => (&section.added, "+ ", Theme::added()),
+    };
+    if unique.is_empty() && section.changed.is_empty() && section.unchanged.is_empty() {
+        return Vec::new();
+    }
+
+    let mut lines = vec![Line::styled(
+        section.label,
+        Style::default().add_modifier(Modifier::BOLD),
+    )];
+    let item = |marker: &'static str, name: &str, style: Style| {
+        Line::from(vec![
+            Span::styled(format!("  {}", marker), style),
+            Span::styled(name.to_string(), style),
+        ])
+    };
+    lines.extend(unique.iter().map(|n| item(marker, n, style)));
+    lines.extend(
+        section
+            .changed
+            .iter()
+            .map(|n| item("~ ", n, Theme::changed())),
+    );
+    lines.extend(
+        section
+            .unchanged
+            .iter()
+            .map(|n| item("  ", n, Theme::muted_color())),
+    );
+    lines
+}
+
+fn diff_line(line: &str) -> Line<'static> {
+    let style = if line.starts_with("+++") || line.starts_with("---") {
+        Style::default().add_modifier(Modifier::BOLD)
+    } else if line.starts_with("@@") {
+        Theme::accent()
+    } else if line.starts_with('+') {
+        Theme::added()
+    } else if line.starts_with('-') {
+        Theme::removed()
+    } else {
+        Style::default()
+    };
+    Line::styled(line.to_string(), style)
+}
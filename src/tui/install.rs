@@ -0,0 +1,839 @@
+//! Install screen: discover components from a source and install them into profiles.
+//!
+//! Discovery runs on a background thread so the UI keeps redrawing while the
+//! repository archive downloads. The screen then walks through component
+//! selection, target selection, and a final report.
+
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+
+use crossterm::event::KeyCode;
+use harness_locate::{Harness, HarnessKind, Severity, validate_agent_for_harness};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+};
+
+use crate::config::{BridleConfig, ProfileManager};
+use crate::harness::HarnessConfig;
+use crate::install::compat::{
+    count_incompatible_agents, harness_supports_agents, harness_supports_commands,
+    harness_supports_mcp, harness_supports_skills, install_harnesses,
+};
+use crate::install::discovery::{discover_skills, normalize_source};
+use crate::install::installer::{
+    InstallOutcome, install_agent, install_command, install_mcp_server, install_skills,
+};
+use crate::install::registry::resolve_source;
+use crate::install::scan::{ScanReport, scan};
+use crate::install::{
+    Dependency, DependencyKind, DiscoveryResult, InstallFailure, InstallOptions, InstallReport,
+    InstallTarget,
+};
+
+use super::theme::Theme;
+
+const SPINNER: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+const TARGET_HARNESSES: [HarnessKind; 4] = [
+    HarnessKind::OpenCode,
+    HarnessKind::ClaudeCode,
+    HarnessKind::Goose,
+    HarnessKind::AmpCode,
+];
+
+type DiscoveryMessage = Result<DiscoveryResult, String>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Step {
+    Source,
+    Discovering,
+    Components,
+    Targets,
+    Done,
+}
+
+/// A discovered component in the selection list.
+#[derive(Debug)]
+struct ComponentRow {
+    component: Dependency,
+    description: Option<String>,
+    warnings: Vec<String>,
+    selected: bool,
+}
+
+/// A profile that components can be installed into.
+#[derive(Debug)]
+struct TargetRow {
+    target: InstallTarget,
+    is_active: bool,
+    warning: Option<String>,
+    disabled: bool,
+    selected: bool,
+}
+
+#[derive(Debug)]
+pub struct InstallScreen {
+    step: Step,
+    source: String,
+    receiver: Option<Receiver<DiscoveryMessage>>,
+    spinner: usize,
+    discovery: Option<DiscoveryResult>,
+    components: Vec<ComponentRow>,
+    targets: Vec<TargetRow>,
+    cursor: usize,
+    list_state: ListState,
+    report: Option<InstallReport>,
+    message: Option<String>,
+    open: bool,
+}
+
+impl InstallScreen {
+    pub fn new() -> Self {
+        Self {
+            step: Step::Source,
+            source: String::new(),
+            receiver: None,
+            spinner: 0,
+            discovery: None,
+            components: Vec::new(),
+            targets: Vec::new(),
+            cursor: 0,
+            list_state: ListState::default(),
+            report: None,
+            message: None,
+            open: true,
+        }
+    }
+
+    /// Whether the screen should stay on top of the main view.
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Whether anything was installed, so the caller knows to refresh profiles.
+    pub fn installed_anything(&self) -> bool {
+        self.report
+            .as_ref()
+            .is_some_and(|r| !r.installed.is_empty())
+    }
+
+    /// Advances the spinner and picks up a finished discovery.
+    pub fn tick(&mut self) {
+        let Some(receiver) = &self.receiver else {
+            return;
+        };
+        self.spinner = (self.spinner + 1) % SPINNER.len();
+
+        match receiver.try_recv() {
+            Ok(Ok(discovery)) => {
+                self.receiver = None;
+                self.load_discovery(discovery);
+            }
+            Ok(Err(e)) => {
+                self.receiver = None;
+                self.step = Step::Source;
+                self.message = Some(e);
+            }
+            Err(TryRecvError::Empty) => {}
+            Err(TryRecvError::Disconnected) => {
+                self.receiver = None;
+                self.step = Step::Source;
+                self.message = Some("Discovery stopped unexpectedly".to_string());
+            }
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyCode, manager: &ProfileManager) {
+        match self.step {
+            Step::Source => self.handle_source_key(key),
+            Step::Discovering => {
+                if key == KeyCode::Esc {
+                    // The worker thread finishes on its own; its result is dropped.
+                    self.receiver = None;
+                    self.step = Step::Source;
+                    self.message = Some("Discovery cancelled".to_string());
+                }
+            }
+            Step::Components => self.handle_components_key(key, manager),
+            Step::Targets => self.handle_targets_key(key),
+            Step::Done => {
+                if matches!(key, KeyCode::Enter | KeyCode::Esc | KeyCode::Char('q')) {
+                    self.open = false;
+                }
+            }
+        }
+    }
+
+    fn handle_source_key(&mut self, key: KeyCode) {
+        match key {
+            KeyCode::Enter => self.start_discovery(),
+            KeyCode::Esc => self.open = false,
+            KeyCode::Backspace => {
+                self.source.pop();
+            }
+            KeyCode::Char(c) => self.source.push(c),
+            _ => {}
+        }
+    }
+
+    fn handle_components_key(&mut self, key: KeyCode, manager: &ProfileManager) {
+        match key {
+            KeyCode::Up | KeyCode::Char('k') => self.move_cursor(-1, self.components.len()),
+            KeyCode::Down | KeyCode::Char('j') => self.move_cursor(1, self.components.len()),
+            KeyCode::Char(' ') => {
+                if let Some(row) = self.components.get_mut(self.cursor) {
+                    row.selected = !row.selected;
+                }
+            }
+            KeyCode::Char('a') => {
+                let select = !self.components.iter().all(|r| r.selected);
+                for row in &mut self.components {
+                    row.selected = select;
+                }
+            }
+            KeyCode::Enter => {
+                if !self.components.iter().any(|r| r.selected) {
+                    self.message = Some("Select at least one component".to_string());
+                    return;
+                }
+                self.select_dependencies();
+                self.load_targets(manager);
+                self.step = Step::Targets;
+                self.cursor = 0;
+            }
+            KeyCode::Esc => {
+                self.step = Step::Source;
+                self.message = None;
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_targets_key(&mut self, key: KeyCode) {
+        match key {
+            KeyCode::Up | KeyCode::Char('k') => self.move_cursor(-1, self.targets.len()),
+            KeyCode::Down | KeyCode::Char('j') => self.move_cursor(1, self.targets.len()),
+            KeyCode::Char(' ') => {
+                if let Some(row) = self.targets.get_mut(self.cursor) {
+                    if row.disabled {
+                        self.message = row.warning.clone();
+                    } else {
+                        row.selected = !row.selected;
+                    }
+                }
+            }
+            KeyCode::Enter => {
+                if !self.targets.iter().any(|t| t.selected) {
+                    self.message = Some("Select at least one target profile".to_string());
+                    return;
+                }
+                self.install();
+                self.step = Step::Done;
+            }
+            KeyCode::Esc => {
+                self.step = Step::Components;
+                self.cursor = 0;
+                self.message = None;
+            }
+            _ => {}
+        }
+    }
+
+    fn move_cursor(&mut self, delta: isize, len: usize) {
+        if len == 0 {
+            return;
+        }
+        self.cursor = (self.cursor as isize + delta).rem_euclid(len as isize) as usize;
+    }
+
+    fn start_discovery(&mut self) {
+        let source = self.source.trim().to_string();
+        if source.is_empty() {
+            self.message = Some("Enter owner/repo, a GitHub URL, or a registry name".to_string());
+            return;
+        }
+
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || {
+            let result = resolve_source(&source)
+                .map_err(|e| e.to_string())
+                .and_then(|resolved| {
+                    discover_skills(&normalize_source(&resolved)).map_err(|e| e.to_string())
+                });
+            let _ = sender.send(result);
+        });
+
+        self.receiver = Some(receiver);
+        self.step = Step::Discovering;
+        self.message = None;
+    }
+
+    fn load_discovery(&mut self, discovery: DiscoveryResult) {
+        let report = scan(&discovery);
+        let located: Vec<HarnessKind> = TARGET_HARNESSES
+            .into_iter()
+            .filter(|kind| Harness::locate(*kind).is_ok())
+            .collect();
+
+        let mut rows = Vec::new();
+        for skill in &discovery.skills {
+            rows.push(component_row(
+                &report,
+                DependencyKind::Skill,
+                &skill.name,
+                skill.description.as_deref(),
+                Vec::new(),
+            ));
+        }
+        for server in &discovery.mcp_servers {
+            rows.push(component_row(
+                &report,
+                DependencyKind::Mcp,
+                &server.name,
+                server.description.as_deref(),
+                Vec::new(),
+            ));
+        }
+        for agent in &discovery.agents {
+            let incompatible: Vec<String> = located
+                .iter()
+                .filter(|kind| {
+                    validate_agent_for_harness(&agent.content, **kind)
+                        .iter()
+                        .any(|i| i.severity == Severity::Error)
+                })
+                .map(|kind| Harness::new(*kind).id().to_string())
+                .collect();
+            let compatibility = if incompatible.is_empty() {
+                Vec::new()
+            } else {
+                vec![format!("incompatible with {}", incompatible.join(", "))]
+            };
+            rows.push(component_row(
+                &report,
+                DependencyKind::Agent,
+                &agent.name,
+                agent.description.as_deref(),
+                compatibility,
+            ));
+        }
+        for command in &discovery.commands {
+            rows.push(component_row(
+                &report,
+                DependencyKind::Command,
+                &command.name,
+                command.description.as_deref(),
+                Vec::new(),
+            ));
+        }
+
+        if !report.is_empty() {
+            self.message = Some(format!(
+                "Security scan flagged {} finding(s); flagged components start unselected",
+                report.findings.len()
+            ));
+        }
+        self.components = rows;
+        self.discovery = Some(discovery);
+        self.cursor = 0;
+        self.step = Step::Components;
+    }
+
+    /// Selects components that selected ones require, when this source provides them.
+    fn select_dependencies(&mut self) {
+        let Some(discovery) = &self.discovery else {
+            return;
+        };
+
+        let mut added = Vec::new();
+        loop {
+            let missing: Vec<Dependency> = self
+                .selected_requires(discovery)
+                .into_iter()
+                .filter(|dep| {
+                    self.components
+                        .iter()
+                        .any(|r| &r.component == dep && !r.selected)
+                })
+                .collect();
+            if missing.is_empty() {
+                break;
+            }
+            for dep in missing {
+                if let Some(row) = self.components.iter_mut().find(|r| r.component == dep) {
+                    row.selected = true;
+                }
+                added.push(dep.to_string());
+            }
+        }
+
+        if !added.is_empty() {
+            self.message = Some(format!("Also selected required: {}", added.join(", ")));
+        }
+    }
+
+    fn selected_requires(&self, discovery: &DiscoveryResult) -> Vec<Dependency> {
+        let is_selected = |kind, name: &str| self.is_selected(kind, name);
+        discovery
+            .skills
+            .iter()
+            .filter(|s| is_selected(DependencyKind::Skill, &s.name))
+            .flat_map(|s| s.requires.iter())
+            .chain(
+                discovery
+                    .agents
+                    .iter()
+                    .filter(|a| is_selected(DependencyKind::Agent, &a.name))
+                    .flat_map(|a| a.requires.iter()),
+            )
+            .chain(
+                discovery
+                    .commands
+                    .iter()
+                    .filter(|c| is_selected(DependencyKind::Command, &c.name))
+                    .flat_map(|c| c.requires.iter()),
+            )
+            .cloned()
+            .collect()
+    }
+
+    fn is_selected(&self, kind: DependencyKind, name: &str) -> bool {
+        self.components
+            .iter()
+            .any(|r| r.selected && r.component.kind == kind && r.component.name == name)
+    }
+
+    fn has_selected(&self, kind: DependencyKind) -> bool {
+        self.components
+            .iter()
+            .any(|r| r.selected && r.component.kind == kind)
+    }
+
+    fn load_targets(&mut self, manager: &ProfileManager) {
+        let config = BridleConfig::load().unwrap_or_default();
+        let has_skills = self.has_selected(DependencyKind::Skill);
+        let has_agents = self.has_selected(DependencyKind::Agent);
+        let has_commands = self.has_selected(DependencyKind::Command);
+        let has_mcp = self.has_selected(DependencyKind::Mcp);
+
+        self.targets.clear();
+        for entry in install_harnesses(None) {
+            let harness_id = entry.id.as_str();
+            let Ok(profiles) = manager.list_profiles(entry.config().as_ref()) else {
+                continue;
+            };
+
+            let supports_agents = harness_supports_agents(harness_id);
+            let supports_commands = harness_supports_commands(harness_id);
+            let can_install = (has_skills && harness_supports_skills(harness_id))
+                || (has_agents && supports_agents)
+                || (has_commands && supports_commands)
+                || (has_mcp && harness_supports_mcp(harness_id));
+
+            let mut warnings = Vec::new();
+            if has_agents && !supports_agents {
+                warnings.push("agents not supported".to_string());
+            }
+            if has_commands && !supports_commands {
+                warnings.push("commands not supported".to_string());
+            }
+            if has_agents && supports_agents {
+                let incompatible = self.discovery.as_ref().map_or(0, |d| {
+                    count_incompatible_agents(
+                        d.agents
+                            .iter()
+                            .filter(|a| self.is_selected(DependencyKind::Agent, &a.name)),
+                        entry.kind,
+                    )
+                });
+                if incompatible > 0 {
+                    warnings.push(format!("{} agent(s) incompatible", incompatible));
+                }
+            }
+
+            let active = config.active_profile_for(harness_id);
+            for profile in profiles {
+                let is_active = active == Some(profile.as_str());
+                let (warning, disabled) = if !can_install {
+                    (Some("no selected components supported".to_string()), true)
+                } else if warnings.is_empty() {
+                    (None, false)
+                } else {
+                    (Some(warnings.join("; ")), false)
+                };
+                self.targets.push(TargetRow {
+                    target: InstallTarget {
+                        harness: harness_id.to_string(),
+                        profile,
+                        project: None,
+                    },
+                    is_active,
+                    warning,
+                    disabled,
+                    selected: is_active && !disabled,
+                });
+            }
+        }
+
+        if self.targets.is_empty() {
+            self.message = Some("No profiles found. Create a profile first".to_string());
+        }
+    }
+
+    fn install(&mut self) {
+        let Some(discovery) = &self.discovery else {
+            return;
+        };
+        let options = InstallOptions::default();
+        let source = Some(&discovery.source);
+        let mut report = InstallReport::default();
+
+        for row in self.targets.iter().filter(|t| t.selected) {
+            let target = &row.target;
+
+            let skills: Vec<_> = discovery
+                .skills
+                .iter()
+                .filter(|s| self.is_selected(DependencyKind::Skill, &s.name))
+                .cloned()
+                .collect();
+            if !skills.is_empty() && harness_supports_skills(&target.harness) {
+                report.merge(install_skills(&skills, target, &options, source));
+            }
+
+            if harness_supports_agents(&target.harness) {
+                for agent in discovery
+                    .agents
+                    .iter()
+                    .filter(|a| self.is_selected(DependencyKind::Agent, &a.name))
+                {
+                    let outcome = install_agent(agent, target, &options, source);
+                    record_outcome(&mut report, &agent.name, target, outcome);
+                }
+            }
+
+            if harness_supports_commands(&target.harness) {
+                for command in discovery
+                    .commands
+                    .iter()
+                    .filter(|c| self.is_selected(DependencyKind::Command, &c.name))
+                {
+                    let outcome = install_command(command, target, &options, source);
+                    record_outcome(&mut report, &command.name, target, outcome);
+                }
+            }
+
+            if harness_supports_mcp(&target.harness) {
+                for server in discovery
+                    .mcp_servers
+                    .iter()
+                    .filter(|m| self.is_selected(DependencyKind::Mcp, &m.name))
+                {
+                    let outcome = install_mcp_server(server, target, &options, source);
+                    record_outcome(&mut report, &server.name, target, outcome);
+                }
+            }
+        }
+
+        self.message = None;
+        self.report = Some(report);
+    }
+
+    pub fn render(&mut self, frame: &mut Frame) {
+        let area = popup_area(frame.area());
+        frame.render_widget(Clear, area);
+
+        let title = match &self.discovery {
+            Some(discovery) if self.step != Step::Source => {
+                format!(" Install from {} ", discovery.source)
+            }
+            _ => " Install ".to_string(),
+        };
+        let block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(Theme::border_active());
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(0),
+                Constraint::Length(1),
+                Constraint::Length(1),
+            ])
+            .split(inner);
+
+        match self.step {
+            Step::Source => self.render_source(frame, chunks[0]),
+            Step::Discovering => self.render_discovering(frame, chunks[0]),
+            Step::Components => self.render_components(frame, chunks[0]),
+            Step::Targets => self.render_targets(frame, chunks[0]),
+            Step::Done => self.render_report(frame, chunks[0]),
+        }
+
+        if let Some(message) = &self.message {
+            frame.render_widget(
+                Paragraph::new(message.as_str()).style(Theme::text_warning()),
+                chunks[1],
+            );
+        }
+        frame.render_widget(
+            Paragraph::new(self.hints()).style(Theme::text_muted()),
+            chunks[2],
+        );
+    }
+
+    fn hints(&self) -> &'static str {
+        match self.step {
+            Step::Source => "Enter:discover  Esc:close",
+            Step::Discovering => "Esc:cancel",
+            Step::Components => "↑/↓:move  Space:toggle  a:all  Enter:choose targets  Esc:back",
+            Step::Targets => "↑/↓:move  Space:toggle  Enter:install  Esc:back",
+            Step::Done => "Enter/Esc:close",
+        }
+    }
+
+    fn render_source(&self, frame: &mut Frame, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(2),
+                Constraint::Length(3),
+                Constraint::Min(0),
+            ])
+            .split(area);
+
+        frame.render_widget(
+            Paragraph::new("Source: owner/repo, a GitHub URL, or a registry name.\nAppend //dir to limit discovery and @ref to pick a branch or tag."),
+            chunks[0],
+        );
+        let input = Paragraph::new(format!("{}█", self.source)).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Theme::text_warning()),
+        );
+        frame.render_widget(input, chunks[1]);
+    }
+
+    fn render_discovering(&self, frame: &mut Frame, area: Rect) {
+        let text = format!(
+            "{} Discovering components from {}...",
+            SPINNER[self.spinner],
+            self.source.trim()
+        );
+        frame.render_widget(Paragraph::new(text), area);
+    }
+
+    fn render_components(&mut self, frame: &mut Frame, area: Rect) {
+        let mut items = Vec::new();
+        let mut cursor_item = 0;
+        let mut current_kind = None;
+
+        for (idx, row) in self.components.iter().enumerate() {
+            if current_kind != Some(row.component.kind) {
+                current_kind = Some(row.component.kind);
+                items.push(ListItem::new(Line::styled(
+                    kind_label(row.component.kind),
+                    Style::default().add_modifier(Modifier::BOLD),
+                )));
+            }
+            if idx == self.cursor {
+                cursor_item = items.len();
+            }
+
+            let mut lines = vec![Line::from(vec![
+                Span::raw(checkbox(row.selected)),
+                Span::raw(row.component.name.clone()),
+                Span::styled(
+                    row.description
+                        .as_ref()
+                        .map(|d| format!(" — {}", d))
+                        .unwrap_or_default(),
+                    Theme::text_muted(),
+                ),
+            ])];
+            for warning in &row.warnings {
+                lines.push(Line::styled(
+                    format!("      ! {}", warning),
+                    Theme::text_warning(),
+                ));
+            }
+            items.push(ListItem::new(lines));
+        }
+
+        self.list_state.select(Some(cursor_item));
+        frame.render_stateful_widget(selection_list(items), area, &mut self.list_state);
+    }
+
+    fn render_targets(&mut self, frame: &mut Frame, area: Rect) {
+        let mut items = Vec::new();
+        let mut cursor_item = 0;
+        let mut current_harness: Option<&str> = None;
+
+        for (idx, row) in self.targets.iter().enumerate() {
+            if current_harness != Some(row.target.harness.as_str()) {
+                current_harness = Some(row.target.harness.as_str());
+                items.push(ListItem::new(Line::styled(
+                    row.target.harness.clone(),
+                    Style::default().add_modifier(Modifier::BOLD),
+                )));
+            }
+            if idx == self.cursor {
+                cursor_item = items.len();
+            }
+
+            let label = if row.is_active {
+                format!("{} (active)", row.target.profile)
+            } else {
+                row.target.profile.to_string()
+            };
+            let style = if row.disabled {
+                Theme::text_muted()
+            } else {
+                Style::default()
+            };
+            let mut spans = vec![Span::styled(
+                format!("{}{}", checkbox(row.selected), label),
+                style,
+            )];
+            if let Some(warning) = &row.warning {
+                spans.push(Span::styled(
+                    format!("  ! {}", warning),
+                    Theme::text_warning(),
+                ));
+            }
+            items.push(ListItem::new(Line::from(spans)));
+        }
+
+        self.list_state.select(Some(cursor_item));
+        frame.render_stateful_widget(selection_list(items), area, &mut self.list_state);
+    }
+
+    fn render_report(&self, frame: &mut Frame, area: Rect) {
+        let Some(report) = &self.report else {
+            return;
+        };
+
+        let mut lines = vec![
+            Line::styled(
+                format!(
+                    "Done! {} installed, {} skipped, {} failed",
+                    report.installed.len(),
+                    report.skipped.len(),
+                    report.errors.len()
+                ),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Line::from(""),
+        ];
+        for success in &report.installed {
+            lines.push(Line::styled(
+                format!("  + {} → {}", success.skill, target_label(&success.target)),
+                Theme::added(),
+            ));
+        }
+        for skip in &report.skipped {
+            lines.push(Line::styled(
+                format!(
+                    "  = {} → {} (already exists)",
+                    skip.skill,
+                    target_label(&skip.target)
+                ),
+                Theme::text_muted(),
+            ));
+        }
+        for error in &report.errors {
+            lines.push(Line::styled(
+                format!(
+                    "  ! {} → {}: {}",
+                    error.skill,
+                    target_label(&error.target),
+                    error.error
+                ),
+                Theme::error(),
+            ));
+        }
+
+        frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), area);
+    }
+}
+
+fn component_row(
+    report: &ScanReport,
+    kind: DependencyKind,
+    name: &str,
+    description: Option<&str>,
+    mut warnings: Vec<String>,
+) -> ComponentRow {
+    let component = Dependency::new(kind, name);
+    let findings: Vec<String> = report
+        .reasons_for(&component)
+        .into_iter()
+        .map(String::from)
+        .collect();
+    let flagged = !findings.is_empty();
+    warnings.extend(findings);
+
+    ComponentRow {
+        component,
+        description: description.map(String::from),
+        warnings,
+        selected: !flagged,
+    }
+}
+
+fn record_outcome(
+    report: &mut InstallReport,
+    name: &str,
+    target: &InstallTarget,
+    outcome: crate::install::installer::InstallResult,
+) {
+    match outcome {
+        Ok(InstallOutcome::Installed(success)) => report.installed.push(success),
+        Ok(InstallOutcome::Skipped(skip)) => report.skipped.push(skip),
+        Err(e) => report.errors.push(InstallFailure {
+            skill: name.to_string(),
+            target: target.clone(),
+            error: e.to_string(),
+        }),
+    }
+}
+
+fn kind_label(kind: DependencyKind) -> &'static str {
+    match kind {
+        DependencyKind::Skill => "Skills",
+        DependencyKind::Mcp => "MCP Servers",
+        DependencyKind::Agent => "Agents",
+        DependencyKind::Command => "Commands",
+    }
+}
+
+fn checkbox(selected: bool) -> &'static str {
+    if selected { "  [x] " } else { "  [ ] " }
+}
+
+fn target_label(target: &InstallTarget) -> String {
+    format!("{}/{}", target.harness, target.profile)
+}
+
+fn selection_list(items: Vec<ListItem<'_>>) -> List<'_> {
+    List::new(items)
+        .highlight_style(Theme::selection())
+        .highlight_symbol("> ")
+}
+
+pub(super) fn popup_area(area: Rect) -> Rect {
+    let width = (area.width * 4 / 5).max(40).min(area.width);
+    let height = (area.height * 4 / 5).max(10).min(area.height);
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
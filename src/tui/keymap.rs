@@ -0,0 +1,619 @@
+//! Remappable key bindings for the main TUI screen.
+//!
+//! A [`KeyPreset`] supplies the starting bindings; each `action = "key"` entry
+//! in `[tui.keys]` replaces the preset's keys for that action. Popups and text
+//! input keep their fixed keys.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::config::{KeyConfig, KeyPreset};
+use crate::tui::views::ViewMode;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Up,
+    Down,
+    PrevHarness,
+    NextHarness,
+    First,
+    Last,
+    CyclePane,
+    ToggleView,
+    Select,
+    Expand,
+    NewProfile,
+    DeleteProfile,
+    Undo,
+    Edit,
+    Install,
+    Refresh,
+    SetDefaultHarness,
+    Search,
+    Overview,
+    MarkDiffBase,
+    Diff,
+    ToggleMcp,
+    Uninstall,
+    CopyComponent,
+    Help,
+    Quit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Navigation,
+    Actions,
+    Components,
+    General,
+}
+
+impl Section {
+    fn title(self) -> &'static str {
+        match self {
+            Self::Navigation => "Navigation",
+            Self::Actions => "Actions",
+            Self::Components => "Components (Details pane)",
+            Self::General => "General",
+        }
+    }
+}
+
+impl Action {
+    const ALL: [Action; 26] = [
+        Action::Up,
+        Action::Down,
+        Action::PrevHarness,
+        Action::NextHarness,
+        Action::First,
+        Action::Last,
+        Action::CyclePane,
+        Action::ToggleView,
+        Action::Select,
+        Action::Expand,
+        Action::NewProfile,
+        Action::DeleteProfile,
+        Action::Undo,
+        Action::Edit,
+        Action::Install,
+        Action::Refresh,
+        Action::SetDefaultHarness,
+        Action::Search,
+        Action::Overview,
+        Action::MarkDiffBase,
+        Action::Diff,
+        Action::ToggleMcp,
+        Action::Uninstall,
+        Action::CopyComponent,
+        Action::Help,
+        Action::Quit,
+    ];
+
+    /// Name used in `[tui.keys]`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Up => "up",
+            Self::Down => "down",
+            Self::PrevHarness => "prev_harness",
+            Self::NextHarness => "next_harness",
+            Self::First => "first",
+            Self::Last => "last",
+            Self::CyclePane => "cycle_pane",
+            Self::ToggleView => "toggle_view",
+            Self::Select => "select",
+            Self::Expand => "expand",
+            Self::NewProfile => "new_profile",
+            Self::DeleteProfile => "delete_profile",
+            Self::Undo => "undo",
+            Self::Edit => "edit",
+            Self::Install => "install",
+            Self::Refresh => "refresh",
+            Self::SetDefaultHarness => "set_default_harness",
+            Self::Search => "search",
+            Self::Overview => "overview",
+            Self::MarkDiffBase => "mark_diff_base",
+            Self::Diff => "diff",
+            Self::ToggleMcp => "toggle_mcp",
+            Self::Uninstall => "uninstall",
+            Self::CopyComponent => "copy_component",
+            Self::Help => "help",
+            Self::Quit => "quit",
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Self::Up => "Move up / scroll",
+            Self::Down => "Move down / scroll",
+            Self::PrevHarness => "Previous harness",
+            Self::NextHarness => "Next harness",
+            Self::First => "First profile",
+            Self::Last => "Last profile",
+            Self::CyclePane => "Cycle panes",
+            Self::ToggleView => "Toggle view",
+            Self::Select => "Switch to profile / preview",
+            Self::Expand => "Expand profile",
+            Self::NewProfile => "New profile",
+            Self::DeleteProfile => "Delete profile (to trash)",
+            Self::Undo => "Undo last delete or switch",
+            Self::Edit => "Open in editor",
+            Self::Install => "Install from source",
+            Self::Refresh => "Refresh",
+            Self::SetDefaultHarness => "Set default harness",
+            Self::Search => "Search (Tab in search: filter)",
+            Self::Overview => "All harnesses at a glance",
+            Self::MarkDiffBase => "Mark profile as diff base",
+            Self::Diff => "Diff base (or live) vs selected",
+            Self::ToggleMcp => "Toggle MCP server",
+            Self::Uninstall => "Uninstall",
+            Self::CopyComponent => "Copy to profile",
+            Self::Help => "Toggle help",
+            Self::Quit => "Quit",
+        }
+    }
+
+    fn section(self) -> Section {
+        match self {
+            Self::Up
+            | Self::Down
+            | Self::PrevHarness
+            | Self::NextHarness
+            | Self::First
+            | Self::Last
+            | Self::CyclePane
+            | Self::ToggleView => Section::Navigation,
+            Self::ToggleMcp | Self::Uninstall | Self::CopyComponent => Section::Components,
+            Self::Help | Self::Quit => Section::General,
+            _ => Section::Actions,
+        }
+    }
+
+    /// Only meaningful while browsing components in the details pane.
+    pub fn component_only(self) -> bool {
+        self.section() == Section::Components
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|a| a.name() == name)
+    }
+
+    fn preset_keys(self, preset: KeyPreset) -> &'static [&'static str] {
+        use KeyPreset::{Default, Emacs, Vim};
+        match (self, preset) {
+            (Self::Up, Emacs) => &["up", "ctrl-p"],
+            (Self::Up, _) => &["up", "k"],
+            (Self::Down, Emacs) => &["down", "ctrl-n"],
+            (Self::Down, _) => &["down", "j"],
+            (Self::PrevHarness, Emacs) => &["left", "ctrl-b"],
+            (Self::PrevHarness, _) => &["left", "h"],
+            (Self::NextHarness, Emacs) => &["right", "ctrl-f"],
+            (Self::NextHarness, _) => &["right", "l"],
+            (Self::First, Vim) => &["g", "home"],
+            (Self::First, Emacs) => &["alt-<", "home"],
+            (Self::First, Default) => &["home"],
+            (Self::Last, Vim) => &["G", "end"],
+            (Self::Last, Emacs) => &["alt->", "end"],
+            (Self::Last, Default) => &["end"],
+            (Self::CyclePane, _) => &["tab"],
+            (Self::ToggleView, _) => &["f2"],
+            (Self::Select, _) => &["enter"],
+            (Self::Expand, _) => &["space"],
+            (Self::NewProfile, _) => &["n"],
+            (Self::DeleteProfile, _) => &["d"],
+            (Self::Undo, _) => &["u"],
+            (Self::Edit, _) => &["e"],
+            (Self::Install, _) => &["i"],
+            (Self::Refresh, _) => &["r"],
+            (Self::SetDefaultHarness, _) => &["f"],
+            (Self::Search, Emacs) => &["ctrl-s", "/"],
+            (Self::Search, _) => &["/"],
+            (Self::Overview, _) => &["o"],
+            (Self::MarkDiffBase, _) => &["m"],
+            (Self::Diff, _) => &["D"],
+            (Self::ToggleMcp, _) => &["t"],
+            (Self::Uninstall, _) => &["u"],
+            (Self::CopyComponent, _) => &["c"],
+            (Self::Help, _) => &["?"],
+            (Self::Quit, Emacs) => &["q", "ctrl-c", "esc"],
+            (Self::Quit, _) => &["q", "esc"],
+        }
+    }
+}
+
+/// A key press with the modifiers that matter for matching.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Key {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl Key {
+    fn from_event(event: KeyEvent) -> Self {
+        let mut modifiers = event.modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT);
+        // Shift is already reflected in the character itself.
+        if !matches!(event.code, KeyCode::Char(_)) {
+            modifiers |= event.modifiers & KeyModifiers::SHIFT;
+        }
+        Self {
+            code: event.code,
+            modifiers,
+        }
+    }
+
+    /// Parses `"q"`, `"G"`, `"ctrl-n"`, `"alt-<"`, `"shift-tab"`, `"f2"`, `"enter"`, ...
+    fn parse(spec: &str) -> Option<Self> {
+        let mut modifiers = KeyModifiers::NONE;
+        let mut rest = spec;
+        loop {
+            let lower = rest.to_ascii_lowercase();
+            let (modifier, len) = if lower.starts_with("ctrl-") {
+                (KeyModifiers::CONTROL, 5)
+            } else if lower.starts_with("alt-") {
+                (KeyModifiers::ALT, 4)
+            } else if lower.starts_with("shift-") {
+                (KeyModifiers::SHIFT, 6)
+            } else {
+                break;
+            };
+            if rest.len() == len {
+                break;
+            }
+            modifiers |= modifier;
+            rest = &rest[len..];
+        }
+
+        let mut chars = rest.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) if modifiers.contains(KeyModifiers::SHIFT) => {
+                modifiers.remove(KeyModifiers::SHIFT);
+                KeyCode::Char(c.to_ascii_uppercase())
+            }
+            (Some(c), None) => KeyCode::Char(c),
+            _ => match rest.to_ascii_lowercase().as_str() {
+                "esc" | "escape" => KeyCode::Esc,
+                "enter" | "return" => KeyCode::Enter,
+                "tab" => KeyCode::Tab,
+                "backtab" => KeyCode::BackTab,
+                "space" => KeyCode::Char(' '),
+                "backspace" => KeyCode::Backspace,
+                "delete" | "del" => KeyCode::Delete,
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                "pageup" => KeyCode::PageUp,
+                "pagedown" => KeyCode::PageDown,
+                name => {
+                    let n: u8 = name.strip_prefix('f')?.parse().ok()?;
+                    (1..=12).contains(&n).then_some(KeyCode::F(n))?
+                }
+            },
+        };
+        Some(Self { code, modifiers })
+    }
+
+    fn display(self) -> String {
+        let key = match self.code {
+            KeyCode::Char(' ') => "Space".to_string(),
+            KeyCode::Char(c) => c.to_string(),
+            KeyCode::Up => "↑".to_string(),
+            KeyCode::Down => "↓".to_string(),
+            KeyCode::Left => "←".to_string(),
+            KeyCode::Right => "→".to_string(),
+            KeyCode::Enter => "Enter".to_string(),
+            KeyCode::Esc => "Esc".to_string(),
+            KeyCode::Tab => "Tab".to_string(),
+            KeyCode::BackTab => "S-Tab".to_string(),
+            KeyCode::Home => "Home".to_string(),
+            KeyCode::End => "End".to_string(),
+            KeyCode::PageUp => "PgUp".to_string(),
+            KeyCode::PageDown => "PgDn".to_string(),
+            KeyCode::Backspace => "Bksp".to_string(),
+            KeyCode::Delete => "Del".to_string(),
+            KeyCode::F(n) => format!("F{}", n),
+            other => format!("{:?}", other),
+        };
+        let mut prefix = String::new();
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            prefix.push_str("C-");
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            prefix.push_str("M-");
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            prefix.push_str("S-");
+        }
+        prefix + &key
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    bindings: Vec<(Action, Vec<Key>)>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::new(&KeyConfig::default()).0
+    }
+}
+
+impl Keymap {
+    /// Builds the keymap from `[tui.keys]`, returning a warning for each
+    /// unknown action or unparseable key.
+    pub fn new(config: &KeyConfig) -> (Self, Vec<String>) {
+        let mut warnings = Vec::new();
+        let mut bindings: Vec<(Action, Vec<Key>)> = Action::ALL
+            .into_iter()
+            .map(|action| {
+                let keys = action
+                    .preset_keys(config.preset)
+                    .iter()
+                    .filter_map(|spec| Key::parse(spec))
+                    .collect();
+                (action, keys)
+            })
+            .collect();
+
+        for (name, keys) in &config.bindings {
+            let Some(action) = Action::from_name(name) else {
+                warnings.push(format!("unknown action '{}'", name));
+                continue;
+            };
+            let mut parsed = Vec::new();
+            for spec in keys.keys() {
+                match Key::parse(spec) {
+                    Some(key) => parsed.push(key),
+                    None => warnings.push(format!("invalid key '{}' for {}", spec, name)),
+                }
+            }
+            // An explicit binding takes its keys away from every other action.
+            for (other, keys) in bindings.iter_mut() {
+                if *other == action {
+                    keys.clone_from(&parsed);
+                } else {
+                    keys.retain(|k| !parsed.contains(k));
+                }
+            }
+        }
+
+        (Self { bindings }, warnings)
+    }
+
+    /// Every action bound to `event`, in help order. Component actions may share keys with
+    /// profile actions (e.g. `u` for uninstall and undo).
+    pub fn actions(&self, event: KeyEvent) -> Vec<Action> {
+        let key = Key::from_event(event);
+        self.bindings
+            .iter()
+            .filter(|(_, keys)| keys.contains(&key))
+            .map(|(action, _)| *action)
+            .collect()
+    }
+
+    /// Keys for `action` joined with `/`, e.g. `"j/↓"`.
+    pub fn keys_for(&self, action: Action) -> String {
+        self.bindings
+            .iter()
+            .find(|(a, _)| *a == action)
+            .map(|(_, keys)| {
+                keys.iter()
+                    .map(|k| k.display())
+                    .collect::<Vec<_>>()
+                    .join("/")
+            })
+            .unwrap_or_default()
+    }
+
+    /// Help modal contents: `(section title, [(keys, description)])`.
+    pub fn help_sections(&self) -> Vec<(&'static str, Vec<(String, &'static str)>)> {
+        let sections = [
+            Section::Navigation,
+            Section::Actions,
+            Section::Components,
+            Section::General,
+        ];
+        sections
+            .into_iter()
+            .map(|section| {
+                let rows = Action::ALL
+                    .into_iter()
+                    .filter(|a| a.section() == section)
+                    .map(|a| (self.keys_for(a), a.description()))
+                    .filter(|(keys, _)| !keys.is_empty())
+                    .collect();
+                (section.title(), rows)
+            })
+            .collect()
+    }
+
+    /// One-line key summary for the status bar.
+    pub fn status_hints(&self, view_mode: ViewMode) -> String {
+        let first = |action: Action| {
+            self.bindings
+                .iter()
+                .find(|(a, _)| *a == action)
+                .and_then(|(_, keys)| keys.first())
+                .map(|k| k.display())
+                .unwrap_or_default()
+        };
+        let pane_label = if view_mode == ViewMode::Legacy {
+            "pane"
+        } else {
+            "focus"
+        };
+        let hints = [
+            (Action::Quit, "quit"),
+            (Action::CyclePane, pane_label),
+            (Action::Select, "switch"),
+            (Action::NewProfile, "new"),
+            (Action::DeleteProfile, "del"),
+            (Action::Edit, "edit"),
+            (Action::Install, "install"),
+            (Action::Diff, "diff"),
+            (Action::Search, "search"),
+            (Action::Overview, "overview"),
+            (Action::Refresh, "refresh"),
+            (Action::Help, "help"),
+        ];
+
+        let mut parts = Vec::new();
+        if view_mode != ViewMode::Legacy {
+            parts.push(format!(
+                "{}/{}:harness",
+                first(Action::PrevHarness),
+                first(Action::NextHarness)
+            ));
+        }
+        parts.push(format!(
+            "{}/{}:profile",
+            first(Action::Up),
+            first(Action::Down)
+        ));
+        parts.extend(
+            hints
+                .iter()
+                .map(|(action, label)| (first(*action), label))
+                .filter(|(key, _)| !key.is_empty())
+                .map(|(key, label)| format!("{}:{}", key, label)),
+        );
+        parts.join("  ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::KeyList;
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> Key {
+        Key { code, modifiers }
+    }
+
+    fn press(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    fn config(preset: KeyPreset, bindings: &[(&str, KeyList)]) -> KeyConfig {
+        KeyConfig {
+            preset,
+            bindings: bindings
+                .iter()
+                .map(|(name, keys)| (name.to_string(), keys.clone()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn parse_reads_modifiers() {
+        assert_eq!(
+            Key::parse("ctrl-n"),
+            Some(key(KeyCode::Char('n'), KeyModifiers::CONTROL))
+        );
+        assert_eq!(
+            Key::parse("alt-<"),
+            Some(key(KeyCode::Char('<'), KeyModifiers::ALT))
+        );
+        // Shift on a character is folded into the character itself.
+        assert_eq!(
+            Key::parse("shift-x"),
+            Some(key(KeyCode::Char('X'), KeyModifiers::NONE))
+        );
+        assert_eq!(
+            Key::parse("shift-tab"),
+            Some(key(KeyCode::Tab, KeyModifiers::SHIFT))
+        );
+        // A bare modifier name is the character key it spells.
+        assert_eq!(
+            Key::parse("ctrl--"),
+            Some(key(KeyCode::Char('-'), KeyModifiers::CONTROL))
+        );
+    }
+
+    #[test]
+    fn parse_reads_named_keys_and_rejects_unknown_ones() {
+        assert_eq!(
+            Key::parse("F2"),
+            Some(key(KeyCode::F(2), KeyModifiers::NONE))
+        );
+        assert_eq!(
+            Key::parse("space"),
+            Some(key(KeyCode::Char(' '), KeyModifiers::NONE))
+        );
+        assert_eq!(
+            Key::parse("Esc"),
+            Some(key(KeyCode::Esc, KeyModifiers::NONE))
+        );
+        assert_eq!(Key::parse("f13"), None);
+        assert_eq!(Key::parse("hyper-x"), None);
+        assert_eq!(Key::parse(""), None);
+    }
+
+    #[test]
+    fn new_warns_about_unknown_actions_and_keys() {
+        let (keymap, warnings) = Keymap::new(&config(
+            KeyPreset::Default,
+            &[
+                ("teleport", KeyList::One("x".to_string())),
+                (
+                    "quit",
+                    KeyList::Many(vec!["x".to_string(), "bogus".to_string()]),
+                ),
+            ],
+        ));
+        assert_eq!(
+            warnings,
+            vec![
+                "invalid key 'bogus' for quit".to_string(),
+                "unknown action 'teleport'".to_string(),
+            ]
+        );
+        assert_eq!(keymap.keys_for(Action::Quit), "x");
+    }
+
+    #[test]
+    fn bindings_take_keys_from_other_actions() {
+        let (keymap, _) = Keymap::new(&config(
+            KeyPreset::Default,
+            &[("search", KeyList::One("n".to_string()))],
+        ));
+        assert_eq!(
+            keymap.actions(press(KeyCode::Char('n'), KeyModifiers::NONE)),
+            vec![Action::Search]
+        );
+        assert_eq!(keymap.keys_for(Action::NewProfile), "");
+    }
+
+    #[test]
+    fn presets_bind_their_movement_keys() {
+        let (vim, _) = Keymap::new(&config(KeyPreset::Vim, &[]));
+        assert_eq!(
+            vim.actions(press(KeyCode::Char('G'), KeyModifiers::SHIFT)),
+            vec![Action::Last]
+        );
+        assert_eq!(
+            vim.actions(press(KeyCode::Char('g'), KeyModifiers::NONE)),
+            vec![Action::First]
+        );
+
+        let (emacs, _) = Keymap::new(&config(KeyPreset::Emacs, &[]));
+        assert_eq!(
+            emacs.actions(press(KeyCode::Char('n'), KeyModifiers::CONTROL)),
+            vec![Action::Down]
+        );
+        assert_eq!(
+            emacs.actions(press(KeyCode::Char('<'), KeyModifiers::ALT)),
+            vec![Action::First]
+        );
+        assert_eq!(
+            emacs.actions(press(KeyCode::Char('s'), KeyModifiers::CONTROL)),
+            vec![Action::Search]
+        );
+        assert!(
+            emacs
+                .actions(press(KeyCode::Char('k'), KeyModifiers::NONE))
+                .is_empty()
+        );
+    }
+}
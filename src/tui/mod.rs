@@ -2,59 +2,61 @@
 //!
 //! Provides an interactive TUI for browsing harnesses, profiles, and their configurations.
 
+mod browser;
+mod diff;
+mod install;
+mod keymap;
+mod overview;
+mod search;
 mod theme;
+mod undo;
 mod views;
+mod watcher;
 mod widgets;
 
 use std::io::{self, Stdout};
 
 use crossterm::{
     event::{
-        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, MouseEvent,
-        MouseEventKind,
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyEventKind,
+        MouseEvent, MouseEventKind,
     },
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use harness_locate::{Harness, HarnessKind, InstallationStatus};
 
-use crate::harness::HarnessConfig;
+use crate::harness::{HarnessConfig, HarnessRegistry};
 use ratatui::{
     Frame, Terminal,
     layout::{Constraint, Direction, Layout, Rect},
     prelude::{Alignment, CrosstermBackend},
-    style::{Color, Modifier, Style},
-    text::{Line, Span, Text},
+    style::{Modifier, Style},
+    text::{Line, Text},
     widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, TableState},
 };
 
 use crate::config::{BridleConfig, ProfileInfo, ProfileManager, ProfileName};
 use crate::error::Error;
-use views::ViewMode;
-use widgets::{DetailPane, HarnessTabs, ProfileTable, StatusBar};
+use crate::install::components::{component_file, copy_component, set_mcp_enabled};
+use crate::install::manifest::{InstallManifest, manifest_path};
+use crate::install::mcp_config::mcp_store;
+use crate::install::uninstaller::uninstall_components;
+use crate::install::{DependencyKind, InstallTarget};
+use browser::{ComponentEntry, Preview, preview_lines, profile_components};
+use diff::DiffScreen;
+use install::InstallScreen;
+use keymap::{Action, Keymap};
+use overview::{OverviewAction, OverviewScreen};
+use search::{MatchKind, SearchAction, SearchOverlay, profile_matches};
+use theme::Theme;
+use undo::{Operation, UndoStack};
+use views::{CardView, CardViewState, ViewMode};
+use watcher::{ConfigWatcher, DriftCheck};
+use widgets::{ComponentList, DetailPane, HarnessTabs, ProfileTable, StatusBar};
 
 type Tui = Terminal<CrosstermBackend<Stdout>>;
 
-fn harness_id(kind: &HarnessKind) -> &'static str {
-    match kind {
-        HarnessKind::ClaudeCode => "claude-code",
-        HarnessKind::OpenCode => "opencode",
-        HarnessKind::Goose => "goose",
-        HarnessKind::AmpCode => "amp-code",
-        _ => "unknown",
-    }
-}
-
-fn harness_name(kind: &HarnessKind) -> &'static str {
-    match kind {
-        HarnessKind::ClaudeCode => "Claude Code",
-        HarnessKind::OpenCode => "OpenCode",
-        HarnessKind::Goose => "Goose",
-        HarnessKind::AmpCode => "AMP Code",
-        _ => "Unknown",
-    }
-}
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Pane {
     Harnesses,
@@ -68,6 +70,8 @@ enum InputMode {
     Normal,
     CreatingProfile,
     ConfirmingDelete,
+    CopyingComponent,
+    ConfirmingUninstall,
 }
 
 #[derive(Debug)]
@@ -80,16 +84,35 @@ struct App {
     profiles: Vec<ProfileInfo>,
     profile_state: ListState,
     profile_table_state: TableState,
+    card_state: CardViewState,
     expanded_profile: Option<usize>,
     status_message: Option<String>,
     bridle_config: BridleConfig,
     manager: ProfileManager,
     show_help: bool,
+    keymap: Keymap,
     input_mode: InputMode,
     input_buffer: String,
+    install_screen: Option<InstallScreen>,
+    diff_screen: Option<DiffScreen>,
+    overview: Option<OverviewScreen>,
+    undo: UndoStack,
+    /// Profile marked with `m` as the left-hand side of the next diff.
+    diff_base: Option<(HarnessKind, String)>,
+    watcher: Option<ConfigWatcher>,
+    search: Option<SearchOverlay>,
+    /// Query narrowing the profile table, set from the search overlay.
+    profile_filter: Option<String>,
+    /// Files where the live config differs from the active profile.
+    unsaved_changes: usize,
+    /// Pending count for `unsaved_changes`.
+    drift_check: Option<DriftCheck>,
     needs_full_redraw: bool,
     detail_scroll: u16,
     detail_content_height: u16,
+    components: Vec<ComponentEntry>,
+    component_state: ListState,
+    preview: Option<Preview>,
     harness_area: Option<Rect>,
     profile_area: Option<Rect>,
     detail_area: Option<Rect>,
@@ -98,9 +121,12 @@ struct App {
 impl App {
     fn new() -> Result<Self, Error> {
         let bridle_config = BridleConfig::load()?;
+        let (keymap, mut warnings) = Keymap::new(&bridle_config.tui.keys);
+        warnings.extend(Theme::init(&bridle_config.tui.theme));
         let profiles_dir = BridleConfig::profiles_dir()?;
         let manager = ProfileManager::new(profiles_dir);
         let harnesses = HarnessKind::ALL.to_vec();
+        let watcher = ConfigWatcher::new(manager.profiles_dir(), &harnesses).ok();
 
         for kind in &harnesses {
             let harness = Harness::new(*kind);
@@ -109,29 +135,45 @@ impl App {
         let mut harness_state = ListState::default();
         let default_idx = bridle_config
             .default_harness()
-            .and_then(|id| harnesses.iter().position(|h| harness_id(h) == id))
+            .and_then(|id| harnesses.iter().position(|h| HarnessRegistry::id(*h) == id))
             .unwrap_or(0);
         harness_state.select(Some(default_idx));
 
         let mut app = Self {
             running: true,
-            view_mode: ViewMode::default(),
+            view_mode: ViewMode::from_config(bridle_config.tui.view),
             active_pane: Pane::Profiles,
             harnesses,
             harness_state,
             profiles: Vec::new(),
             profile_state: ListState::default(),
             profile_table_state: TableState::default(),
+            card_state: CardViewState::default(),
             expanded_profile: None,
-            status_message: None,
+            status_message: (!warnings.is_empty())
+                .then(|| format!("Config: {}", warnings.join("; "))),
             bridle_config,
             manager,
             show_help: false,
+            keymap,
             input_mode: InputMode::Normal,
             input_buffer: String::new(),
+            install_screen: None,
+            diff_screen: None,
+            overview: None,
+            undo: UndoStack::default(),
+            diff_base: None,
+            watcher,
+            search: None,
+            profile_filter: None,
+            unsaved_changes: 0,
+            drift_check: None,
             needs_full_redraw: false,
             detail_scroll: 0,
             detail_content_height: 0,
+            components: Vec::new(),
+            component_state: ListState::default(),
+            preview: None,
             harness_area: None,
             profile_area: None,
             detail_area: None,
@@ -165,6 +207,9 @@ impl App {
         let Some(kind) = self.selected_harness() else {
             return "No harness selected";
         };
+        if self.profile_filter.is_some() {
+            return "No profiles match the filter - press Esc to clear it";
+        }
         let harness = Harness::new(kind);
         match harness.installation_status() {
             Ok(InstallationStatus::NotInstalled) => "Harness not installed",
@@ -194,12 +239,62 @@ impl App {
                 }
             }
 
+            if let Some(query) = &self.profile_filter {
+                self.profiles.retain(|p| profile_matches(p, query));
+            }
+
             if !self.profiles.is_empty() {
                 self.profile_state.select(Some(0));
                 self.profile_table_state.select(Some(0));
                 self.update_detail_content_height();
             }
         }
+        self.refresh_drift();
+    }
+
+    /// Starts counting live config files that differ from the active profile's stored copy.
+    fn refresh_drift(&mut self) {
+        self.unsaved_changes = 0;
+        self.drift_check = None;
+        let Some(kind) = self.selected_harness() else {
+            return;
+        };
+        let Some(active) = self.profiles.iter().find(|p| p.is_active) else {
+            return;
+        };
+        if let Ok(name) = ProfileName::new(&active.name) {
+            self.drift_check = Some(DriftCheck::spawn(&self.manager, vec![(kind, name)]));
+        }
+    }
+
+    /// Picks up the count started by [`Self::refresh_drift`].
+    fn poll_drift(&mut self) {
+        let Some(check) = &self.drift_check else {
+            return;
+        };
+        for (kind, drift) in check.poll() {
+            if self.selected_harness() == Some(kind) {
+                self.unsaved_changes = drift.unwrap_or(0);
+                self.drift_check = None;
+            }
+        }
+    }
+
+    /// Reloads the selected harness when its profiles or live config change on disk.
+    fn poll_watcher(&mut self) {
+        if self.input_mode != InputMode::Normal {
+            return;
+        }
+        let Some(watcher) = &mut self.watcher else {
+            return;
+        };
+        let changed = watcher.poll();
+        if self
+            .selected_harness()
+            .is_some_and(|kind| changed.contains(&kind))
+        {
+            self.reload_selected_profile();
+        }
     }
 
     fn next_harness(&mut self) {
@@ -260,6 +355,17 @@ impl App {
         self.update_detail_content_height();
     }
 
+    fn select_profile_at(&mut self, i: usize) {
+        if self.profiles.is_empty() {
+            return;
+        }
+        let i = i.min(self.profiles.len() - 1);
+        self.profile_state.select(Some(i));
+        self.profile_table_state.select(Some(i));
+        self.detail_scroll = 0;
+        self.update_detail_content_height();
+    }
+
     fn update_detail_content_height(&mut self) {
         self.detail_content_height = if let Some(idx) = self.profile_state.selected() {
             let profile = &self.profiles[idx];
@@ -268,6 +374,243 @@ impl App {
         } else {
             0
         };
+        self.load_components();
+    }
+
+    fn load_components(&mut self) {
+        self.components = self
+            .profile_state
+            .selected()
+            .and_then(|idx| self.profiles.get(idx))
+            .map(profile_components)
+            .unwrap_or_default();
+        self.component_state = ListState::default();
+        if !self.components.is_empty() {
+            self.component_state.select(Some(0));
+        }
+    }
+
+    fn selected_component(&self) -> Option<&ComponentEntry> {
+        self.component_state
+            .selected()
+            .and_then(|idx| self.components.get(idx))
+    }
+
+    fn selected_target(&self) -> Option<InstallTarget> {
+        let kind = self.selected_harness()?;
+        let profile = self.profiles.get(self.profile_state.selected()?)?;
+        Some(InstallTarget {
+            harness: HarnessRegistry::id(kind).to_string(),
+            profile: ProfileName::new(&profile.name).ok()?,
+            project: None,
+        })
+    }
+
+    /// Re-reads profiles after a change, keeping the profile and component selection.
+    fn reload_selected_profile(&mut self) {
+        let profile_idx = self.profile_state.selected();
+        let component_idx = self.component_state.selected();
+        self.refresh_profiles();
+        if let Some(idx) = profile_idx.filter(|i| *i < self.profiles.len()) {
+            self.profile_state.select(Some(idx));
+            self.profile_table_state.select(Some(idx));
+            self.update_detail_content_height();
+        }
+        if let Some(idx) = component_idx
+            && !self.components.is_empty()
+        {
+            self.component_state
+                .select(Some(idx.min(self.components.len() - 1)));
+        }
+    }
+
+    fn move_component(&mut self, delta: isize) {
+        if self.components.is_empty() {
+            return;
+        }
+        let len = self.components.len() as isize;
+        let current = self.component_state.selected().unwrap_or(0) as isize;
+        self.component_state
+            .select(Some((current + delta).rem_euclid(len) as usize));
+    }
+
+    fn browsing_components(&self) -> bool {
+        self.view_mode == ViewMode::Dashboard
+            && self.active_pane == Pane::Details
+            && !self.components.is_empty()
+    }
+
+    /// Actions on the selected component; returns `false` for anything else.
+    fn handle_component_action(&mut self, action: Action) -> bool {
+        match action {
+            Action::Up => self.move_component(-1),
+            Action::Down => self.move_component(1),
+            Action::Select => self.preview_component(),
+            Action::ToggleMcp => self.toggle_component(),
+            Action::Edit => self.edit_component(),
+            Action::Uninstall => {
+                let Some(entry) = self.selected_component() else {
+                    return true;
+                };
+                let name = entry.component.to_string();
+                let dependents = self
+                    .profile_state
+                    .selected()
+                    .and_then(|idx| self.profiles.get(idx))
+                    .and_then(|p| InstallManifest::load(&manifest_path(&p.path)).ok())
+                    .map(|m| m.dependents(&entry.component).len())
+                    .unwrap_or(0);
+                self.input_buffer = if dependents > 0 {
+                    format!(
+                        "Uninstall '{}'? {} component(s) require it",
+                        name, dependents
+                    )
+                } else {
+                    format!("Uninstall '{}'?", name)
+                };
+                self.input_mode = InputMode::ConfirmingUninstall;
+            }
+            Action::CopyComponent => {
+                self.input_mode = InputMode::CopyingComponent;
+                self.input_buffer.clear();
+                self.status_message = Some("Enter target profile (Esc to cancel)".to_string());
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    fn preview_component(&mut self) {
+        let (Some(entry), Some(kind), Some(idx)) = (
+            self.selected_component(),
+            self.selected_harness(),
+            self.profile_state.selected(),
+        ) else {
+            return;
+        };
+        let profile_dir = &self.profiles[idx].path;
+        let harness_id = HarnessRegistry::id(kind);
+        let Some(path) = component_file(profile_dir, harness_id, &entry.component) else {
+            self.status_message = Some(format!("No file found for {}", entry.component));
+            return;
+        };
+
+        let content = if entry.component.kind == DependencyKind::Mcp {
+            mcp_store(harness_id)
+                .and_then(|store| store.get(&path, &entry.component.name))
+                .ok()
+                .flatten()
+                .and_then(|server| serde_json::to_string_pretty(&server).ok())
+        } else {
+            std::fs::read_to_string(&path).ok()
+        };
+        let Some(content) = content else {
+            self.status_message = Some(format!("Could not read {}", path.display()));
+            return;
+        };
+
+        self.preview = Some(Preview {
+            title: entry.component.to_string(),
+            lines: preview_lines(entry, &path, &content),
+            scroll: 0,
+        });
+    }
+
+    fn toggle_component(&mut self) {
+        let Some(entry) = self.selected_component() else {
+            return;
+        };
+        let Some(enabled) = entry.enabled else {
+            self.status_message = Some("Only MCP servers can be toggled".to_string());
+            return;
+        };
+        let Some(target) = self.selected_target() else {
+            return;
+        };
+        let name = entry.component.name.clone();
+
+        match set_mcp_enabled(&target, &name, !enabled) {
+            Ok(()) => {
+                let state = if enabled { "Disabled" } else { "Enabled" };
+                self.status_message = Some(format!("{} '{}'", state, name));
+                self.reload_selected_profile();
+            }
+            Err(e) => self.status_message = Some(format!("Toggle failed: {}", e)),
+        }
+    }
+
+    fn uninstall_component(&mut self) {
+        let (Some(entry), Some(target)) = (self.selected_component(), self.selected_target())
+        else {
+            return;
+        };
+        let component = entry.component.clone();
+
+        let report = uninstall_components(std::slice::from_ref(&component), &target);
+        self.status_message = Some(match report.errors.first() {
+            Some(error) => format!("Uninstall failed: {}", error.error),
+            None => format!("Uninstalled {}", component),
+        });
+        self.reload_selected_profile();
+    }
+
+    fn copy_component_from_input(&mut self) {
+        let name = self.input_buffer.trim().to_string();
+        self.input_mode = InputMode::Normal;
+        self.input_buffer.clear();
+
+        let (Some(entry), Some(from)) = (self.selected_component(), self.selected_target()) else {
+            return;
+        };
+        let Ok(profile) = ProfileName::new(&name) else {
+            self.status_message = Some("Invalid profile name".to_string());
+            return;
+        };
+        let to = InstallTarget {
+            harness: from.harness.clone(),
+            profile,
+            project: None,
+        };
+
+        self.status_message = Some(match copy_component(&entry.component, &from, &to) {
+            Ok(_) => format!("Copied {} to '{}'", entry.component, name),
+            Err(e) => format!("Copy failed: {}", e),
+        });
+        self.reload_selected_profile();
+    }
+
+    fn edit_component(&mut self) {
+        let (Some(entry), Some(kind), Some(idx)) = (
+            self.selected_component(),
+            self.selected_harness(),
+            self.profile_state.selected(),
+        ) else {
+            return;
+        };
+        let Some(path) = component_file(
+            &self.profiles[idx].path,
+            HarnessRegistry::id(kind),
+            &entry.component,
+        ) else {
+            self.status_message = Some(format!("No file found for {}", entry.component));
+            return;
+        };
+        let name = entry.component.to_string();
+        let editor = self.bridle_config.editor();
+
+        let _ = restore_terminal_for_editor();
+        let status = std::process::Command::new(&editor).arg(&path).status();
+        let _ = reinit_terminal_after_editor();
+        self.needs_full_redraw = true;
+
+        match status {
+            Ok(s) if s.success() => {
+                self.status_message = Some(format!("Edited {}", name));
+                self.reload_selected_profile();
+            }
+            Ok(s) => self.status_message = Some(format!("Editor exited: {}", s)),
+            Err(e) => self.status_message = Some(format!("Editor failed: {}", e)),
+        }
     }
 
     fn scroll_detail_up(&mut self) {
@@ -293,7 +636,7 @@ impl App {
                 if self.harness_area.is_some_and(|a| a.contains(pos)) {
                     let area = self.harness_area.unwrap();
 
-                    if self.view_mode == ViewMode::Dashboard {
+                    if self.view_mode != ViewMode::Legacy {
                         let inner_x = event.column.saturating_sub(area.x).saturating_sub(2);
                         let tab_width = 15;
                         let idx = (inner_x / tab_width) as usize;
@@ -309,6 +652,10 @@ impl App {
                             self.refresh_profiles();
                         }
                     }
+                } else if self.view_mode == ViewMode::Cards
+                    && let Some(area) = self.profile_area
+                {
+                    self.click_card(area, pos);
                 } else if self.profile_area.is_some_and(|a| a.contains(pos)) {
                     self.active_pane = Pane::Profiles;
                     let area = self.profile_area.unwrap();
@@ -318,13 +665,16 @@ impl App {
                         self.profile_state.select(Some(idx));
                         self.profile_table_state.select(Some(idx));
                         self.detail_scroll = 0;
+                        self.update_detail_content_height();
                     }
                 } else if self.detail_area.is_some_and(|a| a.contains(pos)) {
                     self.active_pane = Pane::Details;
                 }
             }
             MouseEventKind::ScrollUp => {
-                if self.detail_area.is_some_and(|a| a.contains(pos)) {
+                if self.detail_area.is_some_and(|a| a.contains(pos)) && self.browsing_components() {
+                    self.move_component(-1);
+                } else if self.detail_area.is_some_and(|a| a.contains(pos)) {
                     self.scroll_detail_up();
                 } else if self.profile_area.is_some_and(|a| a.contains(pos)) {
                     self.prev_profile();
@@ -333,7 +683,9 @@ impl App {
                 }
             }
             MouseEventKind::ScrollDown => {
-                if self.detail_area.is_some_and(|a| a.contains(pos)) {
+                if self.detail_area.is_some_and(|a| a.contains(pos)) && self.browsing_components() {
+                    self.move_component(1);
+                } else if self.detail_area.is_some_and(|a| a.contains(pos)) {
                     self.scroll_detail_down();
                 } else if self.profile_area.is_some_and(|a| a.contains(pos)) {
                     self.next_profile();
@@ -345,6 +697,22 @@ impl App {
         }
     }
 
+    /// Clicking a profile card switches to it; the trailing card creates one.
+    fn click_card(&mut self, area: Rect, pos: ratatui::layout::Position) {
+        match CardView::new(&self.profiles).index_at(area, &self.card_state, pos) {
+            Some(idx) if idx < self.profiles.len() => {
+                self.select_profile_at(idx);
+                self.switch_to_selected();
+            }
+            Some(_) => {
+                self.input_mode = InputMode::CreatingProfile;
+                self.input_buffer.clear();
+                self.status_message = Some("Enter profile name (Esc to cancel)".to_string());
+            }
+            None => {}
+        }
+    }
+
     fn delete_selected(&mut self) {
         let Some(kind) = self.selected_harness() else {
             return;
@@ -361,8 +729,13 @@ impl App {
         };
 
         match self.manager.delete_profile(&harness, &profile_name) {
-            Ok(()) => {
-                self.status_message = Some(format!("Deleted '{}'", profile.name));
+            Ok(trashed) => {
+                self.status_message =
+                    Some(format!("Moved '{}' to trash (u to undo)", profile.name));
+                self.undo.push(Operation::Delete {
+                    harness: kind,
+                    trashed,
+                });
                 self.refresh_profiles();
             }
             Err(e) => {
@@ -439,24 +812,11 @@ impl App {
             return;
         }
 
-        let harness = Harness::new(kind);
-        let Ok(profile_name) = ProfileName::new(&profile.name) else {
-            self.status_message = Some("Invalid profile name".to_string());
-            return;
-        };
-
-        if let Err(e) = self.manager.backup_current(&harness) {
-            self.status_message = Some(format!("Backup failed: {}", e));
-            return;
-        }
-
-        match self
-            .manager
-            .switch_profile_with_resources(&harness, Some(&harness), &profile_name)
-        {
-            Ok(_) => {
+        let name = profile.name.clone();
+        match self.switch_harness(kind, &name) {
+            Ok(()) => {
                 self.bridle_config = BridleConfig::load().unwrap_or_default();
-                self.status_message = Some(format!("Switched to '{}'", profile.name));
+                self.status_message = Some(format!("Switched to '{}'", name));
                 let selected_idx = self.profile_state.selected();
                 self.refresh_profiles();
                 if let Some(idx) = selected_idx {
@@ -470,39 +830,319 @@ impl App {
         }
     }
 
-    fn handle_key(&mut self, key: KeyCode) {
-        if self.show_help {
-            match key {
-                KeyCode::Char('?') | KeyCode::Esc | KeyCode::Char('q') => {
-                    self.show_help = false;
+    /// Backs up the live config of `kind`, then switches it to `name`.
+    fn switch_harness(&mut self, kind: HarnessKind, name: &str) -> Result<(), Error> {
+        let harness = Harness::new(kind);
+        let profile_name =
+            ProfileName::new(name).map_err(|_| Error::InvalidProfileName(name.to_string()))?;
+        let previous = BridleConfig::load()
+            .ok()
+            .and_then(|c| c.active_profile_for(harness.id()).map(str::to_string));
+        let backup = self.manager.backup_current(&harness)?;
+        self.manager
+            .switch_profile_with_resources(&harness, Some(&harness), &profile_name)?;
+        self.undo.push(Operation::Switch {
+            harness: kind,
+            previous,
+            to: name.to_string(),
+            backup,
+        });
+        Ok(())
+    }
+
+    /// Reverses the most recent delete or switch.
+    fn undo_last(&mut self) {
+        let Some((kind, result)) = self.undo.undo(&self.manager) else {
+            self.status_message = Some("Nothing to undo".to_string());
+            return;
+        };
+        self.status_message = Some(match result {
+            Ok(done) => {
+                self.bridle_config = BridleConfig::load().unwrap_or_default();
+                if let Some(idx) = self.harnesses.iter().position(|h| *h == kind) {
+                    self.harness_state.select(Some(idx));
                 }
+                self.refresh_profiles();
+                match self.undo.len() {
+                    0 => done,
+                    n => format!("{} ({} more to undo)", done, n),
+                }
+            }
+            Err(e) => format!("Undo failed: {}", e),
+        });
+    }
+
+    /// Switches every harness that has a profile called `name` and is not
+    /// already on it.
+    fn switch_all(&mut self, name: &str) {
+        let mut switched = 0;
+        let mut failures = Vec::new();
+        for kind in self.harnesses.clone() {
+            let harness = Harness::new(kind);
+            if self.bridle_config.active_profile_for(harness.id()) == Some(name) {
+                continue;
+            }
+            let has_profile =
+                ProfileName::new(name).is_ok_and(|n| self.manager.profile_exists(&harness, &n));
+            if !has_profile {
+                continue;
+            }
+            match self.switch_harness(kind, name) {
+                Ok(()) => switched += 1,
+                Err(e) => failures.push(format!("{}: {}", HarnessRegistry::name(kind), e)),
+            }
+        }
+
+        self.bridle_config = BridleConfig::load().unwrap_or_default();
+        self.refresh_profiles();
+        self.status_message = Some(if failures.is_empty() {
+            format!("Switched {} harness(es) to '{}'", switched, name)
+        } else {
+            format!(
+                "Switched {} harness(es) to '{}'; failed: {}",
+                switched,
+                name,
+                failures.join("; ")
+            )
+        });
+    }
+
+    fn open_overview(&mut self) {
+        self.overview = Some(OverviewScreen::new(
+            &self.manager,
+            &self.bridle_config,
+            &self.harnesses,
+            self.selected_harness(),
+        ));
+    }
+
+    fn selected_profile(&self) -> Option<&ProfileInfo> {
+        self.profile_state
+            .selected()
+            .and_then(|i| self.profiles.get(i))
+    }
+
+    fn diff_base_name(&self) -> Option<&str> {
+        match (&self.diff_base, self.selected_harness()) {
+            (Some((kind, name)), Some(selected)) if *kind == selected => Some(name.as_str()),
+            _ => None,
+        }
+    }
+
+    fn mark_diff_base(&mut self) {
+        let (Some(kind), Some(profile)) = (self.selected_harness(), self.selected_profile()) else {
+            self.status_message = Some("No profile selected".to_string());
+            return;
+        };
+        let name = profile.name.clone();
+        if self.diff_base_name() == Some(name.as_str()) {
+            self.diff_base = None;
+            self.status_message = Some(format!("Unmarked '{}'", name));
+        } else {
+            self.status_message = Some(format!(
+                "Marked '{}' as diff base; select another profile and press D",
+                name
+            ));
+            self.diff_base = Some((kind, name));
+        }
+    }
+
+    /// Diffs the marked profile (or the live config) against the selected one.
+    fn open_diff(&mut self) {
+        let (Some(kind), Some(right)) = (self.selected_harness(), self.selected_profile().cloned())
+        else {
+            self.status_message = Some("No profile selected".to_string());
+            return;
+        };
+        let harness = Harness::new(kind);
+
+        let left = match self.diff_base_name() {
+            Some(base) if base != right.name => self
+                .profiles
+                .iter()
+                .find(|p| p.name == base)
+                .cloned()
+                .ok_or_else(|| Error::ProfileNotFound(base.to_string())),
+            _ => self.manager.show_live(&harness),
+        };
+        match left {
+            Ok(left) => self.diff_screen = Some(DiffScreen::new(left, right)),
+            Err(e) => self.status_message = Some(format!("Diff failed: {}", e)),
+        }
+    }
+
+    /// Narrows the profile table to profiles matching `query`; empty clears the filter.
+    fn set_profile_filter(&mut self, query: String) {
+        if query.is_empty() {
+            self.profile_filter = None;
+            self.status_message = Some("Filter cleared".to_string());
+        } else {
+            self.status_message = Some(format!("Filter: {} (Esc to clear)", query));
+            self.profile_filter = Some(query);
+        }
+        self.refresh_profiles();
+    }
+
+    /// Selects a search result's harness and profile, and its component if it names one.
+    fn jump_to(&mut self, harness: HarnessKind, profile: &str, kind: MatchKind, text: &str) {
+        let Some(harness_idx) = self.harnesses.iter().position(|h| *h == harness) else {
+            return;
+        };
+        self.profile_filter = None;
+        self.harness_state.select(Some(harness_idx));
+        self.refresh_profiles();
+
+        let Some(profile_idx) = self.profiles.iter().position(|p| p.name == profile) else {
+            self.status_message = Some(format!("Profile '{}' no longer exists", profile));
+            return;
+        };
+        self.profile_state.select(Some(profile_idx));
+        self.profile_table_state.select(Some(profile_idx));
+        self.detail_scroll = 0;
+        self.update_detail_content_height();
+        self.active_pane = Pane::Profiles;
+
+        if let MatchKind::Component(component_kind) = kind
+            && let Some(idx) = self
+                .components
+                .iter()
+                .position(|c| c.component.kind == component_kind && c.component.name == text)
+        {
+            self.component_state.select(Some(idx));
+            self.active_pane = Pane::Details;
+        }
+        self.status_message = Some(format!("{} / {}", HarnessRegistry::name(harness), profile));
+    }
+
+    fn handle_key(&mut self, event: KeyEvent) {
+        let key = event.code;
+        if let Some(screen) = &mut self.install_screen {
+            screen.handle_key(key, &self.manager);
+            if !screen.is_open() {
+                let installed = screen.installed_anything();
+                self.install_screen = None;
+                if installed {
+                    self.refresh_profiles();
+                    self.status_message = Some("Installed components".to_string());
+                }
+            }
+            return;
+        }
+
+        if let Some(screen) = &mut self.diff_screen {
+            screen.handle_key(key);
+            if !screen.is_open() {
+                self.diff_screen = None;
+            }
+            return;
+        }
+
+        if let Some(overview) = &mut self.overview {
+            match overview.handle_key(key) {
+                OverviewAction::None => {}
+                OverviewAction::Close => self.overview = None,
+                OverviewAction::Jump(kind) => {
+                    self.overview = None;
+                    if let Some(idx) = self.harnesses.iter().position(|h| *h == kind) {
+                        self.harness_state.select(Some(idx));
+                        self.refresh_profiles();
+                    }
+                }
+                OverviewAction::SwitchAll(name) => {
+                    self.switch_all(&name);
+                    self.open_overview();
+                }
+            }
+            return;
+        }
+
+        if let Some(search) = &mut self.search {
+            match search.handle_key(key) {
+                SearchAction::None => {}
+                SearchAction::Close => self.search = None,
+                SearchAction::Jump(entry) => {
+                    self.search = None;
+                    self.jump_to(entry.harness, &entry.profile, entry.kind, &entry.text);
+                }
+                SearchAction::Filter(query) => {
+                    self.search = None;
+                    self.set_profile_filter(query);
+                }
+            }
+            return;
+        }
+
+        let actions = self.keymap.actions(event);
+        let action = actions.first().copied();
+        if let Some(preview) = &mut self.preview {
+            match action {
+                Some(Action::Up) => {
+                    preview.scroll = preview.scroll.saturating_sub(1);
+                }
+                Some(Action::Down) => {
+                    let max = (preview.lines.len() as u16).saturating_sub(1);
+                    preview.scroll = (preview.scroll + 1).min(max);
+                }
+                Some(Action::Select | Action::Quit) => self.preview = None,
+                _ if key == KeyCode::Esc => self.preview = None,
                 _ => {}
             }
             return;
         }
 
+        if self.show_help {
+            if matches!(action, Some(Action::Help | Action::Quit)) || key == KeyCode::Esc {
+                self.show_help = false;
+            }
+            return;
+        }
+
         match self.input_mode {
-            InputMode::Normal => self.handle_normal_key(key),
+            InputMode::Normal => self.handle_normal_key(key, actions),
             InputMode::CreatingProfile => self.handle_input_key(key),
             InputMode::ConfirmingDelete => self.handle_confirm_delete_key(key),
+            InputMode::CopyingComponent => self.handle_input_key(key),
+            InputMode::ConfirmingUninstall => self.handle_confirm_delete_key(key),
         }
     }
 
-    fn handle_normal_key(&mut self, key: KeyCode) {
-        match key {
-            KeyCode::Char('q') | KeyCode::Esc => self.running = false,
-            KeyCode::Char('?') => self.show_help = true,
-            KeyCode::F(2) => {
+    fn handle_normal_key(&mut self, key: KeyCode, actions: Vec<Action>) {
+        if key == KeyCode::Esc && self.profile_filter.is_some() {
+            self.set_profile_filter(String::new());
+            return;
+        }
+        if self.browsing_components() && actions.iter().any(|a| self.handle_component_action(*a)) {
+            return;
+        }
+        let Some(action) = actions.into_iter().find(|a| !a.component_only()) else {
+            return;
+        };
+
+        match action {
+            Action::Quit => self.running = false,
+            Action::Search => {
+                self.search = Some(SearchOverlay::new(
+                    &self.manager,
+                    &self.harnesses,
+                    self.profile_filter.as_deref(),
+                ));
+            }
+            Action::Help => self.show_help = true,
+            Action::ToggleView => {
                 self.view_mode.toggle();
-                self.status_message = Some(format!("View: {}", self.view_mode.name()));
+                self.bridle_config.tui.view = self.view_mode.to_config();
+                self.status_message = Some(match self.bridle_config.save() {
+                    Ok(()) => format!("View: {}", self.view_mode.name()),
+                    Err(e) => format!("View: {} (not saved: {})", self.view_mode.name(), e),
+                });
             }
-            KeyCode::Tab => {
+            Action::CyclePane => {
                 self.active_pane = match self.active_pane {
                     Pane::Harnesses | Pane::Profiles => Pane::Details,
                     Pane::Details => Pane::Profiles,
                 };
             }
-            KeyCode::Up | KeyCode::Char('k') => match self.view_mode {
+            Action::Up => match self.view_mode {
                 ViewMode::Dashboard => {
                     if self.active_pane == Pane::Details {
                         self.scroll_detail_up();
@@ -515,10 +1155,9 @@ impl App {
                     Pane::Profiles => self.prev_profile(),
                     Pane::Details => self.scroll_detail_up(),
                 },
-                #[cfg(feature = "tui-cards")]
                 ViewMode::Cards => self.prev_profile(),
             },
-            KeyCode::Down | KeyCode::Char('j') => match self.view_mode {
+            Action::Down => match self.view_mode {
                 ViewMode::Dashboard => {
                     if self.active_pane == Pane::Details {
                         self.scroll_detail_down();
@@ -531,20 +1170,17 @@ impl App {
                     Pane::Profiles => self.next_profile(),
                     Pane::Details => self.scroll_detail_down(),
                 },
-                #[cfg(feature = "tui-cards")]
                 ViewMode::Cards => self.next_profile(),
             },
-            KeyCode::Left | KeyCode::Char('h') => {
-                if self.view_mode == ViewMode::Dashboard {
-                    self.prev_harness();
-                }
+            Action::PrevHarness if self.view_mode != ViewMode::Legacy => {
+                self.prev_harness();
             }
-            KeyCode::Right | KeyCode::Char('l') => {
-                if self.view_mode == ViewMode::Dashboard {
-                    self.next_harness();
-                }
+            Action::NextHarness if self.view_mode != ViewMode::Legacy => {
+                self.next_harness();
             }
-            KeyCode::Enter => match self.view_mode {
+            Action::First => self.select_profile_at(0),
+            Action::Last => self.select_profile_at(self.profiles.len().saturating_sub(1)),
+            Action::Select => match self.view_mode {
                 ViewMode::Dashboard => {
                     self.switch_to_selected();
                 }
@@ -557,28 +1193,24 @@ impl App {
                         }
                     }
                 }
-                #[cfg(feature = "tui-cards")]
                 ViewMode::Cards => {
                     self.switch_to_selected();
                 }
             },
-            KeyCode::Char(' ') => {
-                if self.active_pane == Pane::Profiles {
-                    self.toggle_expansion();
-                }
+            Action::Expand if self.active_pane == Pane::Profiles => {
+                self.toggle_expansion();
             }
-            KeyCode::Char('r') => {
+            Action::Refresh => {
                 self.refresh_profiles();
                 self.status_message = Some("Refreshed".to_string());
             }
-            KeyCode::Char('n') => {
+            Action::NewProfile => {
                 self.input_mode = InputMode::CreatingProfile;
                 self.input_buffer.clear();
                 self.status_message = Some("Enter profile name (Esc to cancel)".to_string());
             }
-            KeyCode::Char('d') => {
-                if (matches!(self.view_mode, ViewMode::Dashboard)
-                    || self.active_pane == Pane::Profiles)
+            Action::DeleteProfile => {
+                if (self.view_mode != ViewMode::Legacy || self.active_pane == Pane::Profiles)
                     && let Some(idx) = self.profile_state.selected()
                     && let Some(profile) = self.profiles.get(idx)
                 {
@@ -586,23 +1218,28 @@ impl App {
                     self.input_mode = InputMode::ConfirmingDelete;
                 }
             }
-            KeyCode::Char('e') => {
-                if matches!(self.view_mode, ViewMode::Dashboard)
-                    || self.active_pane == Pane::Profiles
-                {
-                    self.edit_selected();
-                }
+            Action::Edit
+                if self.view_mode != ViewMode::Legacy || self.active_pane == Pane::Profiles =>
+            {
+                self.edit_selected();
             }
-            KeyCode::Char('f') => {
+            Action::Install => {
+                self.install_screen = Some(InstallScreen::new());
+            }
+            Action::Overview => self.open_overview(),
+            Action::Undo => self.undo_last(),
+            Action::MarkDiffBase => self.mark_diff_base(),
+            Action::Diff => self.open_diff(),
+            Action::SetDefaultHarness => {
                 if let Some(harness_kind) = self.selected_harness() {
-                    let id = harness_id(&harness_kind);
+                    let id = HarnessRegistry::id(harness_kind);
                     self.bridle_config.set_default_harness(Some(id));
                     if let Err(e) = self.bridle_config.save() {
                         self.status_message = Some(format!("Failed to save: {}", e));
                     } else {
                         self.status_message = Some(format!(
                             "Set {} as default harness",
-                            harness_name(&harness_kind)
+                            HarnessRegistry::name(harness_kind)
                         ));
                     }
                 }
@@ -613,6 +1250,9 @@ impl App {
 
     fn handle_input_key(&mut self, key: KeyCode) {
         match key {
+            KeyCode::Enter if self.input_mode == InputMode::CopyingComponent => {
+                self.copy_component_from_input()
+            }
             KeyCode::Enter => self.create_profile_from_input(),
             KeyCode::Esc => {
                 self.input_mode = InputMode::Normal;
@@ -632,14 +1272,18 @@ impl App {
     fn handle_confirm_delete_key(&mut self, key: KeyCode) {
         match key {
             KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
-                self.delete_selected();
+                if self.input_mode == InputMode::ConfirmingUninstall {
+                    self.uninstall_component();
+                } else {
+                    self.delete_selected();
+                }
                 self.input_mode = InputMode::Normal;
                 self.input_buffer.clear();
             }
             KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
                 self.input_mode = InputMode::Normal;
                 self.input_buffer.clear();
-                self.status_message = Some("Delete cancelled".to_string());
+                self.status_message = Some("Cancelled".to_string());
             }
             _ => {}
         }
@@ -722,12 +1366,27 @@ fn ui(frame: &mut Frame, app: &mut App) {
     match app.view_mode {
         ViewMode::Legacy => render_legacy_view(frame, app),
         ViewMode::Dashboard => render_dashboard_view(frame, app),
-        #[cfg(feature = "tui-cards")]
-        ViewMode::Cards => render_dashboard_view(frame, app), // TODO: implement cards view
+        ViewMode::Cards => render_cards_view(frame, app),
+    }
+
+    if let Some(screen) = &mut app.install_screen {
+        screen.render(frame);
+    }
+
+    if let Some(screen) = &mut app.diff_screen {
+        screen.render(frame);
+    }
+
+    if let Some(overview) = &mut app.overview {
+        overview.render(frame);
+    }
+
+    if let Some(search) = &mut app.search {
+        search.render(frame);
     }
 
     if app.show_help {
-        render_help_modal(frame, frame.area(), app.view_mode);
+        render_help_modal(frame, frame.area(), &app.keymap);
     }
 }
 
@@ -774,15 +1433,80 @@ fn render_dashboard_view(frame: &mut Frame, app: &mut App) {
     render_detail_pane(frame, app, content_chunks[1]);
 
     render_status_bar(frame, app, chunks[2]);
+    render_popups(frame, app);
+}
+
+fn render_cards_view(frame: &mut Frame, app: &mut App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .split(frame.area());
+
+    app.harness_area = Some(chunks[0]);
+    render_harness_tabs(frame, app, chunks[0]);
+
+    let title = match &app.profile_filter {
+        Some(filter) => format!(" Profiles (filter: {}) ", filter),
+        None => " Profiles ".to_string(),
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Theme::border_active());
+    let inner = block.inner(chunks[1]);
+    frame.render_widget(block, chunks[1]);
+
+    app.profile_area = Some(inner);
+    app.detail_area = None;
+    app.card_state.grid_state.selected = app.profile_state.selected().unwrap_or(0);
+    frame.render_stateful_widget(CardView::new(&app.profiles), inner, &mut app.card_state);
+
+    render_status_bar(frame, app, chunks[2]);
+    render_popups(frame, app);
+}
 
-    if app.input_mode == InputMode::CreatingProfile {
-        render_input_popup(frame, app);
+fn render_popups(frame: &mut Frame, app: &App) {
+    match app.input_mode {
+        InputMode::CreatingProfile | InputMode::CopyingComponent => render_input_popup(frame, app),
+        InputMode::ConfirmingDelete | InputMode::ConfirmingUninstall => {
+            render_confirm_delete_popup(frame, app)
+        }
+        InputMode::Normal => {}
     }
-    if app.input_mode == InputMode::ConfirmingDelete {
-        render_confirm_delete_popup(frame, app);
+    if let Some(preview) = &app.preview {
+        render_preview_popup(frame, preview);
     }
 }
 
+fn render_preview_popup(frame: &mut Frame, preview: &Preview) {
+    let area = frame.area();
+    let width = (area.width * 4 / 5).max(40).min(area.width);
+    let height = (area.height * 4 / 5).max(10).min(area.height);
+    let popup_area = Rect::new(
+        (area.width - width) / 2,
+        (area.height - height) / 2,
+        width,
+        height,
+    );
+
+    frame.render_widget(Clear, popup_area);
+
+    let paragraph = Paragraph::new(preview.lines.clone())
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Theme::border_active())
+                .title(format!(" {} (j/k scroll, Esc close) ", preview.title)),
+        )
+        .wrap(ratatui::widgets::Wrap { trim: false })
+        .scroll((preview.scroll, 0));
+    frame.render_widget(paragraph, popup_area);
+}
+
 fn render_confirm_delete_popup(frame: &mut Frame, app: &App) {
     let area = frame.area();
     let popup_width = 50.min(area.width.saturating_sub(4));
@@ -794,15 +1518,22 @@ fn render_confirm_delete_popup(frame: &mut Frame, app: &App) {
 
     frame.render_widget(Clear, popup_area);
 
-    let confirm_text = format!("Delete '{}'? (y/n)", app.input_buffer);
+    let (confirm_text, title) = if app.input_mode == InputMode::ConfirmingUninstall {
+        (format!("{} (y/n)", app.input_buffer), " Confirm Uninstall ")
+    } else {
+        (
+            format!("Delete '{}'? (y/n)", app.input_buffer),
+            " Confirm Delete ",
+        )
+    };
     let confirm = Paragraph::new(confirm_text)
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Red))
-                .title(" Confirm Delete "),
+                .border_style(Theme::error())
+                .title(title),
         )
-        .style(Style::default().fg(Color::White));
+        .style(Style::default());
 
     frame.render_widget(confirm, popup_area);
 }
@@ -818,25 +1549,41 @@ fn render_input_popup(frame: &mut Frame, app: &App) {
 
     frame.render_widget(Clear, popup_area);
 
+    let title = if app.input_mode == InputMode::CopyingComponent {
+        " Copy To Profile (Enter to copy, Esc to cancel) "
+    } else {
+        " New Profile Name (Enter to create, Esc to cancel) "
+    };
     let input_text = format!("{}█", app.input_buffer);
     let input = Paragraph::new(input_text)
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Yellow))
-                .title(" New Profile Name (Enter to create, Esc to cancel) "),
+                .border_style(Theme::text_warning())
+                .title(title),
         )
-        .style(Style::default().fg(Color::White));
+        .style(Style::default());
 
     frame.render_widget(input, popup_area);
 }
 
 fn render_profile_table(frame: &mut Frame, app: &mut App, area: Rect) {
-    let table = ProfileTable::new(&app.profiles).focused(app.active_pane == Pane::Profiles);
+    let diff_base = app.diff_base_name().map(str::to_string);
+    let table = ProfileTable::new(&app.profiles)
+        .focused(app.active_pane == Pane::Profiles)
+        .diff_base(diff_base.as_deref())
+        .unsaved(app.unsaved_changes > 0)
+        .filter(app.profile_filter.as_deref());
     frame.render_stateful_widget(table, area, &mut app.profile_table_state);
 }
 
-fn render_detail_pane(frame: &mut Frame, app: &App, area: Rect) {
+fn render_detail_pane(frame: &mut Frame, app: &mut App, area: Rect) {
+    if app.browsing_components() {
+        let list = ComponentList::new(&app.components);
+        frame.render_stateful_widget(list, area, &mut app.component_state);
+        return;
+    }
+
     let selected_profile = app
         .profile_table_state
         .selected()
@@ -864,9 +1611,9 @@ fn render_harness_tabs(frame: &mut Frame, app: &App, area: Rect) {
 fn render_harness_pane(frame: &mut Frame, app: &mut App, area: Rect) {
     let is_active = app.active_pane == Pane::Harnesses;
     let border_style = if is_active {
-        Style::default().fg(Color::Cyan)
+        Theme::accent()
     } else {
-        Style::default().fg(Color::DarkGray)
+        Theme::muted_color()
     };
 
     let items: Vec<ListItem> = app
@@ -879,7 +1626,7 @@ fn render_harness_pane(frame: &mut Frame, app: &mut App, area: Rect) {
             let style = if installed {
                 Style::default()
             } else {
-                Style::default().fg(Color::DarkGray)
+                Theme::muted_color()
             };
             let suffix = if installed { "" } else { " (not installed)" };
             ListItem::new(format!("{} {}{}", indicator, harness.kind(), suffix)).style(style)
@@ -893,11 +1640,7 @@ fn render_harness_pane(frame: &mut Frame, app: &mut App, area: Rect) {
                 .borders(Borders::ALL)
                 .border_style(border_style),
         )
-        .highlight_style(
-            Style::default()
-                .add_modifier(Modifier::BOLD)
-                .bg(Color::DarkGray),
-        )
+        .highlight_style(Theme::selection().add_modifier(Modifier::BOLD))
         .highlight_symbol("> ");
 
     frame.render_stateful_widget(list, area, &mut app.harness_state);
@@ -929,9 +1672,7 @@ fn render_profile_compact(profile: &ProfileInfo) -> Line<'static> {
     };
 
     let style = if profile.is_active {
-        Style::default()
-            .fg(Color::Green)
-            .add_modifier(Modifier::BOLD)
+        Theme::profile_active()
     } else {
         Style::default()
     };
@@ -949,9 +1690,9 @@ fn render_profile_expanded(profile: &ProfileInfo) -> Vec<Line<'static>> {
 fn render_profile_pane(frame: &mut Frame, app: &mut App, area: Rect) {
     let is_active = app.active_pane == Pane::Profiles;
     let border_style = if is_active {
-        Style::default().fg(Color::Cyan)
+        Theme::accent()
     } else {
-        Style::default().fg(Color::DarkGray)
+        Theme::muted_color()
     };
 
     let (list_area, input_area) = if app.input_mode == InputMode::CreatingProfile {
@@ -978,7 +1719,7 @@ fn render_profile_pane(frame: &mut Frame, app: &mut App, area: Rect) {
         let inner = area.inner(ratatui::layout::Margin::new(2, 2));
         let paragraph = Paragraph::new(message)
             .alignment(Alignment::Center)
-            .style(Style::default().fg(Color::DarkGray));
+            .style(Theme::muted_color());
         frame.render_widget(paragraph, inner);
         return;
     }
@@ -1009,11 +1750,7 @@ fn render_profile_pane(frame: &mut Frame, app: &mut App, area: Rect) {
                 .borders(Borders::ALL)
                 .border_style(border_style),
         )
-        .highlight_style(
-            Style::default()
-                .add_modifier(Modifier::BOLD)
-                .bg(Color::DarkGray),
-        )
+        .highlight_style(Theme::selection().add_modifier(Modifier::BOLD))
         .highlight_symbol("> ");
 
     frame.render_stateful_widget(list, list_area, &mut app.profile_state);
@@ -1025,75 +1762,33 @@ fn render_profile_pane(frame: &mut Frame, app: &mut App, area: Rect) {
                 Block::default()
                     .title(" Profile name: ")
                     .borders(Borders::ALL)
-                    .border_style(Style::default().fg(Color::Yellow)),
+                    .border_style(Theme::text_warning()),
             )
-            .style(Style::default().fg(Color::White));
+            .style(Style::default());
         frame.render_widget(input, input_area);
     }
 }
 
-fn render_help_modal(frame: &mut Frame, area: Rect, view_mode: views::ViewMode) {
-    let mut help_text = vec![Line::from(vec![Span::styled(
-        "Navigation",
-        Style::default().add_modifier(Modifier::BOLD),
-    )])];
-
-    #[cfg(feature = "tui-cards")]
-    if matches!(view_mode, views::ViewMode::Cards) {
-        help_text.extend([
-            Line::from("  ←/→       Move left/right"),
-            Line::from("  ↑/↓       Move up/down"),
-            Line::from("  Space     View details"),
-        ]);
-    } else {
-        help_text.extend([
-            Line::from("  j / ↓     Move down"),
-            Line::from("  k / ↑     Move up"),
-            Line::from("  Tab       Switch pane"),
-        ]);
-    }
-
-    #[cfg(not(feature = "tui-cards"))]
-    {
-        let _ = view_mode;
-        help_text.extend([
-            Line::from("  j / ↓     Move down / scroll"),
-            Line::from("  k / ↑     Move up / scroll"),
-            Line::from("  Tab       Cycle panes"),
-        ]);
+fn render_help_modal(frame: &mut Frame, area: Rect, keymap: &Keymap) {
+    let heading = |title| Line::styled(title, Style::default().add_modifier(Modifier::BOLD));
+    let mut help_text = Vec::new();
+    for (title, rows) in keymap.help_sections() {
+        help_text.push(heading(title));
+        help_text.extend(
+            rows.into_iter()
+                .map(|(keys, description)| Line::from(format!("  {:<10}{}", keys, description))),
+        );
+        help_text.push(Line::from(""));
     }
-
     help_text.extend([
-        Line::from(""),
-        Line::from(vec![Span::styled(
-            "Actions",
-            Style::default().add_modifier(Modifier::BOLD),
-        )]),
-        Line::from("  Enter     Switch to profile"),
-        Line::from("  n         New profile"),
-        Line::from("  d         Delete profile"),
-        Line::from("  e         Edit profile"),
-        Line::from("  f         Set default harness"),
-        Line::from("  r         Refresh"),
-        Line::from(""),
-        Line::from(vec![Span::styled(
-            "Harness Status",
-            Style::default().add_modifier(Modifier::BOLD),
-        )]),
+        heading("Harness Status"),
         Line::from("  ●         Tracked (active profile)"),
         Line::from("  +         Has config (not tracked)"),
         Line::from("  -         Binary only (no config)"),
         Line::from("            Not installed"),
-        Line::from(""),
-        Line::from(vec![Span::styled(
-            "General",
-            Style::default().add_modifier(Modifier::BOLD),
-        )]),
-        Line::from("  ?         Toggle help"),
-        Line::from("  q / Esc   Quit"),
     ]);
 
-    let width = 40;
+    let width = 46;
     let height = help_text.len() as u16 + 4;
     let x = area.width.saturating_sub(width) / 2;
     let y = area.height.saturating_sub(height) / 2;
@@ -1105,8 +1800,7 @@ fn render_help_modal(frame: &mut Frame, area: Rect, view_mode: views::ViewMode)
         .title(" Help ")
         .title_alignment(Alignment::Center)
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan))
-        .style(Style::default().bg(Color::Black));
+        .border_style(Theme::border_active());
 
     let help_paragraph = Paragraph::new(help_text).block(help_block);
     frame.render_widget(help_paragraph, modal_area);
@@ -1121,8 +1815,18 @@ fn render_status_bar(frame: &mut Frame, app: &App, area: Rect) {
         }
     });
 
+    let drift_warning = (app.unsaved_changes > 0).then(|| {
+        format!(
+            "Live config differs from active profile ({} unsaved file(s))",
+            app.unsaved_changes
+        )
+    });
+
+    let hints = app.keymap.status_hints(app.view_mode);
     let status_bar = StatusBar::new(app.view_mode)
+        .hints(&hints)
         .message(app.status_message.as_deref())
+        .warning(drift_warning.as_deref())
         .harness_status(harness_status);
     frame.render_widget(status_bar, area);
 }
@@ -1148,10 +1852,19 @@ pub fn run() -> Result<(), Error> {
             .draw(|frame| ui(frame, &mut app))
             .map_err(Error::Io)?;
 
+        if let Some(screen) = &mut app.install_screen {
+            screen.tick();
+        }
+        if let Some(overview) = &mut app.overview {
+            overview.tick();
+        }
+        app.poll_watcher();
+        app.poll_drift();
+
         if event::poll(std::time::Duration::from_millis(100)).map_err(Error::Io)? {
             match event::read().map_err(Error::Io)? {
                 Event::Key(key) if key.kind == KeyEventKind::Press => {
-                    app.handle_key(key.code);
+                    app.handle_key(key);
                 }
                 Event::Mouse(mouse) => {
                     app.handle_mouse(mouse);
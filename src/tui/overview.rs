@@ -0,0 +1,332 @@
+//! Cross-harness overview: one row per harness with its active profile,
+//! component counts, drift, and installation health.
+//!
+//! `s` switches every harness that has a profile with the entered name, so a
+//! shared name like "work" flips the whole setup at once.
+
+use crossterm::event::KeyCode;
+use harness_locate::{Harness, HarnessKind};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout},
+    style::Modifier,
+    text::Line,
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, TableState},
+};
+
+use crate::config::{BridleConfig, ProfileManager, ProfileName};
+use crate::harness::{HarnessConfig, HarnessRegistry};
+use crate::tui::install::popup_area;
+use crate::tui::theme::Theme;
+use crate::tui::watcher::DriftCheck;
+use crate::tui::widgets::StatusBar;
+
+/// Snapshot of one harness for the overview table.
+#[derive(Debug)]
+struct HarnessRow {
+    kind: HarnessKind,
+    active: Option<String>,
+    model: Option<String>,
+    mcp: usize,
+    skills: usize,
+    agents: Option<usize>,
+    commands: usize,
+    /// Files where the live config differs from the active profile.
+    drift: Option<usize>,
+    health: &'static str,
+    profiles: Vec<String>,
+}
+
+impl HarnessRow {
+    fn load(manager: &ProfileManager, config: &BridleConfig, kind: HarnessKind) -> Self {
+        let harness = Harness::new(kind);
+        let health = match harness.installation_status() {
+            Ok(status) => StatusBar::installation_status_text(&status),
+            Err(_) => "Unknown",
+        };
+        let mut row = Self {
+            kind,
+            active: None,
+            model: None,
+            mcp: 0,
+            skills: 0,
+            agents: None,
+            commands: 0,
+            drift: None,
+            health,
+            profiles: manager
+                .list_profiles(&harness)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|p| p.as_str().to_string())
+                .collect(),
+        };
+
+        let Some(active) = config.active_profile_for(harness.id()) else {
+            return row;
+        };
+        row.active = Some(active.to_string());
+        let Ok(name) = ProfileName::new(active) else {
+            return row;
+        };
+        if let Ok(info) = manager.show_profile(&harness, &name) {
+            row.model = info.model;
+            row.mcp = info.mcp_servers.len();
+            row.skills = info.skills.items.len();
+            row.agents = info.agents.map(|a| a.items.len());
+            row.commands = info.commands.items.len();
+        }
+        row
+    }
+
+    /// What switching every harness to `target` would do for this row.
+    fn switch_plan(&self, target: &str) -> Option<SwitchPlan> {
+        if target.is_empty() {
+            None
+        } else if self.active.as_deref() == Some(target) {
+            Some(SwitchPlan::AlreadyActive)
+        } else if self.profiles.iter().any(|p| p == target) {
+            Some(SwitchPlan::Switch)
+        } else {
+            Some(SwitchPlan::Missing)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SwitchPlan {
+    AlreadyActive,
+    Switch,
+    Missing,
+}
+
+/// What the overview asks the app to do after a key press.
+#[derive(Debug)]
+pub enum OverviewAction {
+    None,
+    Close,
+    /// Show this harness in the main view.
+    Jump(HarnessKind),
+    /// Switch every harness that has a profile with this name.
+    SwitchAll(String),
+}
+
+#[derive(Debug)]
+pub struct OverviewScreen {
+    rows: Vec<HarnessRow>,
+    state: TableState,
+    /// Profile name being entered for a bulk switch.
+    input: Option<String>,
+    /// Counts filling in each row's drift.
+    drift: DriftCheck,
+}
+
+impl OverviewScreen {
+    pub fn new(
+        manager: &ProfileManager,
+        config: &BridleConfig,
+        harnesses: &[HarnessKind],
+        selected: Option<HarnessKind>,
+    ) -> Self {
+        let rows: Vec<HarnessRow> = harnesses
+            .iter()
+            .map(|kind| HarnessRow::load(manager, config, *kind))
+            .collect();
+        let targets = rows
+            .iter()
+            .filter_map(|row| {
+                let name = ProfileName::new(row.active.as_deref()?).ok()?;
+                Some((row.kind, name))
+            })
+            .collect();
+        let drift = DriftCheck::spawn(manager, targets);
+        let mut state = TableState::default();
+        state.select(
+            selected
+                .and_then(|kind| rows.iter().position(|r| r.kind == kind))
+                .or((!rows.is_empty()).then_some(0)),
+        );
+        Self {
+            rows,
+            state,
+            input: None,
+            drift,
+        }
+    }
+
+    /// Fills in drift counts that have finished.
+    pub fn tick(&mut self) {
+        for (kind, drift) in self.drift.poll() {
+            if let Some(row) = self.rows.iter_mut().find(|r| r.kind == kind) {
+                row.drift = drift;
+            }
+        }
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        if self.rows.is_empty() {
+            return;
+        }
+        let len = self.rows.len() as isize;
+        let current = self.state.selected().unwrap_or(0) as isize;
+        self.state
+            .select(Some((current + delta).rem_euclid(len) as usize));
+    }
+
+    pub fn handle_key(&mut self, key: KeyCode) -> OverviewAction {
+        if let Some(input) = &mut self.input {
+            match key {
+                KeyCode::Esc => self.input = None,
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Char(c) => input.push(c),
+                KeyCode::Enter if !input.is_empty() => {
+                    let name = std::mem::take(input);
+                    self.input = None;
+                    return OverviewAction::SwitchAll(name);
+                }
+                _ => {}
+            }
+            return OverviewAction::None;
+        }
+
+        match key {
+            KeyCode::Esc | KeyCode::Char('q') => return OverviewAction::Close,
+            KeyCode::Up | KeyCode::Char('k') => self.move_selection(-1),
+            KeyCode::Down | KeyCode::Char('j') => self.move_selection(1),
+            KeyCode::Enter => {
+                if let Some(row) = self.state.selected().and_then(|i| self.rows.get(i)) {
+                    return OverviewAction::Jump(row.kind);
+                }
+            }
+            KeyCode::Char('s') => {
+                let current = self
+                    .state
+                    .selected()
+                    .and_then(|i| self.rows.get(i))
+                    .and_then(|r| r.active.clone());
+                self.input = Some(current.unwrap_or_default());
+            }
+            _ => {}
+        }
+        OverviewAction::None
+    }
+
+    pub fn render(&mut self, frame: &mut Frame) {
+        let area = popup_area(frame.area());
+        frame.render_widget(Clear, area);
+
+        let block = Block::default()
+            .title(" Overview ")
+            .borders(Borders::ALL)
+            .border_style(Theme::border_active());
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(0),
+                Constraint::Length(if self.input.is_some() { 3 } else { 0 }),
+                Constraint::Length(1),
+            ])
+            .split(inner);
+
+        let target = self.input.as_deref().unwrap_or_default();
+        let header = Row::new(vec![
+            "Harness", "Profile", "Model", "MCP", "Skills", "Agents", "Cmds", "Drift", "Health",
+        ])
+        .style(Theme::accent().add_modifier(Modifier::BOLD))
+        .bottom_margin(1);
+
+        let count = |n: usize| {
+            if n == 0 {
+                "-".to_string()
+            } else {
+                n.to_string()
+            }
+        };
+        let rows = self.rows.iter().map(|row| {
+            let (drift, drift_style) = match (row.active.as_ref(), row.drift) {
+                (None, _) => ("-".to_string(), Theme::text_muted()),
+                (Some(_), None) => ("?".to_string(), Theme::text_muted()),
+                (Some(_), Some(0)) => ("clean".to_string(), Theme::added()),
+                (Some(_), Some(n)) => (format!("{} file(s)", n), Theme::text_warning()),
+            };
+            let health_style = match row.health {
+                "Installed" => Theme::added(),
+                "Not installed" => Theme::muted_color(),
+                _ => Theme::text_warning(),
+            };
+            let active = row.active.as_deref().unwrap_or("-");
+            let (profile, profile_style) = match row.switch_plan(target) {
+                Some(SwitchPlan::Switch) => (format!("{} → {}", active, target), Theme::changed()),
+                Some(SwitchPlan::Missing) => (
+                    format!("{} (no '{}')", active, target),
+                    Theme::muted_color(),
+                ),
+                Some(SwitchPlan::AlreadyActive) | None if row.active.is_some() => {
+                    (active.to_string(), Theme::profile_active())
+                }
+                _ => (active.to_string(), Theme::text_muted()),
+            };
+            let model = row
+                .model
+                .as_deref()
+                .map(|m| m.rsplit('/').next().unwrap_or(m).to_string())
+                .unwrap_or_else(|| "-".to_string());
+
+            Row::new(vec![
+                Cell::from(HarnessRegistry::name(row.kind)),
+                Cell::from(profile).style(profile_style),
+                Cell::from(model),
+                Cell::from(count(row.mcp)),
+                Cell::from(count(row.skills)),
+                Cell::from(row.agents.map(count).unwrap_or_else(|| "n/a".to_string())),
+                Cell::from(count(row.commands)),
+                Cell::from(drift).style(drift_style),
+                Cell::from(row.health).style(health_style),
+            ])
+        });
+
+        let widths = [
+            Constraint::Length(12),
+            Constraint::Min(14),
+            Constraint::Min(14),
+            Constraint::Length(4),
+            Constraint::Length(6),
+            Constraint::Length(6),
+            Constraint::Length(5),
+            Constraint::Length(10),
+            Constraint::Length(13),
+        ];
+        let table = Table::new(rows, widths)
+            .header(header)
+            .row_highlight_style(Theme::selection().add_modifier(Modifier::BOLD))
+            .highlight_symbol("> ");
+        frame.render_stateful_widget(table, chunks[0], &mut self.state);
+
+        if let Some(input) = &self.input {
+            frame.render_widget(
+                Paragraph::new(format!("{}█", input)).block(
+                    Block::default()
+                        .title(" Switch all harnesses to profile ")
+                        .borders(Borders::ALL)
+                        .border_style(Theme::text_warning()),
+                ),
+                chunks[1],
+            );
+        }
+
+        let hints = if self.input.is_some() {
+            "type a profile name  Enter:switch all  Esc:cancel"
+        } else {
+            "↑/↓:harness  Enter:show in dashboard  s:switch all to…  Esc:close"
+        };
+        frame.render_widget(
+            Paragraph::new(Line::from(hints)).style(Theme::text_muted()),
+            chunks[2],
+        );
+    }
+}
@@ -0,0 +1,407 @@
+//! Fuzzy search across every harness's profiles and their components.
+//!
+//! `/` opens the overlay. In jump mode Enter selects the matching harness,
+//! profile, and component; in filter mode Enter narrows the profile table to
+//! profiles with a match.
+
+use crossterm::event::KeyCode;
+use harness_locate::{Harness, HarnessKind};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout},
+    style::Modifier,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+use crate::config::{ProfileInfo, ProfileManager};
+use crate::harness::HarnessRegistry;
+use crate::install::DependencyKind;
+use crate::tui::install::popup_area;
+use crate::tui::theme::Theme;
+
+/// Results shown in the overlay; more specific queries narrow them further.
+const MAX_RESULTS: usize = 200;
+
+/// What a search entry refers to inside a profile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchKind {
+    Profile,
+    Model,
+    Component(DependencyKind),
+}
+
+impl MatchKind {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Profile => "profile",
+            Self::Model => "model",
+            Self::Component(DependencyKind::Mcp) => "mcp",
+            Self::Component(DependencyKind::Skill) => "skill",
+            Self::Component(DependencyKind::Agent) => "agent",
+            Self::Component(DependencyKind::Command) => "command",
+        }
+    }
+}
+
+/// One searchable string and where it lives.
+#[derive(Debug, Clone)]
+pub struct SearchEntry {
+    pub harness: HarnessKind,
+    pub profile: String,
+    pub kind: MatchKind,
+    pub text: String,
+}
+
+/// Searchable strings of a single profile.
+fn searchable(profile: &ProfileInfo) -> Vec<(MatchKind, &str)> {
+    let mut texts = vec![(MatchKind::Profile, profile.name.as_str())];
+    if let Some(model) = &profile.model {
+        texts.push((MatchKind::Model, model.as_str()));
+    }
+    texts.extend(
+        profile
+            .mcp_servers
+            .iter()
+            .map(|s| (MatchKind::Component(DependencyKind::Mcp), s.name.as_str())),
+    );
+    let groups = [
+        (DependencyKind::Skill, Some(&profile.skills)),
+        (DependencyKind::Command, Some(&profile.commands)),
+        (DependencyKind::Agent, profile.agents.as_ref()),
+    ];
+    for (kind, summary) in groups {
+        texts.extend(
+            summary
+                .into_iter()
+                .flat_map(|s| &s.items)
+                .map(|name| (MatchKind::Component(kind), name.as_str())),
+        );
+    }
+    texts
+}
+
+/// Whether anything searchable in `profile` matches `query`.
+pub fn profile_matches(profile: &ProfileInfo, query: &str) -> bool {
+    searchable(profile)
+        .iter()
+        .any(|(_, text)| fuzzy_match(query, text).is_some())
+}
+
+/// Scores `text` against `query` as a case-insensitive subsequence.
+///
+/// Returns the score (higher is better) and the matched character indices.
+/// Consecutive matches and matches at word starts score higher.
+pub fn fuzzy_match(query: &str, text: &str) -> Option<(i64, Vec<usize>)> {
+    let query: Vec<char> = query.chars().flat_map(char::to_lowercase).collect();
+    if query.is_empty() {
+        return Some((0, Vec::new()));
+    }
+
+    let chars: Vec<char> = text.chars().collect();
+    let mut positions = Vec::with_capacity(query.len());
+    let mut score = 0i64;
+    let mut next = 0;
+
+    for q in &query {
+        let found = (next..chars.len()).find(|&i| chars[i].to_lowercase().eq(Some(*q)))?;
+        score += 16;
+        if positions.last().is_some_and(|&last| last + 1 == found) {
+            score += 15;
+        }
+        let at_word_start = found == 0
+            || matches!(chars[found - 1], '-' | '_' | '/' | '.' | ' ' | ':')
+            || (chars[found].is_uppercase() && chars[found - 1].is_lowercase());
+        if at_word_start {
+            score += 10;
+        }
+        score -= (found - next) as i64;
+        positions.push(found);
+        next = found + 1;
+    }
+
+    // Prefer shorter texts when everything else is equal.
+    score -= (chars.len() - query.len()) as i64 / 4;
+    Some((score, positions))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Jump,
+    Filter,
+}
+
+/// What the overlay asks the app to do after a key press.
+#[derive(Debug)]
+pub enum SearchAction {
+    None,
+    Close,
+    Jump(SearchEntry),
+    Filter(String),
+}
+
+#[derive(Debug)]
+struct Hit {
+    entry: usize,
+    positions: Vec<usize>,
+}
+
+#[derive(Debug)]
+pub struct SearchOverlay {
+    query: String,
+    mode: Mode,
+    entries: Vec<SearchEntry>,
+    hits: Vec<Hit>,
+    state: ListState,
+}
+
+impl SearchOverlay {
+    /// Indexes every profile of every harness in `harnesses`.
+    pub fn new(manager: &ProfileManager, harnesses: &[HarnessKind], filter: Option<&str>) -> Self {
+        let mut entries = Vec::new();
+        for kind in harnesses {
+            let harness = Harness::new(*kind);
+            let Ok(names) = manager.list_profiles(&harness) else {
+                continue;
+            };
+            for name in names {
+                if let Ok(info) = manager.show_profile(&harness, &name) {
+                    entries.extend(searchable(&info).into_iter().map(|(match_kind, text)| {
+                        SearchEntry {
+                            harness: *kind,
+                            profile: info.name.clone(),
+                            kind: match_kind,
+                            text: text.to_string(),
+                        }
+                    }));
+                }
+            }
+        }
+
+        let mut overlay = Self {
+            query: filter.unwrap_or_default().to_string(),
+            mode: if filter.is_some() {
+                Mode::Filter
+            } else {
+                Mode::Jump
+            },
+            entries,
+            hits: Vec::new(),
+            state: ListState::default(),
+        };
+        overlay.update_hits();
+        overlay
+    }
+
+    fn update_hits(&mut self) {
+        let mut scored: Vec<(i64, Hit)> = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(entry, e)| {
+                let (score, positions) = fuzzy_match(&self.query, &e.text)?;
+                Some((score, Hit { entry, positions }))
+            })
+            .collect();
+        scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.entry.cmp(&b.1.entry)));
+        self.hits = scored
+            .into_iter()
+            .take(MAX_RESULTS)
+            .map(|(_, hit)| hit)
+            .collect();
+        self.state.select((!self.hits.is_empty()).then_some(0));
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        if self.hits.is_empty() {
+            return;
+        }
+        let len = self.hits.len() as isize;
+        let current = self.state.selected().unwrap_or(0) as isize;
+        self.state
+            .select(Some((current + delta).rem_euclid(len) as usize));
+    }
+
+    pub fn handle_key(&mut self, key: KeyCode) -> SearchAction {
+        match key {
+            KeyCode::Esc => return SearchAction::Close,
+            KeyCode::Tab => {
+                self.mode = match self.mode {
+                    Mode::Jump => Mode::Filter,
+                    Mode::Filter => Mode::Jump,
+                };
+            }
+            KeyCode::Up => self.move_selection(-1),
+            KeyCode::Down => self.move_selection(1),
+            KeyCode::Backspace => {
+                self.query.pop();
+                self.update_hits();
+            }
+            KeyCode::Char(c) => {
+                self.query.push(c);
+                self.update_hits();
+            }
+            KeyCode::Enter => {
+                return match self.mode {
+                    Mode::Filter => SearchAction::Filter(self.query.clone()),
+                    Mode::Jump => self
+                        .state
+                        .selected()
+                        .and_then(|i| self.hits.get(i))
+                        .map(|hit| SearchAction::Jump(self.entries[hit.entry].clone()))
+                        .unwrap_or(SearchAction::None),
+                };
+            }
+            _ => {}
+        }
+        SearchAction::None
+    }
+
+    pub fn render(&mut self, frame: &mut Frame) {
+        let area = popup_area(frame.area());
+        frame.render_widget(Clear, area);
+
+        let title = match self.mode {
+            Mode::Jump => " Search ",
+            Mode::Filter => " Filter profiles ",
+        };
+        let block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(Theme::border_active());
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(1),
+            ])
+            .split(inner);
+
+        frame.render_widget(
+            Paragraph::new(format!("{}_", self.query))
+                .block(Block::default().borders(Borders::ALL).title(" / ")),
+            chunks[0],
+        );
+
+        let highlight = Theme::text_warning().add_modifier(Modifier::BOLD);
+        let items: Vec<ListItem> = self
+            .hits
+            .iter()
+            .map(|hit| {
+                let entry = &self.entries[hit.entry];
+                let mut spans = vec![Span::styled(
+                    format!("{:<8}", entry.kind.label()),
+                    Theme::muted_color(),
+                )];
+                spans.extend(entry.text.chars().enumerate().map(|(i, c)| {
+                    if hit.positions.contains(&i) {
+                        Span::styled(c.to_string(), highlight)
+                    } else {
+                        Span::raw(c.to_string())
+                    }
+                }));
+                let location = if entry.kind == MatchKind::Profile {
+                    format!("  {}", HarnessRegistry::name(entry.harness))
+                } else {
+                    format!(
+                        "  {} / {}",
+                        HarnessRegistry::name(entry.harness),
+                        entry.profile
+                    )
+                };
+                spans.push(Span::styled(location, Theme::muted_color()));
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .title(format!(" {} match(es) ", self.hits.len()))
+                    .borders(Borders::ALL),
+            )
+            .highlight_style(Theme::selection())
+            .highlight_symbol("> ");
+        frame.render_stateful_widget(list, chunks[1], &mut self.state);
+
+        let hints = match self.mode {
+            Mode::Jump => "type to search  ↑/↓:move  Enter:jump  Tab:filter mode  Esc:close",
+            Mode::Filter => "type to search  Enter:filter profile table  Tab:jump mode  Esc:close",
+        };
+        frame.render_widget(Paragraph::new(hints).style(Theme::text_muted()), chunks[2]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{McpServerInfo, ResourceSummary};
+
+    fn profile(name: &str, servers: &[&str], skills: &[&str]) -> ProfileInfo {
+        ProfileInfo {
+            name: name.to_string(),
+            mcp_servers: servers
+                .iter()
+                .map(|s| McpServerInfo {
+                    name: s.to_string(),
+                    ..McpServerInfo::default()
+                })
+                .collect(),
+            skills: ResourceSummary {
+                items: skills.iter().map(|s| s.to_string()).collect(),
+                directory_exists: true,
+            },
+            ..ProfileInfo::default()
+        }
+    }
+
+    #[test]
+    fn fuzzy_match_finds_subsequences() {
+        let (_, positions) = fuzzy_match("gtw", "git-worktree").unwrap();
+        assert_eq!(positions, vec![0, 2, 4]);
+        assert!(fuzzy_match("wtg", "git-worktree").is_none());
+        assert_eq!(fuzzy_match("", "anything"), Some((0, Vec::new())));
+    }
+
+    #[test]
+    fn fuzzy_match_folds_case() {
+        let (_, positions) = fuzzy_match("GH", "github").unwrap();
+        assert_eq!(positions, vec![0, 3]);
+        assert!(fuzzy_match("gh", "GitHub").is_some());
+    }
+
+    #[test]
+    fn fuzzy_match_prefers_word_starts_and_runs() {
+        let score = |query, text| fuzzy_match(query, text).unwrap().0;
+        assert!(score("dep", "auto-deploy") > score("dep", "autodeploy"));
+        // camelCase humps count as word starts.
+        assert_eq!(fuzzy_match("r", "codeReview").unwrap().1, vec![4]);
+        assert!(score("rev", "review") > score("rev", "r-e-v"));
+    }
+
+    #[test]
+    fn profile_matches_narrows_by_name_and_components() {
+        let profiles = [
+            profile("work", &["github"], &["deploy"]),
+            profile("personal", &["filesystem"], &[]),
+            profile("scratch", &[], &["notes"]),
+        ];
+        let matching = |query: &str| -> Vec<&str> {
+            profiles
+                .iter()
+                .filter(|p| profile_matches(p, query))
+                .map(|p| p.name.as_str())
+                .collect()
+        };
+
+        assert_eq!(matching(""), vec!["work", "personal", "scratch"]);
+        assert_eq!(matching("GH"), vec!["work"]);
+        assert_eq!(matching("fs"), vec!["personal"]);
+        assert_eq!(matching("nts"), vec!["scratch"]);
+        assert!(matching("zzz").is_empty());
+    }
+}
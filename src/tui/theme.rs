@@ -1,38 +1,186 @@
+use std::str::FromStr;
+use std::sync::OnceLock;
+
 use ratatui::style::{Color, Modifier, Style};
 
+use crate::config::{ThemeConfig, ThemePreset};
+
+/// Colors for each named slot in `[tui.theme]`.
+#[derive(Debug, Clone, Copy)]
+struct Palette {
+    active_profile: Color,
+    warning: Color,
+    /// `None` dims the terminal's default color instead.
+    muted: Option<Color>,
+    tab: Color,
+    border: Color,
+    accent: Color,
+    selection: Color,
+    added: Color,
+    removed: Color,
+    changed: Color,
+    error: Color,
+}
+
+impl Palette {
+    fn preset(preset: ThemePreset) -> Self {
+        match preset {
+            ThemePreset::Default => Self {
+                active_profile: Color::Green,
+                warning: Color::Yellow,
+                muted: None,
+                tab: Color::Cyan,
+                border: Color::Cyan,
+                accent: Color::Cyan,
+                selection: Color::DarkGray,
+                added: Color::Green,
+                removed: Color::Red,
+                changed: Color::Yellow,
+                error: Color::Red,
+            },
+            ThemePreset::Light => Self {
+                active_profile: Color::Green,
+                warning: Color::Magenta,
+                muted: Some(Color::Gray),
+                tab: Color::Blue,
+                border: Color::Blue,
+                accent: Color::Blue,
+                selection: Color::Gray,
+                added: Color::Green,
+                removed: Color::Red,
+                changed: Color::Magenta,
+                error: Color::Red,
+            },
+            ThemePreset::Colorblind => Self {
+                active_profile: Color::Blue,
+                warning: Color::Rgb(230, 159, 0),
+                muted: None,
+                tab: Color::Cyan,
+                border: Color::Cyan,
+                accent: Color::Cyan,
+                selection: Color::DarkGray,
+                added: Color::Blue,
+                removed: Color::Rgb(230, 159, 0),
+                changed: Color::Magenta,
+                error: Color::Rgb(213, 94, 0),
+            },
+        }
+    }
+
+    fn slot(&mut self, name: &str) -> Option<&mut Color> {
+        let slot = match name {
+            "active_profile" => &mut self.active_profile,
+            "warning" => &mut self.warning,
+            "muted" => self.muted.get_or_insert(Color::DarkGray),
+            "tab" => &mut self.tab,
+            "border" => &mut self.border,
+            "accent" => &mut self.accent,
+            "selection" => &mut self.selection,
+            "added" => &mut self.added,
+            "removed" => &mut self.removed,
+            "changed" => &mut self.changed,
+            "error" => &mut self.error,
+            _ => return None,
+        };
+        Some(slot)
+    }
+}
+
+static PALETTE: OnceLock<Palette> = OnceLock::new();
+
+fn palette() -> &'static Palette {
+    PALETTE.get_or_init(|| Palette::preset(ThemePreset::Default))
+}
+
 /// Theme constants for consistent styling across the TUI.
 pub struct Theme;
 
 impl Theme {
-    #[cfg(feature = "tui-cards")]
+    /// Applies `[tui.theme]`; must run before the first frame is drawn.
+    ///
+    /// Returns a warning for each unknown slot or unparseable color.
+    pub fn init(config: &ThemeConfig) -> Vec<String> {
+        let mut palette = Palette::preset(config.preset);
+        let mut warnings = Vec::new();
+        for (name, value) in &config.colors {
+            let color = Color::from_str(value).ok();
+            match (palette.slot(name), color) {
+                (Some(slot), Some(color)) => *slot = color,
+                (Some(_), None) => warnings.push(format!("invalid color '{}' for {}", value, name)),
+                (None, _) => warnings.push(format!("unknown theme slot '{}'", name)),
+            }
+        }
+        let _ = PALETTE.set(palette);
+        warnings
+    }
+
     pub fn profile_active() -> Style {
         Style::default()
-            .fg(Color::Green)
+            .fg(palette().active_profile)
             .add_modifier(Modifier::BOLD)
     }
 
+    pub fn border_active() -> Style {
+        Style::default().fg(palette().border)
+    }
+
+    /// Section headers and field labels.
+    pub fn accent() -> Style {
+        Style::default().fg(palette().accent)
+    }
+
+    /// Background of the selected row.
+    pub fn selection() -> Style {
+        Style::default().bg(palette().selection)
+    }
+
     // Harness states
     pub fn harness_installed() -> Style {
         Style::default()
     }
 
     pub fn harness_not_installed() -> Style {
-        Style::default().fg(Color::DarkGray)
+        Self::muted_color()
     }
 
     // Text styles
     pub fn text_muted() -> Style {
-        Style::default().add_modifier(Modifier::DIM)
+        match palette().muted {
+            Some(color) => Style::default().fg(color),
+            None => Style::default().add_modifier(Modifier::DIM),
+        }
+    }
+
+    /// Muted text that must stay visible as a color, e.g. disabled items.
+    pub fn muted_color() -> Style {
+        Style::default().fg(palette().muted.unwrap_or(Color::DarkGray))
     }
 
     pub fn text_warning() -> Style {
-        Style::default().fg(Color::Yellow)
+        Style::default().fg(palette().warning)
+    }
+
+    pub fn error() -> Style {
+        Style::default().fg(palette().error)
+    }
+
+    // Diff and state markers
+    pub fn added() -> Style {
+        Style::default().fg(palette().added)
+    }
+
+    pub fn removed() -> Style {
+        Style::default().fg(palette().removed)
+    }
+
+    pub fn changed() -> Style {
+        Style::default().fg(palette().changed)
     }
 
     // Tab styles
     pub fn tab_selected() -> Style {
         Style::default()
-            .fg(Color::Cyan)
+            .fg(palette().tab)
             .add_modifier(Modifier::BOLD)
     }
 }
@@ -0,0 +1,97 @@
+//! Undo history for destructive TUI actions.
+//!
+//! Deleted profiles go to the trash and switches keep a backup of the live
+//! config, so each can be reversed from the record pushed here.
+
+use std::collections::VecDeque;
+use std::path::PathBuf;
+
+use harness_locate::{Harness, HarnessKind};
+
+use crate::config::{ProfileManager, ProfileName};
+use crate::error::{Error, Result};
+
+/// Operations kept; older ones fall off the bottom.
+const MAX_UNDO: usize = 20;
+
+#[derive(Debug)]
+pub enum Operation {
+    Delete {
+        harness: HarnessKind,
+        /// Where the profile now sits in the trash.
+        trashed: PathBuf,
+    },
+    Switch {
+        harness: HarnessKind,
+        /// Active profile before the switch, if any.
+        previous: Option<String>,
+        to: String,
+        /// Live config as it was before the switch.
+        backup: PathBuf,
+    },
+}
+
+impl Operation {
+    /// Reverses the operation and describes what was done.
+    fn undo(&self, manager: &ProfileManager) -> Result<String> {
+        match self {
+            Self::Delete { harness, trashed } => {
+                let name = manager.restore_trashed(&Harness::new(*harness), trashed)?;
+                Ok(format!("Restored '{}' from trash", name.as_str()))
+            }
+            Self::Switch {
+                harness,
+                previous,
+                to,
+                backup,
+            } => {
+                let harness = Harness::new(*harness);
+                // Keep edits made since the switch in the profile being left.
+                if let Ok(to) = ProfileName::new(to) {
+                    let _ = manager.save_live(&harness, &to);
+                }
+                let previous = previous
+                    .as_deref()
+                    .map(|p| {
+                        ProfileName::new(p).map_err(|_| Error::InvalidProfileName(p.to_string()))
+                    })
+                    .transpose()?;
+                manager.restore_backup(&harness, backup, previous.as_ref())?;
+                Ok(match previous {
+                    Some(p) => format!("Switched back to '{}'", p.as_str()),
+                    None => format!("Restored live config from before '{}'", to),
+                })
+            }
+        }
+    }
+
+    pub fn harness(&self) -> HarnessKind {
+        match self {
+            Self::Delete { harness, .. } | Self::Switch { harness, .. } => *harness,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct UndoStack {
+    ops: VecDeque<Operation>,
+}
+
+impl UndoStack {
+    pub fn push(&mut self, op: Operation) {
+        if self.ops.len() == MAX_UNDO {
+            self.ops.pop_front();
+        }
+        self.ops.push_back(op);
+    }
+
+    /// Undoes the most recent operation. A failed undo is dropped from the history.
+    pub fn undo(&mut self, manager: &ProfileManager) -> Option<(HarnessKind, Result<String>)> {
+        let op = self.ops.pop_back()?;
+        Some((op.harness(), op.undo(manager)))
+    }
+
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+}
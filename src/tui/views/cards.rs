@@ -1,14 +1,13 @@
-#![cfg(feature = "tui-cards")]
-
 use ratatui::{
     buffer::Buffer,
-    layout::{Constraint, Layout, Rect},
+    layout::{Position, Rect},
     widgets::StatefulWidget,
 };
 
 use crate::config::ProfileInfo;
 use crate::tui::widgets::{CardGrid, CardGridState};
 
+#[derive(Debug)]
 pub struct CardViewState {
     pub grid_state: CardGridState,
 }
@@ -35,18 +34,21 @@ impl<'a> CardView<'a> {
     pub fn new(profiles: &'a [ProfileInfo]) -> Self {
         Self { profiles }
     }
+
+    fn grid(&self) -> CardGrid<'a> {
+        CardGrid::new(self.profiles).show_new_card(true)
+    }
+
+    /// Card under `pos`: `Some(profiles.len())` is the "new profile" card.
+    pub fn index_at(&self, area: Rect, state: &CardViewState, pos: Position) -> Option<usize> {
+        self.grid().index_at(area, &state.grid_state, pos)
+    }
 }
 
 impl StatefulWidget for CardView<'_> {
     type State = CardViewState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        let chunks = Layout::vertical([Constraint::Min(0)]).split(area);
-
-        CardGrid::new(self.profiles).show_new_card(true).render(
-            chunks[0],
-            buf,
-            &mut state.grid_state,
-        );
+        self.grid().render(area, buf, &mut state.grid_state);
     }
 }
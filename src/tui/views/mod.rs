@@ -1,13 +1,11 @@
 mod dashboard;
 
-#[cfg(feature = "tui-cards")]
 mod cards;
 
 #[allow(unused_imports)]
 pub use dashboard::DashboardView;
 
-#[cfg(feature = "tui-cards")]
-pub use cards::CardView;
+pub use cards::{CardView, CardViewState};
 
 use crate::config::ViewPreference;
 
@@ -16,29 +14,30 @@ pub enum ViewMode {
     Legacy,
     #[default]
     Dashboard,
-    #[cfg(feature = "tui-cards")]
     Cards,
 }
 
 impl ViewMode {
-    #[allow(dead_code)]
     pub fn from_config(pref: ViewPreference) -> Self {
         match pref {
             ViewPreference::Legacy => ViewMode::Legacy,
             ViewPreference::Dashboard => ViewMode::Dashboard,
-            #[cfg(feature = "tui-cards")]
             ViewPreference::Cards => ViewMode::Cards,
         }
     }
 
+    pub fn to_config(self) -> ViewPreference {
+        match self {
+            ViewMode::Legacy => ViewPreference::Legacy,
+            ViewMode::Dashboard => ViewPreference::Dashboard,
+            ViewMode::Cards => ViewPreference::Cards,
+        }
+    }
+
     pub fn toggle(&mut self) {
         *self = match self {
             ViewMode::Legacy => ViewMode::Dashboard,
-            #[cfg(feature = "tui-cards")]
             ViewMode::Dashboard => ViewMode::Cards,
-            #[cfg(not(feature = "tui-cards"))]
-            ViewMode::Dashboard => ViewMode::Legacy,
-            #[cfg(feature = "tui-cards")]
             ViewMode::Cards => ViewMode::Legacy,
         };
     }
@@ -47,7 +46,6 @@ impl ViewMode {
         match self {
             ViewMode::Legacy => "Legacy",
             ViewMode::Dashboard => "Dashboard",
-            #[cfg(feature = "tui-cards")]
             ViewMode::Cards => "Cards",
         }
     }
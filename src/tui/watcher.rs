@@ -0,0 +1,182 @@
+//! Filesystem watching for the profiles directory and live harness configs.
+//!
+//! Events are coalesced: a harness is reported once its files have been quiet
+//! for [`DEBOUNCE`], so editors that write in several steps trigger a single
+//! refresh. The drift a refresh shows is counted by [`DriftCheck`] on a
+//! background thread, since it reads every file in the config directory.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{Receiver, channel};
+use std::time::{Duration, Instant};
+
+use harness_locate::{Harness, HarnessKind};
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::config::{ProfileManager, ProfileName};
+use crate::harness::HarnessConfig;
+
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Paths whose changes belong to one harness.
+#[derive(Debug)]
+struct WatchedHarness {
+    kind: HarnessKind,
+    profiles_dir: PathBuf,
+    config_dir: Option<PathBuf>,
+    mcp_file: Option<PathBuf>,
+    /// Entries the harness writes while it runs; their churn is not a config change.
+    runtime: Vec<PathBuf>,
+}
+
+impl WatchedHarness {
+    fn owns(&self, path: &Path) -> bool {
+        if self.runtime.iter().any(|r| path.starts_with(r)) {
+            return false;
+        }
+        path.starts_with(&self.profiles_dir)
+            || self
+                .config_dir
+                .as_ref()
+                .is_some_and(|d| path.starts_with(d))
+            || self.mcp_file.as_deref() == Some(path)
+    }
+}
+
+pub struct ConfigWatcher {
+    _watcher: RecommendedWatcher,
+    events: Receiver<notify::Result<Event>>,
+    harnesses: Vec<WatchedHarness>,
+    pending: HashMap<HarnessKind, Instant>,
+}
+
+impl std::fmt::Debug for ConfigWatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConfigWatcher")
+            .field("harnesses", &self.harnesses)
+            .field("pending", &self.pending)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ConfigWatcher {
+    /// Watches `profiles_dir` and the live config of every harness in `kinds`.
+    ///
+    /// Directories that do not exist yet are skipped.
+    pub fn new(profiles_dir: &Path, kinds: &[HarnessKind]) -> notify::Result<Self> {
+        let (tx, events) = channel();
+        let mut watcher = notify::recommended_watcher(tx)?;
+
+        if profiles_dir.exists() {
+            watcher.watch(profiles_dir, RecursiveMode::Recursive)?;
+        }
+
+        let mut harnesses = Vec::new();
+        for kind in kinds {
+            let harness = Harness::new(*kind);
+            let config_dir = HarnessConfig::config_dir(&harness)
+                .ok()
+                .filter(|d| d.exists());
+            if let Some(dir) = &config_dir {
+                let _ = watcher.watch(dir, RecursiveMode::Recursive);
+            }
+
+            // MCP files outside the config dir (e.g. ~/.claude.json) are watched
+            // through their parent, with events filtered to the file itself.
+            let mcp_file = harness
+                .mcp_config_path()
+                .filter(|p| !config_dir.as_ref().is_some_and(|d| p.starts_with(d)));
+            if let Some(parent) = mcp_file.as_deref().and_then(Path::parent)
+                && parent.exists()
+            {
+                let _ = watcher.watch(parent, RecursiveMode::NonRecursive);
+            }
+
+            let runtime = config_dir
+                .as_ref()
+                .map(|dir| {
+                    harness
+                        .runtime_entries()
+                        .iter()
+                        .map(|entry| dir.join(entry))
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            harnesses.push(WatchedHarness {
+                kind: *kind,
+                profiles_dir: profiles_dir.join(harness.id()),
+                config_dir,
+                mcp_file,
+                runtime,
+            });
+        }
+
+        Ok(Self {
+            _watcher: watcher,
+            events,
+            harnesses,
+            pending: HashMap::new(),
+        })
+    }
+
+    /// Harnesses whose files changed and have since settled.
+    pub fn poll(&mut self) -> Vec<HarnessKind> {
+        let now = Instant::now();
+        while let Ok(event) = self.events.try_recv() {
+            let Ok(event) = event else {
+                continue;
+            };
+            if event.kind.is_access() {
+                continue;
+            }
+            for path in &event.paths {
+                for harness in self.harnesses.iter().filter(|h| h.owns(path)) {
+                    self.pending.insert(harness.kind, now);
+                }
+            }
+        }
+
+        let settled: Vec<HarnessKind> = self
+            .pending
+            .iter()
+            .filter(|(_, last)| now.duration_since(**last) >= DEBOUNCE)
+            .map(|(kind, _)| *kind)
+            .collect();
+        for kind in &settled {
+            self.pending.remove(kind);
+        }
+        settled
+    }
+}
+
+/// Counts, off the UI thread, the live config files that differ from a profile.
+#[derive(Debug)]
+pub struct DriftCheck {
+    results: Receiver<(HarnessKind, Option<usize>)>,
+}
+
+impl DriftCheck {
+    /// Compares the live config of each harness with the stored copy of its profile.
+    pub fn spawn(manager: &ProfileManager, targets: Vec<(HarnessKind, ProfileName)>) -> Self {
+        let manager = manager.clone();
+        let (tx, results) = channel();
+        std::thread::spawn(move || {
+            for (kind, name) in targets {
+                let drift = manager
+                    .live_drift(&Harness::new(kind), &name)
+                    .ok()
+                    .map(|changes| changes.len());
+                if tx.send((kind, drift)).is_err() {
+                    return;
+                }
+            }
+        });
+        Self { results }
+    }
+
+    /// Counts that have arrived since the last poll; `None` if a comparison failed.
+    pub fn poll(&self) -> Vec<(HarnessKind, Option<usize>)> {
+        self.results.try_iter().collect()
+    }
+}
@@ -1,8 +1,6 @@
-#![cfg(feature = "tui-cards")]
-
 use ratatui::{
     buffer::Buffer,
-    layout::Rect,
+    layout::{Position, Rect},
     widgets::{StatefulWidget, Widget},
 };
 
@@ -10,10 +8,11 @@ use crate::config::ProfileInfo;
 
 use super::profile_card::{NewProfileCard, ProfileCard};
 
-const CARD_WIDTH: u16 = 30;
-const CARD_HEIGHT: u16 = 8;
+const CARD_MIN_WIDTH: u16 = 26;
+const CARD_HEIGHT: u16 = 7;
 const CARD_GAP: u16 = 1;
 
+#[derive(Debug)]
 pub struct CardGridState {
     pub selected: usize,
     pub scroll_offset: usize,
@@ -26,31 +25,6 @@ impl CardGridState {
             scroll_offset: 0,
         }
     }
-
-    pub fn select_next(&mut self, total: usize) {
-        if total > 0 && self.selected < total - 1 {
-            self.selected += 1;
-        }
-    }
-
-    pub fn select_prev(&mut self) {
-        if self.selected > 0 {
-            self.selected -= 1;
-        }
-    }
-
-    pub fn select_down(&mut self, cols: usize, total: usize) {
-        let next = self.selected + cols;
-        if next < total {
-            self.selected = next;
-        }
-    }
-
-    pub fn select_up(&mut self, cols: usize) {
-        if self.selected >= cols {
-            self.selected -= cols;
-        }
-    }
 }
 
 impl Default for CardGridState {
@@ -77,10 +51,36 @@ impl<'a> CardGrid<'a> {
         self
     }
 
-    fn calc_columns(&self, width: u16) -> usize {
-        let usable = width.saturating_sub(CARD_GAP);
-        let card_with_gap = CARD_WIDTH + CARD_GAP;
-        (usable / card_with_gap).max(1) as usize
+    fn total_items(&self) -> usize {
+        self.profiles.len() + usize::from(self.show_new_card)
+    }
+
+    /// Column count and card width for `width`; cards stretch to fill the row.
+    fn columns(width: u16) -> (usize, u16) {
+        let cols = ((width + CARD_GAP) / (CARD_MIN_WIDTH + CARD_GAP)).max(1);
+        let card_width = (width.saturating_sub(CARD_GAP * (cols - 1)) / cols).max(1);
+        (cols as usize, card_width)
+    }
+
+    /// Index of the card under `pos`, given the `area` and state of the last render.
+    ///
+    /// `profiles.len()` is the "new profile" card.
+    pub fn index_at(&self, area: Rect, state: &CardGridState, pos: Position) -> Option<usize> {
+        if !area.contains(pos) {
+            return None;
+        }
+        let (cols, card_width) = Self::columns(area.width);
+        let dx = pos.x - area.x;
+        let dy = pos.y - area.y;
+        let col = (dx / (card_width + CARD_GAP)) as usize;
+        let row = (dy / (CARD_HEIGHT + CARD_GAP)) as usize;
+        let in_gap = dx % (card_width + CARD_GAP) >= card_width
+            || dy % (CARD_HEIGHT + CARD_GAP) >= CARD_HEIGHT;
+        if col >= cols || in_gap {
+            return None;
+        }
+        let idx = (state.scroll_offset + row) * cols + col;
+        (idx < self.total_items()).then_some(idx)
     }
 }
 
@@ -88,18 +88,18 @@ impl StatefulWidget for CardGrid<'_> {
     type State = CardGridState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
-        if area.width < CARD_WIDTH || area.height < CARD_HEIGHT {
+        if area.height < CARD_HEIGHT {
             return;
         }
 
-        let cols = self.calc_columns(area.width);
-        let total_items = self.profiles.len() + if self.show_new_card { 1 } else { 0 };
+        let (cols, card_width) = Self::columns(area.width);
+        let total_items = self.total_items();
 
         if state.selected >= total_items && total_items > 0 {
             state.selected = total_items - 1;
         }
 
-        let visible_rows = (area.height / (CARD_HEIGHT + CARD_GAP)) as usize;
+        let visible_rows = (((area.height + CARD_GAP) / (CARD_HEIGHT + CARD_GAP)) as usize).max(1);
         let selected_row = state.selected / cols;
 
         if selected_row < state.scroll_offset {
@@ -116,14 +116,9 @@ impl StatefulWidget for CardGrid<'_> {
                     break;
                 }
 
-                let x = area.x + (col as u16) * (CARD_WIDTH + CARD_GAP);
+                let x = area.x + (col as u16) * (card_width + CARD_GAP);
                 let y = area.y + (row as u16) * (CARD_HEIGHT + CARD_GAP);
-
-                if x + CARD_WIDTH > area.x + area.width || y + CARD_HEIGHT > area.y + area.height {
-                    continue;
-                }
-
-                let card_area = Rect::new(x, y, CARD_WIDTH, CARD_HEIGHT);
+                let card_area = Rect::new(x, y, card_width, CARD_HEIGHT).intersection(area);
                 let is_selected = idx == state.selected;
 
                 if idx < self.profiles.len() {
@@ -0,0 +1,88 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, StatefulWidget},
+};
+
+use crate::install::DependencyKind;
+use crate::tui::browser::ComponentEntry;
+use crate::tui::theme::Theme;
+
+/// Selectable list of a profile's components, shown when the detail pane has focus.
+pub struct ComponentList<'a> {
+    entries: &'a [ComponentEntry],
+}
+
+impl<'a> ComponentList<'a> {
+    pub fn new(entries: &'a [ComponentEntry]) -> Self {
+        Self { entries }
+    }
+
+    fn group_label(kind: DependencyKind) -> &'static str {
+        match kind {
+            DependencyKind::Mcp => "MCP Servers",
+            DependencyKind::Skill => "Skills",
+            DependencyKind::Command => "Commands",
+            DependencyKind::Agent => "Agents",
+        }
+    }
+}
+
+impl StatefulWidget for ComponentList<'_> {
+    type State = ListState;
+
+    /// `state` selects an entry index; group headers are skipped over.
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut ListState) {
+        let mut items = Vec::new();
+        let mut selected_item = None;
+        let mut current_kind = None;
+
+        for (idx, entry) in self.entries.iter().enumerate() {
+            if current_kind != Some(entry.component.kind) {
+                current_kind = Some(entry.component.kind);
+                items.push(ListItem::new(Line::styled(
+                    Self::group_label(entry.component.kind),
+                    Theme::accent().add_modifier(Modifier::BOLD),
+                )));
+            }
+            if state.selected() == Some(idx) {
+                selected_item = Some(items.len());
+            }
+
+            let (marker, style) = match entry.enabled {
+                Some(true) => ("✓ ", Theme::added()),
+                Some(false) => ("✗ ", Theme::muted_color()),
+                None => ("  ", Style::default()),
+            };
+            let mut spans = vec![
+                Span::styled(marker, style),
+                Span::styled(entry.component.name.clone(), style),
+            ];
+            if let Some(provenance) = entry.provenance_text() {
+                spans.push(Span::styled(
+                    format!("  {}", provenance),
+                    Theme::muted_color(),
+                ));
+            }
+            items.push(ListItem::new(Line::from(spans)));
+        }
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .title(" Components (Enter:preview t:toggle u:uninstall c:copy e:edit) ")
+                    .borders(Borders::ALL)
+                    .border_style(Theme::border_active()),
+            )
+            .highlight_style(Theme::selection())
+            .highlight_symbol("> ");
+
+        let mut list_state = ListState::default()
+            .with_offset(state.offset())
+            .with_selected(selected_item);
+        StatefulWidget::render(list, area, buf, &mut list_state);
+        *state.offset_mut() = list_state.offset();
+    }
+}
@@ -1,12 +1,12 @@
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    style::{Color, Style},
     text::Line,
     widgets::{Block, Borders, Paragraph, Widget},
 };
 
 use crate::config::ProfileInfo;
+use crate::tui::theme::Theme;
 
 pub fn render_profile_details(profile: &ProfileInfo) -> Vec<Line<'static>> {
     let nodes = crate::display::profile_to_nodes(profile);
@@ -42,9 +42,9 @@ impl<'a> DetailPane<'a> {
 impl Widget for DetailPane<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         let border_style = if self.is_focused {
-            Style::default().fg(Color::Cyan)
+            Theme::accent()
         } else {
-            Style::default().fg(Color::DarkGray)
+            Theme::muted_color()
         };
 
         let block = Block::default()
@@ -56,7 +56,7 @@ impl Widget for DetailPane<'_> {
             Some(profile) => render_profile_details(profile),
             None => vec![Line::styled(
                 "Select a profile to view details",
-                Style::default().fg(Color::DarkGray),
+                Theme::muted_color(),
             )],
         };
 
@@ -4,7 +4,7 @@ use harness_locate::{Harness, HarnessKind, InstallationStatus};
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Tabs, Widget},
 };
@@ -29,7 +29,7 @@ impl HarnessStatus {
 
     pub fn style(self) -> Style {
         match self {
-            Self::Active => Style::default().fg(Color::Green),
+            Self::Active => Theme::profile_active(),
             Self::Installed => Theme::harness_installed(),
             Self::BinaryOnly | Self::NotInstalled => Theme::harness_not_installed(),
         }
@@ -92,7 +92,7 @@ impl Widget for HarnessTabs<'_> {
             })
             .collect();
 
-        let border_style = Style::default().fg(Color::DarkGray);
+        let border_style = Theme::muted_color();
 
         let tabs = Tabs::new(titles)
             .block(
@@ -103,11 +103,7 @@ impl Widget for HarnessTabs<'_> {
             )
             .select(self.selected)
             .style(Style::default())
-            .highlight_style(
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
-            )
+            .highlight_style(Theme::tab_selected().add_modifier(Modifier::UNDERLINED))
             .divider(Span::raw(" │ "));
 
         tabs.render(area, buf);
@@ -1,19 +1,16 @@
+mod component_list;
 mod detail_pane;
 mod harness_tabs;
 mod profile_table;
 mod status_bar;
 
-#[cfg(feature = "tui-cards")]
 mod card_grid;
-#[cfg(feature = "tui-cards")]
 mod profile_card;
 
+pub use component_list::ComponentList;
 pub use detail_pane::{DetailPane, render_profile_details};
 pub use harness_tabs::HarnessTabs;
 pub use profile_table::ProfileTable;
 pub use status_bar::StatusBar;
 
-#[cfg(feature = "tui-cards")]
 pub use card_grid::{CardGrid, CardGridState};
-#[cfg(feature = "tui-cards")]
-pub use profile_card::{NewProfileCard, ProfileCard};
@@ -1,9 +1,6 @@
-#![cfg(feature = "tui-cards")]
-
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph, Widget},
 };
@@ -44,7 +41,7 @@ impl Widget for ProfileCard<'_> {
         } else if self.selected {
             Theme::profile_active()
         } else {
-            Style::default().fg(Color::DarkGray)
+            Theme::muted_color()
         };
 
         let block = Block::default()
@@ -64,44 +61,46 @@ impl Widget for ProfileCard<'_> {
         if self.profile.is_active {
             lines.push(Line::from(Span::styled(
                 "● Active",
-                Style::default()
-                    .fg(Color::Green)
-                    .add_modifier(Modifier::BOLD),
-            )));
-        }
-
-        if let Some(model) = &self.profile.model {
-            let display = if model.len() > inner.width as usize - 2 {
-                format!("{}…", &model[..inner.width as usize - 3])
-            } else {
-                model.clone()
-            };
-            lines.push(Line::from(Span::styled(
-                display,
-                Style::default().fg(Color::Cyan),
+                Theme::profile_active(),
             )));
         }
 
-        let mcp_count = self.profile.mcp_servers.len();
-        if mcp_count > 0 {
-            lines.push(Line::from(Span::styled(
-                format!("{} MCP servers", mcp_count),
-                Style::default().fg(Color::Yellow),
-            )));
-        }
+        let model = self.profile.model.as_deref().unwrap_or("-");
+        lines.push(Line::from(Span::styled(
+            truncate(model, inner.width as usize),
+            Theme::accent(),
+        )));
 
-        if let Some(theme) = &self.profile.theme {
-            lines.push(Line::from(Span::styled(
-                format!("Theme: {}", theme),
-                Style::default().fg(Color::Gray),
-            )));
-        }
+        lines.push(Line::from(Span::styled(
+            count_label(self.profile.mcp_servers.len(), "MCP server"),
+            Theme::text_muted(),
+        )));
+        lines.push(Line::from(Span::styled(
+            count_label(self.profile.skills.items.len(), "skill"),
+            Theme::text_muted(),
+        )));
 
         let para = Paragraph::new(lines);
         para.render(inner, buf);
     }
 }
 
+fn truncate(text: &str, width: usize) -> String {
+    if text.chars().count() <= width {
+        return text.to_string();
+    }
+    let kept: String = text.chars().take(width.saturating_sub(1)).collect();
+    format!("{}…", kept)
+}
+
+fn count_label(count: usize, noun: &str) -> String {
+    match count {
+        0 => format!("no {}s", noun),
+        1 => format!("1 {}", noun),
+        n => format!("{} {}s", n, noun),
+    }
+}
+
 pub struct NewProfileCard {
     focused: bool,
 }
@@ -128,7 +127,7 @@ impl Widget for NewProfileCard {
         let border_style = if self.focused {
             Theme::border_active()
         } else {
-            Style::default().fg(Color::DarkGray)
+            Theme::muted_color()
         };
 
         let block = Block::default()
@@ -145,7 +144,7 @@ impl Widget for NewProfileCard {
 
         let text = Paragraph::new(Line::from(Span::styled(
             "Create new profile",
-            Style::default().fg(Color::DarkGray),
+            Theme::text_muted(),
         )));
         text.render(inner, buf);
     }
@@ -1,16 +1,21 @@
 use ratatui::{
     buffer::Buffer,
     layout::{Constraint, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, Borders, Cell, Row, StatefulWidget, Table, TableState},
 };
 
 use crate::config::ProfileInfo;
+use crate::tui::theme::Theme;
 
 pub struct ProfileTable<'a> {
     profiles: &'a [ProfileInfo],
     block: Option<Block<'a>>,
     focused: bool,
+    diff_base: Option<&'a str>,
+    unsaved: bool,
+    filter: Option<&'a str>,
 }
 
 impl<'a> ProfileTable<'a> {
@@ -19,6 +24,9 @@ impl<'a> ProfileTable<'a> {
             profiles,
             block: None,
             focused: false,
+            diff_base: None,
+            unsaved: false,
+            filter: None,
         }
     }
 
@@ -33,6 +41,24 @@ impl<'a> ProfileTable<'a> {
         self
     }
 
+    /// Profile marked as the left-hand side of a diff.
+    pub fn diff_base(mut self, name: Option<&'a str>) -> Self {
+        self.diff_base = name;
+        self
+    }
+
+    /// Whether the live config has drifted from the active profile.
+    pub fn unsaved(mut self, unsaved: bool) -> Self {
+        self.unsaved = unsaved;
+        self
+    }
+
+    /// Active search filter, shown in the title.
+    pub fn filter(mut self, query: Option<&'a str>) -> Self {
+        self.filter = query;
+        self
+    }
+
     fn truncate_model(model: &str, max_len: usize) -> String {
         if model.len() <= max_len {
             model.to_string()
@@ -57,7 +83,11 @@ impl StatefulWidget for ProfileTable<'_> {
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
         if self.profiles.is_empty() {
-            let empty_msg = "No profiles found. Create one with 'n' or run 'bridle profile create'";
+            let empty_msg = if self.filter.is_some() {
+                "No profiles match the filter. Press Esc to clear it"
+            } else {
+                "No profiles found. Create one with 'n' or run 'bridle profile create'"
+            };
             let x = area.x + area.width.saturating_sub(empty_msg.len() as u16) / 2;
             let y = area.y + area.height / 2;
             buf.set_string(
@@ -72,14 +102,18 @@ impl StatefulWidget for ProfileTable<'_> {
         let header_cells = ["", "Name", "Model", "MCP"]
             .iter()
             .map(|h| Cell::from(*h).style(Style::default().add_modifier(Modifier::BOLD)));
-        let header = Row::new(header_cells)
-            .style(Style::default().fg(Color::Cyan))
-            .height(1);
+        let header = Row::new(header_cells).style(Theme::accent()).height(1);
 
         let rows = self.profiles.iter().map(|profile| {
-            let active = if profile.is_active { "●" } else { " " };
-            let active_style = if profile.is_active {
-                Style::default().fg(Color::Green)
+            let active = match (profile.is_active, self.unsaved) {
+                (true, true) => "●*",
+                (true, false) => "●",
+                _ => " ",
+            };
+            let active_style = if profile.is_active && self.unsaved {
+                Theme::text_warning()
+            } else if profile.is_active {
+                Theme::profile_active()
             } else {
                 Style::default()
             };
@@ -99,7 +133,14 @@ impl StatefulWidget for ProfileTable<'_> {
 
             Row::new(vec![
                 Cell::from(active).style(active_style),
-                Cell::from(profile.name.as_str()),
+                if self.diff_base == Some(profile.name.as_str()) {
+                    Cell::from(Line::from(vec![
+                        Span::raw(profile.name.as_str()),
+                        Span::styled(" [base]", Theme::changed()),
+                    ]))
+                } else {
+                    Cell::from(profile.name.as_str())
+                },
                 Cell::from(model).style(Style::default().add_modifier(Modifier::DIM)),
                 Cell::from(mcp).style(Style::default().add_modifier(Modifier::DIM)),
             ])
@@ -113,24 +154,22 @@ impl StatefulWidget for ProfileTable<'_> {
         ];
 
         let border_style = if self.focused {
-            Style::default().fg(Color::Cyan)
+            Theme::accent()
         } else {
-            Style::default().fg(Color::DarkGray)
+            Theme::muted_color()
         };
 
         let table = Table::new(rows, widths)
             .header(header)
-            .row_highlight_style(
-                Style::default()
-                    .fg(Color::Black)
-                    .bg(Color::White)
-                    .add_modifier(Modifier::BOLD),
-            )
+            .row_highlight_style(Theme::selection().add_modifier(Modifier::BOLD))
             .block(
                 Block::default()
                     .borders(Borders::ALL)
                     .border_style(border_style)
-                    .title(" Profiles "),
+                    .title(match self.filter {
+                        Some(query) => format!(" Profiles (filter: {}) ", query),
+                        None => " Profiles ".to_string(),
+                    }),
             );
 
         let table = if let Some(block) = self.block {
@@ -10,7 +10,9 @@ use ratatui::{
 
 pub struct StatusBar<'a> {
     view_mode: ViewMode,
+    hints: &'a str,
     message: Option<&'a str>,
+    warning: Option<&'a str>,
     harness_status: Option<&'a str>,
 }
 
@@ -18,16 +20,30 @@ impl<'a> StatusBar<'a> {
     pub fn new(view_mode: ViewMode) -> Self {
         Self {
             view_mode,
+            hints: "",
             message: None,
+            warning: None,
             harness_status: None,
         }
     }
 
+    /// Key hints generated from the active keymap.
+    pub fn hints(mut self, hints: &'a str) -> Self {
+        self.hints = hints;
+        self
+    }
+
     pub fn message(mut self, msg: Option<&'a str>) -> Self {
         self.message = msg;
         self
     }
 
+    /// Persistent warning shown ahead of the transient message.
+    pub fn warning(mut self, warning: Option<&'a str>) -> Self {
+        self.warning = warning;
+        self
+    }
+
     pub fn harness_status(mut self, status: Option<&'a str>) -> Self {
         self.harness_status = status;
         self
@@ -43,26 +59,10 @@ impl<'a> StatusBar<'a> {
         }
     }
 
-    fn keybindings(&self) -> &'static str {
-        match self.view_mode {
-            ViewMode::Dashboard => {
-                "q:quit  ←/→:harness  ↑/↓:profile  Tab:focus  Enter:switch  n:new  d:del  e:edit  r:refresh  ?:help"
-            }
-            ViewMode::Legacy => {
-                "q:quit  Tab:pane  ↑/↓:nav  Enter:switch  n:new  d:del  e:edit  r:refresh  ?:help"
-            }
-            #[cfg(feature = "tui-cards")]
-            ViewMode::Cards => {
-                "q:quit  ←/→:harness  ↑/↓:profile  Enter:switch  n:new  d:del  e:edit  r:refresh  ?:help"
-            }
-        }
-    }
-
     fn mode_indicator(&self) -> &'static str {
         match self.view_mode {
             ViewMode::Dashboard => "[Dashboard]",
             ViewMode::Legacy => "[Legacy]",
-            #[cfg(feature = "tui-cards")]
             ViewMode::Cards => "[Cards]",
         }
     }
@@ -82,8 +82,15 @@ impl Widget for StatusBar<'_> {
             spans.push(Span::raw(" "));
         }
 
-        spans.push(Span::styled(self.keybindings(), Theme::text_muted()));
+        spans.push(Span::styled(self.hints, Theme::text_muted()));
         spans.push(Span::raw("  "));
+        if let Some(warning) = self.warning {
+            spans.push(Span::styled(
+                format!("⚠ {}", warning),
+                Theme::text_warning(),
+            ));
+            spans.push(Span::raw("  "));
+        }
         spans.push(Span::styled(msg, Theme::text_warning()));
 
         let paragraph = Paragraph::new(Line::from(spans));